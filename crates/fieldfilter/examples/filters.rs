@@ -46,7 +46,11 @@ fn main() {
 
   // Example 5: Slug filter
   println!("\n5. Slug filter:");
-  let slug_filter = FilterOp::<String>::Slug { max_length: None };
+  let slug_filter = FilterOp::<String>::Slug {
+    max_length: None,
+    separator: '-',
+    stop_words: Vec::new(),
+  };
   let input = "Hello World! This is a Test";
   let result = slug_filter.apply_ref(input);
   println!("   Input:  '{}'", input);
@@ -93,7 +97,11 @@ fn main() {
   let slug_processor = FilterOp::<String>::Chain(vec![
     FilterOp::Trim,
     FilterOp::StripTags,
-    FilterOp::Slug { max_length: None },
+    FilterOp::Slug {
+      max_length: None,
+      separator: '-',
+      stop_words: Vec::new(),
+    },
   ]);
 
   let titles = vec![
@@ -0,0 +1,132 @@
+//! Per-field audit log of the changes filters made to submitted values.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use walrs_filter::{FilterTrace, TraceValues};
+
+/// Which filter steps changed which field, keyed by field name in the order fields
+/// were first recorded.
+///
+/// Populated by [`Field::filter_traced`](crate::Field::filter_traced) and
+/// [`Field::sanitize_traced`](crate::Field::sanitize_traced); serializes as a map
+/// from field name to its [`FilterTrace`].
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_fieldfilter::{ChangeLog, FieldBuilder, FilterOp, TraceValues};
+///
+/// let email = FieldBuilder::<String>::default()
+///     .name("email")
+///     .filters(vec![FilterOp::Trim, FilterOp::Lowercase])
+///     .build()
+///     .unwrap();
+///
+/// let mut log = ChangeLog::new(TraceValues::Masked);
+/// let value = email.sanitize_traced(" Jo@Example.com".to_string(), &mut log).unwrap();
+///
+/// assert_eq!(value, "jo@example.com");
+/// let ops: Vec<&str> = log.field("email").unwrap().changed_steps().map(|s| s.op.as_str()).collect();
+/// assert_eq!(ops, ["Trim", "Lowercase"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ChangeLog {
+  #[serde(skip)]
+  values: TraceValues,
+  fields: IndexMap<String, FilterTrace>,
+}
+
+impl ChangeLog {
+  /// An empty log recording values per `values`.
+  pub fn new(values: TraceValues) -> Self {
+    Self {
+      values,
+      fields: IndexMap::new(),
+    }
+  }
+
+  /// How much of each value the log records.
+  pub fn values(&self) -> TraceValues {
+    self.values
+  }
+
+  /// Appends the steps of `trace` to the entry for `field`.
+  pub fn record(&mut self, field: &str, trace: FilterTrace) {
+    let values = self.values;
+    self
+      .fields
+      .entry(field.to_string())
+      .or_insert_with(|| FilterTrace::new(values))
+      .steps
+      .extend(trace.steps);
+  }
+
+  /// The trace recorded for `field`, if any.
+  pub fn field(&self, field: &str) -> Option<&FilterTrace> {
+    self.fields.get(field)
+  }
+
+  /// Every recorded field and its trace.
+  pub fn iter(&self) -> impl Iterator<Item = (&str, &FilterTrace)> {
+    self
+      .fields
+      .iter()
+      .map(|(field, trace)| (field.as_str(), trace))
+  }
+
+  /// The names of the fields whose value some filter changed.
+  pub fn changed_fields(&self) -> impl Iterator<Item = &str> {
+    self
+      .iter()
+      .filter(|(_, trace)| trace.is_changed())
+      .map(|(field, _)| field)
+  }
+
+  /// Whether no field has been recorded.
+  pub fn is_empty(&self) -> bool {
+    self.fields.is_empty()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use walrs_filter::FilterOp;
+
+  #[test]
+  fn test_record_appends_per_field() {
+    let mut log = ChangeLog::new(TraceValues::Full);
+    assert!(log.is_empty());
+    let (_, trace) = FilterOp::<String>::Trim.apply_traced(" a".to_string(), TraceValues::Full);
+    log.record("name", trace);
+    let (_, trace) = FilterOp::<String>::Trim.apply_traced("b".to_string(), TraceValues::Full);
+    log.record("city", trace.clone());
+    log.record("name", trace);
+
+    assert_eq!(log.field("name").unwrap().steps.len(), 2);
+    assert_eq!(log.changed_fields().collect::<Vec<_>>(), ["name"]);
+    assert_eq!(
+      log.iter().map(|(field, _)| field).collect::<Vec<_>>(),
+      ["name", "city"]
+    );
+  }
+
+  #[test]
+  fn test_serializes_as_field_map() {
+    let mut log = ChangeLog::new(TraceValues::Omitted);
+    let (_, trace) =
+      FilterOp::<String>::Uppercase.apply_traced("a".to_string(), TraceValues::Omitted);
+    log.record("code", trace);
+    let json = serde_json::to_string(&log).unwrap();
+    assert_eq!(
+      json,
+      r#"{"code":{"steps":[{"op":"Uppercase","changed":true}]}}"#
+    );
+    let back: ChangeLog = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+      back.field("code").unwrap().steps,
+      log.field("code").unwrap().steps
+    );
+  }
+}
@@ -0,0 +1,1073 @@
+//! Runtime-defined fieldsets for forms described by configuration.
+//!
+//! [`DynamicFieldset`] is the counterpart of [`Fieldset`](crate::Fieldset) for forms
+//! whose fields are only known at runtime (e.g. stored in a database). It holds an
+//! ordered map of named [`Field`]s plus cross-field rules, deserializes from any serde
+//! format (JSON, YAML, ...), and validates and sanitizes a
+//! [`serde_json::Map`] of submitted values, reporting the same
+//! [`FieldsetViolations`] as the typed path.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
+use std::sync::Arc;
+use walrs_filter::{FilterError, FilterRegistry};
+use walrs_validation::{FieldsetViolations, Violation, ViolationType, Violations};
+
+use crate::Field;
+
+/// Custom cross-field check for [`CrossValidateRule::Custom`].
+pub type CrossValidateFn = Arc<dyn Fn(&Map<String, Value>) -> Result<(), Violation> + Send + Sync>;
+
+/// A named field in a [`DynamicFieldset`], tagged by the JSON type of its value.
+///
+/// Serialized with an internal `type` tag: `{"type": "string", "rule": ...}`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DynamicField {
+  /// A JSON string.
+  String(Field<String>),
+
+  /// A JSON number without a fractional part that fits in an `i64`.
+  Integer(Field<i64>),
+
+  /// Any JSON number.
+  Number(Field<f64>),
+
+  /// A JSON boolean.
+  Bool(Field<bool>),
+
+  /// A JSON array of strings.
+  List(Field<Vec<String>>),
+
+  /// A JSON object validated by a nested fieldset. Violations are reported under
+  /// dotted keys (`address.zip`).
+  Nested {
+    fieldset: Box<DynamicFieldset>,
+
+    /// When `true`, a missing or `null` object is a `ValueMissing` violation;
+    /// otherwise it is skipped.
+    #[serde(default)]
+    required: bool,
+  },
+}
+
+impl DynamicField {
+  /// Creates a nested field.
+  pub fn nested(fieldset: DynamicFieldset, required: bool) -> Self {
+    Self::Nested {
+      fieldset: Box::new(fieldset),
+      required,
+    }
+  }
+
+  /// Returns the field's own `break_on_failure` flag (`false` for nested fields).
+  fn break_on_failure(&self) -> bool {
+    match self {
+      Self::String(field) => field.break_on_failure,
+      Self::Integer(field) => field.break_on_failure,
+      Self::Number(field) => field.break_on_failure,
+      Self::Bool(field) => field.break_on_failure,
+      Self::List(field) => field.break_on_failure,
+      Self::Nested { .. } => false,
+    }
+  }
+
+  /// Validates `value` (`None` when the key is absent), adding any violations to
+  /// `violations` under `name`. Returns `true` if violations were added.
+  fn validate_into(
+    &self,
+    name: &str,
+    value: Option<&Value>,
+    violations: &mut FieldsetViolations,
+  ) -> bool {
+    let result = match self {
+      Self::String(field) => {
+        typed(value, "a string", Value::as_str).and_then(|v| field.validate_option(v))
+      }
+      Self::Integer(field) => {
+        typed(value, "an integer", Value::as_i64).and_then(|v| field.validate_option(v.as_ref()))
+      }
+      Self::Number(field) => {
+        typed(value, "a number", Value::as_f64).and_then(|v| field.validate_option(v.as_ref()))
+      }
+      Self::Bool(field) => {
+        typed(value, "a boolean", Value::as_bool).and_then(|v| field.validate_option(v.as_ref()))
+      }
+      Self::List(field) => typed(value, "a list of strings", string_list)
+        .and_then(|v| field.validate_option(v.as_ref())),
+      Self::Nested { fieldset, required } => match typed(value, "an object", Value::as_object) {
+        Ok(Some(object)) => match fieldset.validate(object) {
+          Ok(()) => return false,
+          Err(nested) => {
+            violations.merge_prefixed(name, nested);
+            return true;
+          }
+        },
+        Ok(None) if *required => Err(Violation::value_missing().into()),
+        Ok(None) => Ok(()),
+        Err(type_mismatch) => Err(type_mismatch),
+      },
+    };
+    match result {
+      Ok(()) => false,
+      Err(errors) => {
+        violations.add_many(name, errors);
+        true
+      }
+    }
+  }
+
+  /// Filters a present value. Values of the wrong JSON type are returned unchanged
+  /// so validation can report them.
+  fn filter_value(&self, name: &str, value: Value) -> Result<Value, FieldsetViolations> {
+    let result = match (self, value) {
+      (Self::String(field), Value::String(s)) => field.try_filter(field.filter(s)).map(Value::from),
+      (Self::Integer(field), Value::Number(n)) if n.is_i64() => {
+        let n = n.as_i64().expect("checked by `is_i64`");
+        field.try_filter(field.filter(n)).map(Value::from)
+      }
+      (Self::Number(field), Value::Number(n)) => {
+        let x = n.as_f64().expect("every JSON number converts to `f64`");
+        field.try_filter(field.filter(x)).and_then(|filtered| {
+          // Keep the submitted number (`5` stays `5`, not `5.0`) when unchanged.
+          if filtered == x {
+            return Ok(Value::Number(n));
+          }
+          serde_json::Number::from_f64(filtered)
+            .map(Value::Number)
+            .ok_or_else(|| {
+              Violation::new(ViolationType::TypeMismatch, "Expected a finite number.").into()
+            })
+        })
+      }
+      (Self::Bool(field), Value::Bool(b)) => field.try_filter(field.filter(b)).map(Value::from),
+      (Self::List(field), value) => match string_list(&value) {
+        Some(list) => field.try_filter(field.filter(list)).map(Value::from),
+        None => Ok(value),
+      },
+      (Self::Nested { fieldset, .. }, Value::Object(object)) => {
+        return fieldset
+          .filter(object)
+          .map(Value::Object)
+          .map_err(|nested| {
+            let mut violations = FieldsetViolations::new();
+            violations.merge_prefixed(name, nested);
+            violations
+          });
+      }
+      (_, value) => Ok(value),
+    };
+    result.map_err(|errors| {
+      let mut violations = FieldsetViolations::new();
+      violations.add_many(name, errors);
+      violations
+    })
+  }
+}
+
+impl DynamicField {
+  /// Binds the field's `Named` filters. Unknown names are added to `violations`
+  /// under `name`, and the field is dropped.
+  fn bind(
+    self,
+    name: &str,
+    registry: &DynamicFilterRegistry,
+    violations: &mut FieldsetViolations,
+  ) -> Option<Self> {
+    let result = match self {
+      Self::String(field) => bind_field(field, &registry.string).map(Self::String),
+      Self::Integer(field) => bind_field(field, &registry.integer).map(Self::Integer),
+      Self::Number(field) => bind_field(field, &registry.number).map(Self::Number),
+      Self::Bool(field) => bind_field(field, &registry.bool).map(Self::Bool),
+      Self::List(field) => bind_field(field, &registry.list).map(Self::List),
+      Self::Nested { fieldset, required } => {
+        return match fieldset.bind(registry) {
+          Ok(fieldset) => Some(Self::nested(fieldset, required)),
+          Err(nested) => {
+            violations.merge_prefixed(name, nested);
+            None
+          }
+        };
+      }
+    };
+    result.map_err(|err| violations.add(name, err.into())).ok()
+  }
+}
+
+/// Binds `field`'s filters and fallible filters against `registry`.
+fn bind_field<T: Clone + 'static>(
+  mut field: Field<T>,
+  registry: &FilterRegistry<T>,
+) -> Result<Field<T>, FilterError> {
+  field.filters = field
+    .filters
+    .map(|ops| ops.into_iter().map(|op| registry.bind(op)).collect())
+    .transpose()?;
+  field.try_filters = field
+    .try_filters
+    .map(|ops| ops.into_iter().map(|op| registry.bind_try(op)).collect())
+    .transpose()?;
+  Ok(field)
+}
+
+/// The [`FilterRegistry`]s a [`DynamicFieldset`]'s `Named` filters are bound against,
+/// one per [`DynamicField`] type.
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_fieldfilter::{DynamicFieldset, DynamicFilterRegistry};
+/// use serde_json::json;
+///
+/// let mut registry = DynamicFilterRegistry::default();
+/// registry.string.register("initials", |value, _| {
+///     value.split_whitespace().filter_map(|w| w.chars().next()).collect()
+/// });
+///
+/// let fieldset: DynamicFieldset = serde_json::from_value(json!({
+///     "fields": {
+///         "name": {"type": "string", "filters": [{"type": "Named", "config": {"name": "initials"}}]}
+///     }
+/// }))
+/// .unwrap();
+/// let fieldset = fieldset.bind(&registry).unwrap();
+///
+/// let values = json!({"name": "Ada Lovelace"});
+/// let filtered = fieldset.filter(values.as_object().unwrap().clone()).unwrap();
+/// assert_eq!(filtered["name"], "AL");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DynamicFilterRegistry {
+  /// Functions for `string` fields.
+  pub string: FilterRegistry<String>,
+
+  /// Functions for `integer` fields.
+  pub integer: FilterRegistry<i64>,
+
+  /// Functions for `number` fields.
+  pub number: FilterRegistry<f64>,
+
+  /// Functions for `bool` fields.
+  pub bool: FilterRegistry<bool>,
+
+  /// Functions for `list` fields.
+  pub list: FilterRegistry<Vec<String>>,
+}
+
+impl From<Field<String>> for DynamicField {
+  fn from(field: Field<String>) -> Self {
+    Self::String(field)
+  }
+}
+
+impl From<Field<i64>> for DynamicField {
+  fn from(field: Field<i64>) -> Self {
+    Self::Integer(field)
+  }
+}
+
+impl From<Field<f64>> for DynamicField {
+  fn from(field: Field<f64>) -> Self {
+    Self::Number(field)
+  }
+}
+
+impl From<Field<bool>> for DynamicField {
+  fn from(field: Field<bool>) -> Self {
+    Self::Bool(field)
+  }
+}
+
+impl From<Field<Vec<String>>> for DynamicField {
+  fn from(field: Field<Vec<String>>) -> Self {
+    Self::List(field)
+  }
+}
+
+/// Extracts an optional typed value; `null` and absent keys are `None`.
+fn typed<'a, T>(
+  value: Option<&'a Value>,
+  expected: &str,
+  extract: impl FnOnce(&'a Value) -> Option<T>,
+) -> Result<Option<T>, Violations> {
+  match value {
+    None | Some(Value::Null) => Ok(None),
+    Some(value) => extract(value).map(Some).ok_or_else(|| {
+      Violation::new(
+        ViolationType::TypeMismatch,
+        format!("Expected {}.", expected),
+      )
+      .into()
+    }),
+  }
+}
+
+fn string_list(value: &Value) -> Option<Vec<String>> {
+  value
+    .as_array()?
+    .iter()
+    .map(|item| item.as_str().map(str::to_string))
+    .collect()
+}
+
+/// Whether `name` carries a value, matching the derive's presence check: absent keys,
+/// `null`, and blank strings are empty; anything else is present.
+fn has_value(values: &Map<String, Value>, name: &str) -> bool {
+  match values.get(name) {
+    None | Some(Value::Null) => false,
+    Some(Value::String(s)) => !s.trim().is_empty(),
+    Some(_) => true,
+  }
+}
+
+/// Returns the value under `name`, treating absent keys as `null`.
+fn value_of<'a>(values: &'a Map<String, Value>, name: &str) -> &'a Value {
+  values.get(name).unwrap_or(&Value::Null)
+}
+
+/// A rule spanning several fields of a [`DynamicFieldset`].
+///
+/// Mirrors the derive's `#[cross_validate(...)]` rules, with the same violation
+/// types and messages. Violations are form-level (the `""` key). Serialized with an
+/// internal `type` tag: `{"type": "fields_equal", "field_a": "password", ...}`.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CrossValidateRule {
+  /// Both fields hold equal values.
+  FieldsEqual { field_a: String, field_b: String },
+
+  /// `field` is required when `condition_field` equals `value`.
+  RequiredIf {
+    field: String,
+    condition_field: String,
+    value: Value,
+  },
+
+  /// `field` is required unless `condition_field` equals `value`.
+  RequiredUnless {
+    field: String,
+    condition_field: String,
+    value: Value,
+  },
+
+  /// At least one of `fields` has a value.
+  OneOfRequired { fields: Vec<String> },
+
+  /// At most one of `fields` has a value.
+  MutuallyExclusive { fields: Vec<String> },
+
+  /// Every field in `dependents` is required when `trigger` has a value.
+  DependentRequired {
+    trigger: String,
+    dependents: Vec<String>,
+  },
+
+  /// Custom check over all submitted values (not serializable).
+  #[serde(skip)]
+  Custom(CrossValidateFn),
+}
+
+impl CrossValidateRule {
+  /// Checks the rule against `values`, adding form-level violations.
+  fn validate_into(&self, values: &Map<String, Value>, violations: &mut FieldsetViolations) {
+    match self {
+      Self::FieldsEqual { field_a, field_b } => {
+        if value_of(values, field_a) != value_of(values, field_b) {
+          violations.add_form_violation(Violation::new(
+            ViolationType::NotEqual,
+            format!("FieldsEqual: {} and {} must be equal", field_a, field_b),
+          ));
+        }
+      }
+      Self::RequiredIf {
+        field,
+        condition_field,
+        value,
+      } => {
+        if value_of(values, condition_field) == value && !has_value(values, field) {
+          violations.add_form_violation(Violation::new(
+            ViolationType::ValueMissing,
+            format!(
+              "RequiredIf: {} is required when condition is met on {}",
+              field, condition_field
+            ),
+          ));
+        }
+      }
+      Self::RequiredUnless {
+        field,
+        condition_field,
+        value,
+      } => {
+        if value_of(values, condition_field) != value && !has_value(values, field) {
+          violations.add_form_violation(Violation::new(
+            ViolationType::ValueMissing,
+            format!(
+              "RequiredUnless: {} is required unless condition is met on {}",
+              field, condition_field
+            ),
+          ));
+        }
+      }
+      Self::OneOfRequired { fields } => {
+        if !fields.iter().any(|name| has_value(values, name)) {
+          violations.add_form_violation(Violation::new(
+            ViolationType::ValueMissing,
+            format!(
+              "OneOfRequired: At least one of {} is required",
+              fields.join(", ")
+            ),
+          ));
+        }
+      }
+      Self::MutuallyExclusive { fields } => {
+        if fields.iter().filter(|name| has_value(values, name)).count() > 1 {
+          violations.add_form_violation(Violation::new(
+            ViolationType::CustomError,
+            format!(
+              "MutuallyExclusive: Only one of {} can have a value",
+              fields.join(", ")
+            ),
+          ));
+        }
+      }
+      Self::DependentRequired {
+        trigger,
+        dependents,
+      } => {
+        if has_value(values, trigger) {
+          for dependent in dependents {
+            if !has_value(values, dependent) {
+              violations.add_form_violation(Violation::new(
+                ViolationType::ValueMissing,
+                format!(
+                  "DependentRequired: {} is required when {} is provided",
+                  dependent, trigger
+                ),
+              ));
+            }
+          }
+        }
+      }
+      Self::Custom(check) => {
+        if let Err(violation) = check(values) {
+          violations.add_form_violation(violation);
+        }
+      }
+    }
+  }
+}
+
+impl fmt::Debug for CrossValidateRule {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::FieldsEqual { field_a, field_b } => f
+        .debug_struct("FieldsEqual")
+        .field("field_a", field_a)
+        .field("field_b", field_b)
+        .finish(),
+      Self::RequiredIf {
+        field,
+        condition_field,
+        value,
+      } => f
+        .debug_struct("RequiredIf")
+        .field("field", field)
+        .field("condition_field", condition_field)
+        .field("value", value)
+        .finish(),
+      Self::RequiredUnless {
+        field,
+        condition_field,
+        value,
+      } => f
+        .debug_struct("RequiredUnless")
+        .field("field", field)
+        .field("condition_field", condition_field)
+        .field("value", value)
+        .finish(),
+      Self::OneOfRequired { fields } => f
+        .debug_struct("OneOfRequired")
+        .field("fields", fields)
+        .finish(),
+      Self::MutuallyExclusive { fields } => f
+        .debug_struct("MutuallyExclusive")
+        .field("fields", fields)
+        .finish(),
+      Self::DependentRequired {
+        trigger,
+        dependents,
+      } => f
+        .debug_struct("DependentRequired")
+        .field("trigger", trigger)
+        .field("dependents", dependents)
+        .finish(),
+      Self::Custom(_) => write!(f, "Custom(<fn>)"),
+    }
+  }
+}
+
+/// An ordered set of named fields defined at runtime.
+///
+/// Fields are processed in insertion order. Keys in the submitted map that are not
+/// declared as fields are ignored by validation and passed through `filter`
+/// unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_fieldfilter::DynamicFieldset;
+/// use serde_json::json;
+///
+/// let fieldset: DynamicFieldset = serde_json::from_value(json!({
+///     "fields": {
+///         "email": {
+///             "type": "string",
+///             "filters": [{"type": "Trim"}, {"type": "Lowercase"}],
+///             "rule": {"type": "all", "config": [{"type": "required"}, {"type": "minlength", "config": 3}]}
+///         },
+///         "age": {"type": "integer", "rule": {"type": "min", "config": 18}},
+///         "address": {
+///             "type": "nested",
+///             "fieldset": {"fields": {"zip": {"type": "string", "rule": {"type": "required"}}}}
+///         }
+///     },
+///     "cross_validate": [
+///         {"type": "dependent_required", "trigger": "age", "dependents": ["address"]}
+///     ]
+/// }))
+/// .unwrap();
+///
+/// let values = json!({"email": "  Ada@Example.COM ", "age": 36, "address": {"zip": "10001"}});
+/// let sanitized = fieldset.sanitize(values.as_object().unwrap().clone()).unwrap();
+/// assert_eq!(sanitized["email"], "ada@example.com");
+///
+/// let values = json!({"email": "ada@example.com", "age": 12, "address": {"zip": ""}});
+/// let violations = fieldset.validate(values.as_object().unwrap()).unwrap_err();
+/// assert!(violations.get("age").is_some());
+/// assert!(violations.get("address.zip").is_some());
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DynamicFieldset {
+  /// Fields by name, in processing order.
+  #[serde(default)]
+  pub fields: IndexMap<String, DynamicField>,
+
+  /// Rules checked after all fields, in order.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub cross_validate: Vec<CrossValidateRule>,
+
+  /// If `true`, validation stops after the first field with violations. A field's
+  /// own `break_on_failure` flag has the same effect for that field.
+  #[serde(default)]
+  pub break_on_failure: bool,
+}
+
+impl DynamicFieldset {
+  /// Creates an empty fieldset.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds (or replaces) the field `name`, keeping its original position on replace.
+  pub fn field(mut self, name: impl Into<String>, field: impl Into<DynamicField>) -> Self {
+    self.fields.insert(name.into(), field.into());
+    self
+  }
+
+  /// Appends a cross-field rule.
+  pub fn cross_validate(mut self, rule: CrossValidateRule) -> Self {
+    self.cross_validate.push(rule);
+    self
+  }
+
+  /// Sets [`break_on_failure`](Self::break_on_failure).
+  pub fn break_on_failure(mut self, break_on_failure: bool) -> Self {
+    self.break_on_failure = break_on_failure;
+    self
+  }
+
+  /// Binds every field's [`Named`](walrs_filter::FilterOp::Named) filters (including
+  /// in nested fieldsets) against `registry`, so unknown names are reported when the
+  /// config is loaded rather than skipped when values are filtered.
+  ///
+  /// # Errors
+  ///
+  /// Returns an `UnknownFilter` violation under each field (dotted for nested fields)
+  /// that names an unregistered function.
+  pub fn bind(self, registry: &DynamicFilterRegistry) -> Result<Self, FieldsetViolations> {
+    let mut violations = FieldsetViolations::new();
+    let fields = self
+      .fields
+      .into_iter()
+      .filter_map(|(name, field)| {
+        let field = field.bind(&name, registry, &mut violations)?;
+        Some((name, field))
+      })
+      .collect();
+    if violations.is_empty() {
+      Ok(Self { fields, ..self })
+    } else {
+      Err(violations)
+    }
+  }
+
+  /// Validates `values` against every field and then the cross-field rules.
+  ///
+  /// An absent or `null` value is validated like `None` by
+  /// [`Field::validate_option`]; a value of the wrong JSON type is a
+  /// `TypeMismatch` violation.
+  pub fn validate(&self, values: &Map<String, Value>) -> Result<(), FieldsetViolations> {
+    let mut violations = FieldsetViolations::new();
+    for (name, field) in &self.fields {
+      let failed = field.validate_into(name, values.get(name), &mut violations);
+      if failed && (self.break_on_failure || field.break_on_failure()) {
+        return Err(violations);
+      }
+    }
+    for rule in &self.cross_validate {
+      rule.validate_into(values, &mut violations);
+    }
+    violations.into()
+  }
+
+  /// Applies each field's filters and fallible filters to its value.
+  ///
+  /// Absent values stay absent and `null` stays `null`. Errors from fallible filters
+  /// are collected for every field before returning.
+  pub fn filter(
+    &self,
+    mut values: Map<String, Value>,
+  ) -> Result<Map<String, Value>, FieldsetViolations> {
+    let mut violations = FieldsetViolations::new();
+    for (name, field) in &self.fields {
+      let Some(value) = values.get_mut(name) else {
+        continue;
+      };
+      if value.is_null() {
+        continue;
+      }
+      match field.filter_value(name, value.take()) {
+        Ok(filtered) => *value = filtered,
+        Err(errors) => {
+          violations.merge(errors);
+        }
+      }
+    }
+    if violations.is_empty() {
+      Ok(values)
+    } else {
+      Err(violations)
+    }
+  }
+
+  /// Filter and then validate (convenience method).
+  pub fn sanitize(
+    &self,
+    values: Map<String, Value>,
+  ) -> Result<Map<String, Value>, FieldsetViolations> {
+    let filtered = self.filter(values)?;
+    self.validate(&filtered)?;
+    Ok(filtered)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::FieldBuilder;
+  use serde_json::json;
+  use walrs_filter::FilterOp;
+  use walrs_validation::Rule;
+
+  fn object(value: Value) -> Map<String, Value> {
+    match value {
+      Value::Object(map) => map,
+      _ => panic!("expected an object"),
+    }
+  }
+
+  fn contact_fieldset() -> DynamicFieldset {
+    DynamicFieldset::new()
+      .field(
+        "name",
+        FieldBuilder::<String>::default()
+          .filters(vec![FilterOp::Trim])
+          .rule(Rule::Required)
+          .build()
+          .unwrap(),
+      )
+      .field(
+        "age",
+        FieldBuilder::<i64>::default()
+          .rule(Rule::Min(18))
+          .build()
+          .unwrap(),
+      )
+      .field(
+        "tags",
+        FieldBuilder::<Vec<String>>::default()
+          .filters(vec![FilterOp::Lowercase])
+          .rule(Rule::MaxLength(2))
+          .build()
+          .unwrap(),
+      )
+  }
+
+  #[test]
+  fn test_validate() {
+    let fieldset = contact_fieldset();
+    assert!(
+      fieldset
+        .validate(&object(json!({"name": "Ada", "age": 36, "tags": ["a"]})))
+        .is_ok()
+    );
+    // Optional fields may be absent or null.
+    assert!(
+      fieldset
+        .validate(&object(json!({"name": "Ada", "age": null})))
+        .is_ok()
+    );
+
+    let err = fieldset
+      .validate(&object(json!({"age": 12, "tags": ["a", "b", "c"]})))
+      .unwrap_err();
+    assert_eq!(
+      err.get("name").unwrap()[0].violation_type(),
+      ViolationType::ValueMissing
+    );
+    assert_eq!(
+      err.get("age").unwrap()[0].violation_type(),
+      ViolationType::RangeUnderflow
+    );
+    assert_eq!(
+      err.get("tags").unwrap()[0].violation_type(),
+      ViolationType::TooLong
+    );
+    assert_eq!(
+      err.field_names().collect::<Vec<_>>(),
+      ["name", "age", "tags"]
+    );
+  }
+
+  #[test]
+  fn test_validate_type_mismatch() {
+    let fieldset = contact_fieldset();
+    let err = fieldset
+      .validate(&object(json!({"name": 1, "age": 20.5, "tags": [1]})))
+      .unwrap_err();
+    for name in ["name", "age", "tags"] {
+      assert_eq!(
+        err.get(name).unwrap()[0].violation_type(),
+        ViolationType::TypeMismatch
+      );
+    }
+    assert_eq!(err.get("name").unwrap()[0].message(), "Expected a string.");
+  }
+
+  #[test]
+  fn test_break_on_failure() {
+    let values = object(json!({"age": 12}));
+    assert_eq!(contact_fieldset().validate(&values).unwrap_err().len(), 2);
+    let err = contact_fieldset()
+      .break_on_failure(true)
+      .validate(&values)
+      .unwrap_err();
+    assert_eq!(err.len(), 1);
+    assert!(err.get("name").is_some());
+  }
+
+  #[test]
+  fn test_filter_and_sanitize() {
+    let fieldset = contact_fieldset();
+    let filtered = fieldset
+      .filter(object(
+        json!({"name": "  Ada  ", "tags": ["A", "B"], "extra": " x "}),
+      ))
+      .unwrap();
+    assert_eq!(
+      Value::Object(filtered),
+      json!({"name": "Ada", "tags": ["a", "b"], "extra": " x "})
+    );
+
+    let err = fieldset
+      .sanitize(object(json!({"name": "   "})))
+      .unwrap_err();
+    assert!(err.get("name").is_some());
+  }
+
+  #[test]
+  fn test_filter_error() {
+    let fieldset = DynamicFieldset::new().field(
+      "data",
+      FieldBuilder::<String>::default()
+        .try_filters(vec![walrs_filter::TryFilterOp::TryCustom(Arc::new(
+          |_: String| Err(walrs_filter::FilterError::new("bad data")),
+        ))])
+        .build()
+        .unwrap(),
+    );
+    let err = fieldset.filter(object(json!({"data": "x"}))).unwrap_err();
+    assert!(err.get("data").is_some());
+    // Absent values are not filtered.
+    assert!(fieldset.filter(Map::new()).is_ok());
+  }
+
+  #[test]
+  fn test_nested() {
+    let address = DynamicFieldset::new().field(
+      "zip",
+      FieldBuilder::<String>::default()
+        .filters(vec![FilterOp::Trim])
+        .rule(Rule::Required)
+        .build()
+        .unwrap(),
+    );
+    let fieldset = DynamicFieldset::new()
+      .field("address", DynamicField::nested(address.clone(), true))
+      .field("billing", DynamicField::nested(address, false));
+
+    let err = fieldset
+      .validate(&object(json!({"address": {"zip": ""}})))
+      .unwrap_err();
+    assert!(err.get("address.zip").is_some());
+    assert!(err.get("billing").is_none());
+
+    let err = fieldset
+      .validate(&object(json!({"billing": "x"})))
+      .unwrap_err();
+    assert_eq!(
+      err.get("address").unwrap()[0].violation_type(),
+      ViolationType::ValueMissing
+    );
+    assert_eq!(
+      err.get("billing").unwrap()[0].violation_type(),
+      ViolationType::TypeMismatch
+    );
+
+    let sanitized = fieldset
+      .sanitize(object(json!({"address": {"zip": " 10001 "}})))
+      .unwrap();
+    assert_eq!(sanitized["address"]["zip"], "10001");
+  }
+
+  #[test]
+  fn test_cross_validate() {
+    let fieldset = DynamicFieldset::new()
+      .cross_validate(CrossValidateRule::FieldsEqual {
+        field_a: "password".into(),
+        field_b: "confirm".into(),
+      })
+      .cross_validate(CrossValidateRule::RequiredIf {
+        field: "company".into(),
+        condition_field: "kind".into(),
+        value: json!("business"),
+      })
+      .cross_validate(CrossValidateRule::RequiredUnless {
+        field: "phone".into(),
+        condition_field: "contact_by_email".into(),
+        value: json!(true),
+      })
+      .cross_validate(CrossValidateRule::OneOfRequired {
+        fields: vec!["email".into(), "phone".into()],
+      })
+      .cross_validate(CrossValidateRule::MutuallyExclusive {
+        fields: vec!["card".into(), "iban".into()],
+      })
+      .cross_validate(CrossValidateRule::DependentRequired {
+        trigger: "street".into(),
+        dependents: vec!["city".into(), "zip".into()],
+      });
+
+    assert!(
+      fieldset
+        .validate(&object(json!({
+          "password": "x", "confirm": "x", "kind": "personal",
+          "contact_by_email": true, "email": "a@b.c", "card": "1"
+        })))
+        .is_ok()
+    );
+
+    let err = fieldset
+      .validate(&object(json!({
+        "password": "x", "confirm": "y", "kind": "business", "company": "  ",
+        "card": "1", "iban": "2", "street": "Main", "city": "Town"
+      })))
+      .unwrap_err();
+    let messages: Vec<&str> = err
+      .form_violations()
+      .unwrap()
+      .iter()
+      .map(Violation::message)
+      .collect();
+    assert_eq!(
+      messages,
+      [
+        "FieldsEqual: password and confirm must be equal",
+        "RequiredIf: company is required when condition is met on kind",
+        "RequiredUnless: phone is required unless condition is met on contact_by_email",
+        "OneOfRequired: At least one of email, phone is required",
+        "MutuallyExclusive: Only one of card, iban can have a value",
+        "DependentRequired: zip is required when street is provided",
+      ]
+    );
+  }
+
+  #[test]
+  fn test_custom_cross_validate() {
+    let fieldset =
+      DynamicFieldset::new().cross_validate(CrossValidateRule::Custom(Arc::new(|values| {
+        if values.len() > 1 {
+          Err(Violation::new(
+            ViolationType::CustomError,
+            "Too many values",
+          ))
+        } else {
+          Ok(())
+        }
+      })));
+    assert!(fieldset.validate(&object(json!({"a": 1}))).is_ok());
+    let err = fieldset
+      .validate(&object(json!({"a": 1, "b": 2})))
+      .unwrap_err();
+    assert_eq!(
+      err.form_violations().unwrap()[0].message(),
+      "Too many values"
+    );
+  }
+
+  #[test]
+  fn test_number_keeps_submitted_representation() {
+    let fieldset = DynamicFieldset::new()
+      .field(
+        "qty",
+        FieldBuilder::<f64>::default()
+          .filters(vec![FilterOp::Clamp {
+            min: 0.0,
+            max: 10.0,
+          }])
+          .build()
+          .unwrap(),
+      )
+      .field(
+        "ratio",
+        FieldBuilder::<f64>::default()
+          .filters(vec![FilterOp::Custom(Arc::new(|x: f64| x / 0.0 - x / 0.0))])
+          .build()
+          .unwrap(),
+      );
+    let filtered = fieldset.filter(object(json!({"qty": 5}))).unwrap();
+    assert_eq!(serde_json::to_string(&filtered).unwrap(), r#"{"qty":5}"#);
+    let filtered = fieldset.filter(object(json!({"qty": 12}))).unwrap();
+    assert_eq!(serde_json::to_string(&filtered).unwrap(), r#"{"qty":10.0}"#);
+
+    // A filter producing NaN is reported instead of writing `null`.
+    let err = fieldset.filter(object(json!({"ratio": 1}))).unwrap_err();
+    assert_eq!(
+      err.get("ratio").unwrap()[0].violation_type(),
+      ViolationType::TypeMismatch
+    );
+  }
+
+  #[test]
+  fn test_bind() {
+    let config = json!({
+      "fields": {
+        "name": {"type": "string", "filters": [{"type": "Named", "config": {"name": "shout"}}]},
+        "age": {"type": "integer", "try_filters": [{"type": "Named", "config": {"name": "adult"}}]},
+        "address": {"type": "nested", "fieldset": {"fields": {
+          "zip": {"type": "string", "filters": [{"type": "Named", "config": {"name": "shout"}}]}
+        }}}
+      }
+    });
+    let fieldset: DynamicFieldset = serde_json::from_value(config).unwrap();
+    let serialized = serde_json::to_value(&fieldset).unwrap();
+
+    // Unknown names are reported for every field, nested ones under dotted keys.
+    let err = fieldset
+      .clone()
+      .bind(&DynamicFilterRegistry::default())
+      .unwrap_err();
+    assert_eq!(
+      err.field_names().collect::<Vec<_>>(),
+      ["address.zip", "age", "name"]
+    );
+    assert_eq!(
+      walrs_filter::FilterErrorCode::from_violation(&err.get("age").unwrap()[0]),
+      Some(walrs_filter::FilterErrorCode::UnknownFilter)
+    );
+
+    let mut registry = DynamicFilterRegistry::default();
+    registry
+      .string
+      .register("shout", |value: String, _: &Value| value.to_uppercase());
+    registry
+      .integer
+      .register_try("adult", |value: i64, _: &Value| {
+        if value >= 18 {
+          Ok(value)
+        } else {
+          Err(walrs_filter::FilterError::new("too young"))
+        }
+      });
+    let fieldset = fieldset.bind(&registry).unwrap();
+    let filtered = fieldset
+      .filter(object(
+        json!({"name": "ada", "age": 36, "address": {"zip": "ab1"}}),
+      ))
+      .unwrap();
+    assert_eq!(
+      Value::Object(filtered),
+      json!({"name": "ADA", "age": 36, "address": {"zip": "AB1"}})
+    );
+    assert!(
+      fieldset
+        .filter(object(json!({"age": 12})))
+        .unwrap_err()
+        .get("age")
+        .is_some()
+    );
+    // The bound fieldset still serializes to its config.
+    assert_eq!(serde_json::to_value(&fieldset).unwrap(), serialized);
+  }
+
+  #[test]
+  fn test_deserialize() {
+    let fieldset: DynamicFieldset = serde_json::from_str(
+      r#"{
+        "fields": {
+          "title": {"type": "string", "filters": [{"type": "Trim"}], "rule": {"type": "required"}},
+          "price": {"type": "number", "rule": {"type": "min", "config": 0.0}},
+          "published": {"type": "bool"},
+          "meta": {"type": "nested", "required": true, "fieldset": {
+            "fields": {"slug": {"type": "string", "rule": {"type": "required"}}}
+          }}
+        },
+        "cross_validate": [{"type": "one_of_required", "fields": ["title", "price"]}],
+        "break_on_failure": false
+      }"#,
+    )
+    .unwrap();
+    assert_eq!(
+      fieldset.fields.keys().collect::<Vec<_>>(),
+      ["title", "price", "published", "meta"]
+    );
+
+    let err = fieldset
+      .sanitize(object(
+        json!({"title": " ", "price": -1, "published": "yes", "meta": {}}),
+      ))
+      .unwrap_err();
+    assert_eq!(
+      err.field_names().collect::<Vec<_>>(),
+      ["title", "price", "published", "meta.slug"]
+    );
+
+    // Round-trips through serialization.
+    let json = serde_json::to_value(&fieldset).unwrap();
+    let again: DynamicFieldset = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&again).unwrap(), json);
+  }
+}
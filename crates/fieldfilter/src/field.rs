@@ -13,6 +13,8 @@ use walrs_validation::{Rule, ValidateRef, Violation, Violations};
 #[cfg(feature = "async")]
 use walrs_validation::ValidateRefAsync;
 
+use crate::ChangeLog;
+
 /// Validation configuration for a single field.
 ///
 /// `Field<T>` provides a unified API for field validation and filtering,
@@ -94,6 +96,7 @@ where
   pub try_filters: Option<Vec<TryFilterOp<T>>>,
 
   /// When true, stops validation at the first error.
+  #[serde(default)]
   #[builder(default = "false")]
   pub break_on_failure: bool,
 }
@@ -147,10 +150,10 @@ impl<T: Clone> Field<T> {
 }
 
 // ============================================================================
-// FieldOps trait — private abstraction for Field<String>
+// FieldOps trait — private abstraction over supported value types
 // ============================================================================
 
-/// Private trait for `Field<String>` method bodies.
+/// Private trait backing the `Field<T>` method bodies for each supported `T`.
 trait FieldOps: Clone + Sized {
   /// The reference type for validation (`String` → `str`).
   type ValueRef: ?Sized;
@@ -172,6 +175,13 @@ trait FieldOps: Clone + Sized {
     filter: &TryFilterOp<Self>,
     value: Self,
   ) -> Result<Self, walrs_filter::FilterError>;
+
+  /// Apply a single fallible filter to an owned value, awaiting async steps.
+  #[cfg(feature = "async")]
+  async fn try_apply_filter_async(
+    filter: &TryFilterOp<Self>,
+    value: Self,
+  ) -> Result<Self, walrs_filter::FilterError>;
 }
 
 impl FieldOps for String {
@@ -213,8 +223,84 @@ impl FieldOps for String {
   ) -> Result<Self, walrs_filter::FilterError> {
     filter.try_apply(value)
   }
+
+  #[cfg(feature = "async")]
+  async fn try_apply_filter_async(
+    filter: &TryFilterOp<Self>,
+    value: Self,
+  ) -> Result<Self, walrs_filter::FilterError> {
+    filter.try_apply_async(value).await
+  }
+}
+
+/// Implements [`FieldOps`] for types validated and filtered by value (`ValueRef = Self`).
+macro_rules! impl_field_ops {
+  ($($t:ty),*) => {
+    $(
+      impl FieldOps for $t {
+        type ValueRef = $t;
+
+        fn apply_filters_from_ref(filters: &[FilterOp<Self>], value: &$t) -> $t {
+          Self::apply_filters(filters, value.clone())
+        }
+
+        fn apply_filters(filters: &[FilterOp<Self>], value: $t) -> $t {
+          filters.iter().fold(value, |value, f| f.apply(value))
+        }
+
+        fn ref_to_owned(value: &$t) -> $t {
+          value.clone()
+        }
+
+        fn as_value_ref(&self) -> &$t {
+          self
+        }
+
+        fn try_apply_filter(
+          filter: &TryFilterOp<Self>,
+          value: Self,
+        ) -> Result<Self, walrs_filter::FilterError> {
+          filter.try_apply(value)
+        }
+
+        #[cfg(feature = "async")]
+        async fn try_apply_filter_async(
+          filter: &TryFilterOp<Self>,
+          value: Self,
+        ) -> Result<Self, walrs_filter::FilterError> {
+          filter.try_apply_async(value).await
+        }
+      }
+    )*
+  };
 }
 
+impl_field_ops!(
+  i8,
+  i16,
+  i32,
+  i64,
+  i128,
+  isize,
+  u8,
+  u16,
+  u32,
+  u64,
+  u128,
+  usize,
+  f32,
+  f64,
+  bool,
+  char,
+  Vec<String>
+);
+
+#[cfg(feature = "chrono")]
+impl_field_ops!(chrono::NaiveDate, chrono::NaiveDateTime);
+
+#[cfg(feature = "jiff")]
+impl_field_ops!(jiff::civil::Date, jiff::civil::DateTime);
+
 // ============================================================================
 // Private generic helper functions
 // ============================================================================
@@ -233,6 +319,16 @@ fn filter_impl<T: FieldOps>(field: &Field<T>, value: T) -> T {
   }
 }
 
+/// Fails with `UnknownFilter` when a `Named` filter was never bound to a registry, since
+/// the infallible filters would pass the value through it.
+fn ensure_filters_bound<T: FieldOps>(field: &Field<T>) -> Result<(), Violations> {
+  for (step, f) in field.filters.iter().flatten().enumerate() {
+    f.ensure_bound()
+      .map_err(|e| Violations::new(vec![e.with_step(step).into()]))?;
+  }
+  Ok(())
+}
+
 fn try_filter_ref_impl<T: FieldOps>(
   field: &Field<T>,
   value: &T::ValueRef,
@@ -240,9 +336,9 @@ fn try_filter_ref_impl<T: FieldOps>(
   match &field.try_filters {
     Some(try_filters) => {
       let mut result = T::ref_to_owned(value);
-      for f in try_filters {
+      for (step, f) in try_filters.iter().enumerate() {
         result = T::try_apply_filter(f, result).map_err(|e| -> Violations {
-          let violation: Violation = e.into();
+          let violation: Violation = e.with_step(step).into();
           Violations::new(vec![violation])
         })?;
       }
@@ -256,9 +352,9 @@ fn try_filter_impl<T: FieldOps>(field: &Field<T>, value: T) -> Result<T, Violati
   match &field.try_filters {
     Some(try_filters) => {
       let mut result = value;
-      for f in try_filters {
+      for (step, f) in try_filters.iter().enumerate() {
         result = T::try_apply_filter(f, result).map_err(|e| -> Violations {
-          let violation: Violation = e.into();
+          let violation: Violation = e.with_step(step).into();
           Violations::new(vec![violation])
         })?;
       }
@@ -298,12 +394,48 @@ fn sanitize_impl<T: FieldOps>(field: &Field<T>, value: T) -> Result<T, Violation
 where
   Rule<T>: ValidateRef<T::ValueRef>,
 {
+  ensure_filters_bound(field)?;
   let filtered = filter_impl(field, value);
   let filtered = try_filter_impl(field, filtered)?;
   validate_ref_impl(field, filtered.as_value_ref())?;
   Ok(filtered)
 }
 
+/// `None` fails only when the rule requires a value, matching the
+/// `Validate<Option<T>>` impls in `walrs_validation`.
+fn missing_impl<T: FieldOps>(field: &Field<T>) -> Result<(), Violations> {
+  match &field.rule {
+    Some(rule) if rule.requires_value() => Err(Violations::new(vec![Violation::value_missing()])),
+    _ => Ok(()),
+  }
+}
+
+fn validate_option_impl<T: FieldOps>(
+  field: &Field<T>,
+  value: Option<&T::ValueRef>,
+) -> Result<(), Violations>
+where
+  Rule<T>: ValidateRef<T::ValueRef>,
+{
+  match value {
+    Some(value) => validate_ref_impl(field, value),
+    None => missing_impl(field),
+  }
+}
+
+fn sanitize_option_impl<T: FieldOps>(
+  field: &Field<T>,
+  value: Option<T>,
+) -> Result<Option<T>, Violations>
+where
+  Rule<T>: ValidateRef<T::ValueRef>,
+{
+  match value {
+    Some(value) => sanitize_impl(field, value).map(Some),
+    None => missing_impl(field).map(|()| None),
+  }
+}
+
 #[cfg(feature = "async")]
 async fn validate_ref_async_impl<T: FieldOps>(
   field: &Field<T>,
@@ -334,18 +466,48 @@ where
   }
 }
 
+#[cfg(feature = "async")]
+async fn try_filter_async_impl<T: FieldOps>(field: &Field<T>, value: T) -> Result<T, Violations> {
+  let mut result = value;
+  for (step, f) in field.try_filters.iter().flatten().enumerate() {
+    result = T::try_apply_filter_async(f, result)
+      .await
+      .map_err(|e| -> Violations {
+        let violation: Violation = e.with_step(step).into();
+        Violations::new(vec![violation])
+      })?;
+  }
+  Ok(result)
+}
+
 #[cfg(feature = "async")]
 async fn sanitize_async_impl<T: FieldOps>(field: &Field<T>, value: T) -> Result<T, Violations>
 where
   T::ValueRef: Sync,
   Rule<T>: ValidateRefAsync<T::ValueRef>,
 {
+  ensure_filters_bound(field)?;
   let filtered = filter_impl(field, value);
-  let filtered = try_filter_impl(field, filtered)?;
+  let filtered = try_filter_async_impl(field, filtered).await?;
   validate_ref_async_impl(field, filtered.as_value_ref()).await?;
   Ok(filtered)
 }
 
+#[cfg(feature = "async")]
+async fn sanitize_option_async_impl<T: FieldOps>(
+  field: &Field<T>,
+  value: Option<T>,
+) -> Result<Option<T>, Violations>
+where
+  T::ValueRef: Sync,
+  Rule<T>: ValidateRefAsync<T::ValueRef>,
+{
+  match value {
+    Some(value) => sanitize_async_impl(field, value).await.map(Some),
+    None => missing_impl(field).map(|()| None),
+  }
+}
+
 // ============================================================================
 // String Field Implementation
 // ============================================================================
@@ -401,7 +563,9 @@ impl Field<String> {
   /// Filter the value and then validate it.
   ///
   /// Applies infallible filters first, then fallible filters, then validates.
-  /// Returns `Ok(filtered_value)` if all steps pass, or `Err(Violations)`.
+  /// Returns `Ok(filtered_value)` if all steps pass, or `Err(Violations)`. A
+  /// [`Named`](FilterOp::Named) filter that was never bound to a registry fails with
+  /// `UnknownFilter` instead of being skipped.
   pub fn sanitize(&self, value: String) -> Result<String, Violations> {
     sanitize_impl(self, value)
   }
@@ -411,11 +575,58 @@ impl Field<String> {
   /// Like [`sanitize`](Self::sanitize) but starts from a `&str` reference,
   /// avoiding the need for the caller to allocate a `String` up-front.
   pub fn sanitize_ref(&self, value: &str) -> Result<String, Violations> {
+    ensure_filters_bound(self)?;
     let filtered = self.filter_ref(value);
     let filtered = self.try_filter(filtered)?;
     self.validate_ref(&filtered)?;
     Ok(filtered)
   }
+
+  /// Validate an optional value. `None` fails only if the rule requires a value
+  /// (`Rule::Required`, possibly inside `All` or `WithMessage`).
+  pub fn validate_option(&self, value: Option<&str>) -> Result<(), Violations> {
+    validate_option_impl(self, value)
+  }
+
+  /// Sanitize an optional value. `Some` is filtered and validated like
+  /// [`sanitize`](Self::sanitize); `None` skips the filters and is checked as in
+  /// [`validate_option`](Self::validate_option).
+  pub fn sanitize_option(&self, value: Option<String>) -> Result<Option<String>, Violations> {
+    sanitize_option_impl(self, value)
+  }
+
+  /// Like [`filter`](Self::filter), also recording every filter step in `log`
+  /// under the field's name (or `""` when unnamed).
+  pub fn filter_traced(&self, value: String, log: &mut ChangeLog) -> String {
+    let name = self.name.as_deref().unwrap_or_default();
+    self.filters.iter().flatten().fold(value, |value, f| {
+      let (value, trace) = f.apply_traced(value, log.values());
+      log.record(name, trace);
+      value
+    })
+  }
+
+  /// Like [`sanitize`](Self::sanitize), also recording every filter step in `log`
+  /// under the field's name (or `""` when unnamed).
+  ///
+  /// Steps applied before a failing fallible filter stay in the log.
+  pub fn sanitize_traced(&self, value: String, log: &mut ChangeLog) -> Result<String, Violations> {
+    ensure_filters_bound(self)?;
+    let name = self.name.as_deref().unwrap_or_default();
+    let mut filtered = self.filter_traced(value, log);
+    for (step, f) in self.try_filters.iter().flatten().enumerate() {
+      let (value, trace) =
+        f.try_apply_traced(filtered, log.values())
+          .map_err(|e| -> Violations {
+            let violation: Violation = e.with_step(step).into();
+            Violations::new(vec![violation])
+          })?;
+      log.record(name, trace);
+      filtered = value;
+    }
+    self.validate_ref(&filtered)?;
+    Ok(filtered)
+  }
 }
 
 // ============================================================================
@@ -437,7 +648,15 @@ impl Field<String> {
     self.validate_ref_async(value.as_str()).await
   }
 
-  /// Filter the value synchronously (infallible + fallible), then validate asynchronously.
+  /// Apply all fallible filters asynchronously, awaiting
+  /// [`TryCustomAsync`](TryFilterOp::TryCustomAsync) steps that
+  /// [`try_filter`](Self::try_filter) rejects.
+  pub async fn try_filter_async(&self, value: String) -> Result<String, Violations> {
+    try_filter_async_impl(self, value).await
+  }
+
+  /// Filter the value (infallible filters, then fallible filters via
+  /// [`try_filter_async`](Self::try_filter_async)), then validate asynchronously.
   ///
   /// Returns `Ok(filtered_value)` if all steps pass, or `Err(Violations)`.
   pub async fn sanitize_async(&self, value: String) -> Result<String, Violations> {
@@ -446,19 +665,185 @@ impl Field<String> {
 
   /// Like [`sanitize_async`](Self::sanitize_async) but starts from a `&str` reference.
   pub async fn sanitize_ref_async(&self, value: &str) -> Result<String, Violations> {
+    ensure_filters_bound(self)?;
     let filtered = self.filter_ref(value);
-    let filtered = self.try_filter(filtered)?;
+    let filtered = self.try_filter_async(filtered).await?;
     self.validate_ref_async(&filtered).await?;
     Ok(filtered)
   }
+
+  /// Async version of [`sanitize_option`](Self::sanitize_option).
+  pub async fn sanitize_option_async(
+    &self,
+    value: Option<String>,
+  ) -> Result<Option<String>, Violations> {
+    sanitize_option_async_impl(self, value).await
+  }
 }
 
+// ============================================================================
+// Numeric, Scalar, Date, and Collection Field Implementations
+// ============================================================================
+
+/// Implements the `Field<T>` methods for types validated by value.
+macro_rules! impl_field_methods {
+  ($($t:ty),*) => {
+    $(
+      impl Field<$t> {
+        /// Apply all filters to the value sequentially.
+        pub fn filter(&self, value: $t) -> $t {
+          filter_impl(self, value)
+        }
+
+        /// Apply all fallible filters to the value.
+        ///
+        /// Returns `Ok(filtered_value)` if all filters succeed, or `Err(Violations)` with
+        /// the filter error converted to a `Violation`.
+        pub fn try_filter(&self, value: $t) -> Result<$t, Violations> {
+          try_filter_impl(self, value)
+        }
+
+        /// Validate the value against the rule, short-circuiting on the first violation.
+        ///
+        /// If the field has a locale set, it is applied to the rule for internationalized
+        /// error messages.
+        pub fn validate_ref(&self, value: &$t) -> Result<(), Violations> {
+          validate_ref_impl(self, value)
+        }
+
+        /// Validate an owned value. Delegates to [`validate_ref`](Self::validate_ref).
+        pub fn validate(&self, value: $t) -> Result<(), Violations> {
+          self.validate_ref(&value)
+        }
+
+        /// Filter the value and then validate it.
+        ///
+        /// Applies infallible filters first, then fallible filters, then validates.
+        /// Returns `Ok(filtered_value)` if all steps pass, or `Err(Violations)`.
+        pub fn sanitize(&self, value: $t) -> Result<$t, Violations> {
+          sanitize_impl(self, value)
+        }
+
+        /// Validate an optional value. `None` fails only if the rule requires a value
+        /// (`Rule::Required`, possibly inside `All` or `WithMessage`).
+        pub fn validate_option(&self, value: Option<&$t>) -> Result<(), Violations> {
+          validate_option_impl(self, value)
+        }
+
+        /// Sanitize an optional value. `Some` is filtered and validated like
+        /// [`sanitize`](Self::sanitize); `None` skips the filters and is checked as in
+        /// [`validate_option`](Self::validate_option).
+        pub fn sanitize_option(&self, value: Option<$t>) -> Result<Option<$t>, Violations> {
+          sanitize_option_impl(self, value)
+        }
+      }
+    )*
+  };
+}
+
+/// Implements the async `Field<T>` methods for types validated by value.
+#[cfg(feature = "async")]
+macro_rules! impl_field_async_methods {
+  ($($t:ty),*) => {
+    $(
+      impl Field<$t> {
+        /// Validate the value asynchronously against the rule.
+        ///
+        /// Works like [`validate_ref`](Self::validate_ref) but supports
+        /// `Rule::CustomAsync` validators.
+        pub async fn validate_ref_async(&self, value: &$t) -> Result<(), Violations> {
+          validate_ref_async_impl(self, value).await
+        }
+
+        /// Validate an owned value asynchronously.
+        pub async fn validate_async(&self, value: $t) -> Result<(), Violations> {
+          self.validate_ref_async(&value).await
+        }
+
+        /// Apply all fallible filters asynchronously, awaiting
+        /// [`TryCustomAsync`](TryFilterOp::TryCustomAsync) steps that
+        /// [`try_filter`](Self::try_filter) rejects.
+        pub async fn try_filter_async(&self, value: $t) -> Result<$t, Violations> {
+          try_filter_async_impl(self, value).await
+        }
+
+        /// Filter the value (infallible filters, then fallible filters via
+        /// [`try_filter_async`](Self::try_filter_async)), then validate asynchronously.
+        pub async fn sanitize_async(&self, value: $t) -> Result<$t, Violations> {
+          sanitize_async_impl(self, value).await
+        }
+
+        /// Async version of [`sanitize_option`](Self::sanitize_option).
+        pub async fn sanitize_option_async(
+          &self,
+          value: Option<$t>,
+        ) -> Result<Option<$t>, Violations> {
+          sanitize_option_async_impl(self, value).await
+        }
+      }
+    )*
+  };
+}
+
+impl_field_methods!(
+  i8,
+  i16,
+  i32,
+  i64,
+  i128,
+  isize,
+  u8,
+  u16,
+  u32,
+  u64,
+  u128,
+  usize,
+  f32,
+  f64,
+  bool,
+  char,
+  Vec<String>
+);
+
+#[cfg(feature = "async")]
+impl_field_async_methods!(
+  i8,
+  i16,
+  i32,
+  i64,
+  i128,
+  isize,
+  u8,
+  u16,
+  u32,
+  u64,
+  u128,
+  usize,
+  f32,
+  f64,
+  bool,
+  char,
+  Vec<String>
+);
+
+#[cfg(feature = "chrono")]
+impl_field_methods!(chrono::NaiveDate, chrono::NaiveDateTime);
+
+#[cfg(all(feature = "chrono", feature = "async"))]
+impl_field_async_methods!(chrono::NaiveDate, chrono::NaiveDateTime);
+
+#[cfg(feature = "jiff")]
+impl_field_methods!(jiff::civil::Date, jiff::civil::DateTime);
+
+#[cfg(all(feature = "jiff", feature = "async"))]
+impl_field_async_methods!(jiff::civil::Date, jiff::civil::DateTime);
+
 #[cfg(test)]
 mod tests {
   use super::*;
   use std::sync::Arc;
-  use walrs_filter::{FilterError, TryFilterOp};
-  use walrs_validation::Rule;
+  use walrs_filter::{FilterError, FilterErrorCode, TryFilterOp};
+  use walrs_validation::{Rule, ViolationType};
 
   #[test]
   fn test_field_builder_defaults() {
@@ -703,6 +1088,21 @@ mod tests {
     assert!(err[0].message().contains("first fails"));
   }
 
+  #[test]
+  fn test_string_field_try_filter_error_records_step() {
+    let field = FieldBuilder::<String>::default()
+      .try_filters(vec![
+        TryFilterOp::Infallible(FilterOp::Trim),
+        TryFilterOp::ToInt,
+      ])
+      .build()
+      .unwrap();
+
+    let err = field.sanitize(" 4x2 ".to_string()).unwrap_err();
+    assert_eq!(err[0].violation_type(), ViolationType::TypeMismatch);
+    assert!(err[0].message().contains("at step [1]"));
+  }
+
   // ====================================================================
   // Builder with try_filters
   // ====================================================================
@@ -813,4 +1213,205 @@ mod tests {
     assert!(field.locale.is_some());
     assert!(field.rule.is_none());
   }
+
+  #[test]
+  fn test_sanitize_traced_logs_changes() {
+    let field = FieldBuilder::<String>::default()
+      .name("age")
+      .filters(vec![FilterOp::Trim])
+      .try_filters(vec![TryFilterOp::ToInt])
+      .rule(Rule::Required)
+      .build()
+      .unwrap();
+
+    let mut log = ChangeLog::new(walrs_filter::TraceValues::Full);
+    assert_eq!(
+      field
+        .sanitize_traced(" 007 ".to_string(), &mut log)
+        .unwrap(),
+      "7"
+    );
+    let steps = &log.field("age").unwrap().steps;
+    assert_eq!(steps.len(), 2);
+    assert_eq!(steps[0].after.as_deref(), Some("007"));
+    assert_eq!(steps[1].op, "ToInt");
+    assert_eq!(steps[1].after.as_deref(), Some("7"));
+
+    // Steps before a failing fallible filter are kept.
+    let mut log = ChangeLog::default();
+    assert!(field.sanitize_traced(" x ".to_string(), &mut log).is_err());
+    assert_eq!(log.changed_fields().collect::<Vec<_>>(), ["age"]);
+  }
+
+  #[test]
+  fn test_sanitize_traced_reports_failing_step() {
+    let field = FieldBuilder::<String>::default()
+      .try_filters(vec![TryFilterOp::UrlDecode, TryFilterOp::ToInt])
+      .build()
+      .unwrap();
+
+    let err = field
+      .sanitize_traced("x".to_string(), &mut ChangeLog::default())
+      .unwrap_err();
+    assert_eq!(err[0].path(), [1]);
+    assert!(err[0].message().contains("at step [1]"));
+  }
+
+  #[test]
+  fn test_sanitize_rejects_unbound_named_filters() {
+    let field = FieldBuilder::<String>::default()
+      .filters(vec![
+        FilterOp::Trim,
+        FilterOp::named("shout", serde_json::Value::Null),
+      ])
+      .build()
+      .unwrap();
+    for result in [
+      field.sanitize("a".to_string()),
+      field.sanitize_ref("a"),
+      field.sanitize_traced("a".to_string(), &mut ChangeLog::default()),
+    ] {
+      let violations = result.unwrap_err();
+      assert_eq!(
+        FilterErrorCode::from_violation(&violations[0]),
+        Some(FilterErrorCode::UnknownFilter)
+      );
+    }
+
+    let field = FieldBuilder::<i64>::default()
+      .filters(vec![FilterOp::named("double", serde_json::Value::Null)])
+      .build()
+      .unwrap();
+    assert!(field.sanitize(1).is_err());
+  }
+
+  // ====================================================================
+  // Non-String fields
+  // ====================================================================
+
+  #[test]
+  fn test_numeric_field() {
+    let field = FieldBuilder::<i64>::default()
+      .filters(vec![FilterOp::Clamp { min: 0, max: 100 }])
+      .try_filters(vec![TryFilterOp::TryCustom(Arc::new(|v: i64| {
+        if v % 2 == 0 {
+          Ok(v)
+        } else {
+          Err(FilterError::new("must be even"))
+        }
+      }))])
+      .rule(Rule::Min(10))
+      .build()
+      .unwrap();
+
+    assert_eq!(field.filter(500), 100);
+    assert_eq!(field.sanitize(500).unwrap(), 100);
+    assert!(field.sanitize(7).is_err()); // odd
+    assert!(field.sanitize(-4).is_err()); // clamped to 0, below min
+    assert!(field.validate(12).is_ok());
+    assert!(field.validate_ref(&4).is_err());
+
+    let field = FieldBuilder::<f64>::default()
+      .filters(vec![FilterOp::Round {
+        places: 1,
+        mode: Default::default(),
+      }])
+      .build()
+      .unwrap();
+    assert_eq!(field.sanitize(2.345).unwrap(), 2.3);
+  }
+
+  #[test]
+  fn test_bool_field() {
+    let field = FieldBuilder::<bool>::default()
+      .rule(Rule::Equals(true))
+      .build()
+      .unwrap();
+
+    assert!(field.sanitize(true).unwrap());
+    assert!(field.validate(false).is_err());
+  }
+
+  #[test]
+  fn test_vec_string_field() {
+    let field = FieldBuilder::<Vec<String>>::default()
+      .filters(vec![
+        FilterOp::Trim,
+        FilterOp::Lowercase,
+        FilterOp::RemoveEmpty,
+        FilterOp::Dedup,
+      ])
+      .rule(Rule::Required.and(Rule::MaxLength(2)))
+      .build()
+      .unwrap();
+
+    let tags = vec![" Rust".to_string(), "".to_string(), "rust".to_string()];
+    assert_eq!(field.sanitize(tags).unwrap(), ["rust"]);
+    assert!(field.sanitize(vec![" ".to_string()]).is_err()); // empty after filtering
+    assert!(
+      field
+        .sanitize(vec!["a".into(), "b".into(), "c".into()])
+        .is_err()
+    );
+  }
+
+  #[test]
+  fn test_option_values() {
+    let field = FieldBuilder::<u32>::default()
+      .rule(Rule::Max(10))
+      .filters(vec![FilterOp::Clamp { min: 1, max: 20 }])
+      .build()
+      .unwrap();
+    assert_eq!(field.sanitize_option(None).unwrap(), None);
+    assert_eq!(field.sanitize_option(Some(0)).unwrap(), Some(1));
+    assert!(field.sanitize_option(Some(15)).is_err());
+    assert!(field.validate_option(None).is_ok());
+
+    let field = FieldBuilder::<String>::default()
+      .filters(vec![FilterOp::Trim])
+      .rule(Rule::Required.and(Rule::MinLength(2)))
+      .build()
+      .unwrap();
+    let err = field.sanitize_option(None).unwrap_err();
+    assert_eq!(err[0].violation_type(), ViolationType::ValueMissing);
+    assert_eq!(
+      field
+        .sanitize_option(Some(" ab ".into()))
+        .unwrap()
+        .as_deref(),
+      Some("ab")
+    );
+    assert!(field.validate_option(Some("a")).is_err());
+  }
+
+  #[cfg(feature = "chrono")]
+  #[test]
+  fn test_date_field() {
+    use chrono::NaiveDate;
+
+    let field = FieldBuilder::<NaiveDate>::default()
+      .rule(Rule::Min(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap()))
+      .build()
+      .unwrap();
+
+    let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    assert_eq!(field.sanitize(date).unwrap(), date);
+    assert!(
+      field
+        .validate(NaiveDate::from_ymd_opt(2019, 12, 31).unwrap())
+        .is_err()
+    );
+    assert!(field.sanitize_option(None).unwrap().is_none());
+  }
+
+  #[test]
+  fn test_filter_traced_unnamed_field() {
+    let field = FieldBuilder::<String>::default()
+      .filters(vec![FilterOp::Lowercase])
+      .build()
+      .unwrap();
+    let mut log = ChangeLog::default();
+    assert_eq!(field.filter_traced("abc".to_string(), &mut log), "abc");
+    assert!(!log.field("").unwrap().is_changed());
+  }
 }
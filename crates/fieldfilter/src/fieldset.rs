@@ -58,6 +58,10 @@ pub trait Fieldset: Sized {
   fn validate(&self) -> Result<(), FieldsetViolations>;
 
   /// Apply filters to all fields, returning the filtered struct.
+  ///
+  /// Derived impls cannot run `try_custom_async` filters here: a field that has one
+  /// fails with a `FilterErrorCode::AsyncOnly` violation instead of skipping the
+  /// step. Use `FieldsetAsync::filter_async` for such types.
   fn filter(self) -> Result<Self, FieldsetViolations>;
 
   /// Filter and then validate (convenience method).
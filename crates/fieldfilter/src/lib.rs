@@ -6,10 +6,13 @@
 //!
 //! - [`Fieldset`] - Typed struct validation and filtering (recommended for new code)
 //! - `FieldsetAsync` - Async version of `Fieldset` (behind `async` feature)
-//! - [`Field`] - Unified validation configuration
+//! - [`DynamicFieldset`] - Runtime-defined fieldset for forms described by configuration
+//! - [`Field`] - Unified validation configuration for `String`, numeric, `bool`, `char`,
+//!   `Vec<String>`, and (with `chrono`/`jiff`) date fields
 //! - [`FilterOp`] - Serializable filter enum for value transformation (re-exported from `walrs_filter`)
 //! - [`TryFilterOp`] - Fallible filter enum for transformations that can fail (re-exported from `walrs_filter`)
 //! - [`FilterError`] - Error type for fallible filters (re-exported from `walrs_filter`)
+//! - [`ChangeLog`] - Per-field audit log of filter changes, filled by [`Field::sanitize_traced`]
 //!
 //! ## Example
 //!
@@ -61,6 +64,8 @@
 #[macro_use]
 extern crate derive_builder;
 
+pub mod change_log;
+pub mod dynamic_fieldset;
 pub mod field;
 pub mod fieldset;
 
@@ -79,7 +84,15 @@ pub use walrs_validation::{
 pub use walrs_validation::{ValidateAsync, ValidateRefAsync};
 
 // Re-export FilterOp and TryFilterOp from walrs_filter
-pub use walrs_filter::{FilterError, FilterOp, TryFilterOp};
+pub use walrs_filter::{
+  FilterError, FilterErrorCode, FilterOp, FilterRegistry, FilterStep, FilterTrace, TraceValues,
+  TryFilterOp,
+};
+
+pub use change_log::ChangeLog;
+pub use dynamic_fieldset::{
+  CrossValidateFn, CrossValidateRule, DynamicField, DynamicFieldset, DynamicFilterRegistry,
+};
 
 pub use field::{Field, FieldBuilder};
 pub use fieldset::Fieldset;
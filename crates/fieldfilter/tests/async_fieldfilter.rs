@@ -72,3 +72,78 @@ async fn string_field_sanitize_async() {
   let result = field.sanitize_async("  bad  ".to_string()).await;
   assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn string_field_sanitize_async_runs_async_try_filters() {
+  use walrs_fieldfilter::{FilterError, TryFilterOp};
+
+  let canonical_city = TryFilterOp::<String>::TryCustomAsync(Arc::new(|value: String| {
+    Box::pin(async move {
+      // Pretend we hit a geocoding service here.
+      match value.as_str() {
+        "nyc" => Ok("New York".to_string()),
+        "" => Err(FilterError::new("unknown city").with_name("CanonicalCity")),
+        _ => Ok(value),
+      }
+    })
+  }));
+  let field = FieldBuilder::<String>::default()
+    .filters(vec![
+      walrs_filter::FilterOp::Trim,
+      walrs_filter::FilterOp::Lowercase,
+    ])
+    .try_filters(vec![canonical_city])
+    .rule(Rule::Required)
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    field.sanitize_async(" NYC ".to_string()).await.unwrap(),
+    "New York"
+  );
+  assert_eq!(field.sanitize_ref_async("NYC").await.unwrap(), "New York");
+  assert!(field.sanitize_async("  ".to_string()).await.is_err());
+
+  // The synchronous path refuses to run async filters.
+  assert!(field.sanitize(" NYC ".to_string()).is_err());
+}
+
+// ---------------------------------------------------------------------------
+// Field<Vec<String>> async tests
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn vec_field_sanitize_async_awaits_custom_async_rules() {
+  let field = FieldBuilder::<Vec<String>>::default()
+    .filters(vec![
+      walrs_filter::FilterOp::Trim,
+      walrs_filter::FilterOp::RemoveEmpty,
+    ])
+    .rule(
+      Rule::Required.and(Rule::CustomAsync(Arc::new(|tags: &Vec<String>| {
+        // Pretend we check the tags against a moderation service here.
+        let banned = tags.iter().any(|t| t == "spam");
+        Box::pin(async move {
+          if banned {
+            Err(Violation::new(ViolationType::CustomError, "banned tag"))
+          } else {
+            Ok(())
+          }
+        })
+      }))),
+    )
+    .build()
+    .unwrap();
+
+  assert_eq!(
+    field
+      .sanitize_async(vec![" rust ".into(), " ".into()])
+      .await
+      .unwrap(),
+    ["rust"]
+  );
+  assert!(field.sanitize_async(vec![" spam".into()]).await.is_err());
+  assert!(field.sanitize_async(vec![" ".into()]).await.is_err());
+  assert!(field.validate_async(vec!["spam".into()]).await.is_err());
+  assert_eq!(field.sanitize_option_async(None).await.unwrap_err().len(), 1);
+}
@@ -0,0 +1,240 @@
+//! Integration tests for `Vec` and map fields in `#[derive(Fieldset)]`.
+
+#[cfg(feature = "derive")]
+mod collection_tests {
+  use std::collections::{BTreeMap, HashMap};
+  use walrs_fieldfilter::{DeriveFieldset, Fieldset};
+  use walrs_validation::ViolationType;
+
+  #[derive(Debug, DeriveFieldset)]
+  struct Address {
+    #[validate(required)]
+    #[filter(trim)]
+    street: String,
+
+    #[validate(required, pattern = r"^\d{5}$")]
+    #[filter(trim)]
+    zip: String,
+  }
+
+  #[derive(Debug, DeriveFieldset)]
+  struct LineItem {
+    #[validate(required)]
+    #[filter(trim, uppercase)]
+    sku: String,
+
+    #[validate(min = 1, max = 99)]
+    quantity: u32,
+  }
+
+  #[derive(Debug, DeriveFieldset)]
+  struct Order {
+    #[validate(required, max_items = 3, nested)]
+    #[filter(nested)]
+    addresses: Vec<Address>,
+
+    #[validate(min_items = 1, nested)]
+    #[filter(nested)]
+    items: Option<Vec<LineItem>>,
+
+    #[validate(unique, each(email))]
+    notify: Vec<String>,
+
+    #[validate(each(min = 0, max = 100))]
+    discounts: HashMap<String, u8>,
+
+    #[validate(nested)]
+    #[filter(nested)]
+    shipping: BTreeMap<String, Address>,
+  }
+
+  fn address(zip: &str) -> Address {
+    Address {
+      street: "1 Main St".into(),
+      zip: zip.into(),
+    }
+  }
+
+  fn line_item(sku: &str, quantity: u32) -> LineItem {
+    LineItem {
+      sku: sku.into(),
+      quantity,
+    }
+  }
+
+  fn order() -> Order {
+    Order {
+      addresses: vec![address("12345"), address("54321")],
+      items: Some(vec![line_item("abc-1", 2), line_item("xyz-9", 1)]),
+      notify: vec!["a@example.com".into(), "b@example.com".into()],
+      discounts: HashMap::from([("spring".into(), 10)]),
+      shipping: BTreeMap::from([("home".into(), address("11111"))]),
+    }
+  }
+
+  #[test]
+  fn test_collections_pass() {
+    assert!(order().validate().is_ok());
+    let order = Order {
+      items: None,
+      notify: vec![],
+      discounts: HashMap::new(),
+      shipping: BTreeMap::new(),
+      ..order()
+    };
+    assert!(order.validate().is_ok());
+  }
+
+  #[test]
+  fn test_nested_violations_use_indexed_keys() {
+    let order = Order {
+      addresses: vec![address("12345"), address("bad"), address("")],
+      items: Some(vec![line_item("abc-1", 2), line_item("", 100)]),
+      shipping: BTreeMap::from([("work".into(), address("nope"))]),
+      ..order()
+    };
+    let err = order.validate().unwrap_err();
+    assert!(err.get("addresses[0].zip").is_none());
+    assert_eq!(
+      err.get("addresses[1].zip").unwrap()[0].violation_type(),
+      ViolationType::PatternMismatch
+    );
+    assert_eq!(
+      err.get("addresses[2].zip").unwrap()[0].violation_type(),
+      ViolationType::ValueMissing
+    );
+    assert!(err.get("items[1].sku").is_some());
+    assert_eq!(
+      err.get("items[1].quantity").unwrap()[0].violation_type(),
+      ViolationType::RangeOverflow
+    );
+    assert!(err.get("shipping[work].zip").is_some());
+    assert_eq!(err.len(), 5);
+  }
+
+  #[test]
+  fn test_item_counts() {
+    let empty = Order {
+      addresses: vec![],
+      items: Some(vec![]),
+      ..order()
+    };
+    let err = empty.validate().unwrap_err();
+    assert_eq!(
+      err.get("addresses").unwrap()[0].violation_type(),
+      ViolationType::ValueMissing
+    );
+    assert_eq!(
+      err.get("items").unwrap()[0].violation_type(),
+      ViolationType::TooShort
+    );
+
+    let crowded = Order {
+      addresses: (0..4).map(|_| address("12345")).collect(),
+      ..order()
+    };
+    let err = crowded.validate().unwrap_err();
+    assert_eq!(
+      err.get("addresses").unwrap()[0].violation_type(),
+      ViolationType::TooLong
+    );
+  }
+
+  #[test]
+  fn test_each_and_unique() {
+    let order = Order {
+      notify: vec![
+        "a@example.com".into(),
+        "not-an-email".into(),
+        "a@example.com".into(),
+      ],
+      discounts: HashMap::from([("b".into(), 101), ("a".into(), 200), ("ok".into(), 5)]),
+      ..order()
+    };
+    let err = order.validate().unwrap_err();
+    assert_eq!(
+      err.get("notify").unwrap()[0].message(),
+      "Items must be unique."
+    );
+    assert_eq!(
+      err.get("notify[1]").unwrap()[0].violation_type(),
+      ViolationType::TypeMismatch
+    );
+    assert!(err.get("notify[0]").is_none());
+    assert_eq!(
+      err.field_names().collect::<Vec<_>>(),
+      vec!["notify", "notify[1]", "discounts[a]", "discounts[b]"]
+    );
+  }
+
+  #[test]
+  fn test_nested_filter_on_collections() {
+    let order = Order {
+      addresses: vec![Address {
+        street: "  2 Side St ".into(),
+        zip: " 12345 ".into(),
+      }],
+      items: Some(vec![line_item(" abc-1 ", 1)]),
+      ..order()
+    };
+    let filtered = order.sanitize().unwrap();
+    assert_eq!(filtered.addresses[0].street, "2 Side St");
+    assert_eq!(filtered.addresses[0].zip, "12345");
+    assert_eq!(filtered.items.unwrap()[0].sku, "ABC-1");
+    assert_eq!(filtered.shipping["home"].zip, "11111");
+  }
+
+  #[derive(Debug, DeriveFieldset)]
+  #[fieldset(break_on_failure)]
+  struct Batch {
+    #[validate(each(min_length = 2))]
+    tags: Vec<String>,
+  }
+
+  #[test]
+  fn test_break_on_failure_stops_at_first_item() {
+    let batch = Batch {
+      tags: vec!["ok".into(), "x".into(), "y".into()],
+    };
+    let err = batch.validate().unwrap_err();
+    assert_eq!(err.field_names().collect::<Vec<_>>(), vec!["tags[1]"]);
+  }
+}
+
+#[cfg(all(feature = "derive", feature = "async"))]
+mod async_collection_tests {
+  use walrs_fieldfilter::{DeriveFieldset, FieldsetAsync};
+
+  #[derive(Debug, DeriveFieldset)]
+  #[fieldset(async)]
+  struct Contact {
+    #[validate(email)]
+    #[filter(trim)]
+    email: String,
+  }
+
+  #[derive(Debug, DeriveFieldset)]
+  #[fieldset(async)]
+  struct Team {
+    #[validate(min_items = 1, nested)]
+    #[filter(nested)]
+    members: Vec<Contact>,
+  }
+
+  #[tokio::test]
+  async fn test_async_nested_collection() {
+    let team = Team {
+      members: vec![
+        Contact {
+          email: " a@example.com ".into(),
+        },
+        Contact {
+          email: "nope".into(),
+        },
+      ],
+    };
+    let err = team.sanitize_async().await.unwrap_err();
+    assert!(err.get("members[0].email").is_none());
+    assert!(err.get("members[1].email").is_some());
+  }
+}
@@ -0,0 +1,199 @@
+//! Integration tests for date attributes in `#[derive(Fieldset)]`.
+
+#[cfg(all(feature = "derive", feature = "chrono"))]
+mod chrono_tests {
+  use chrono::{NaiveDate, NaiveDateTime};
+  use walrs_fieldfilter::{DeriveFieldset, Fieldset};
+  use walrs_validation::ViolationType;
+
+  #[derive(Debug, DeriveFieldset)]
+  struct Booking {
+    #[validate(required, date)]
+    #[filter(trim)]
+    arrival: String,
+
+    #[validate(date(format = "us_date"))]
+    departure: Option<String>,
+
+    #[validate(date_range(min = "01/01/2020", max = "12/31/2030", format = "us_date"))]
+    issued: String,
+
+    #[validate(date(format = "%d %B %Y"))]
+    signed: String,
+
+    #[validate(date_range(min = "2020-01-01T00:00:00", allow_time))]
+    created: String,
+  }
+
+  fn booking() -> Booking {
+    Booking {
+      arrival: " 2026-02-23 ".into(),
+      departure: Some("02/28/2026".into()),
+      issued: "06/15/2025".into(),
+      signed: "23 February 2026".into(),
+      created: "2024-05-01T09:30:00".into(),
+    }
+  }
+
+  #[test]
+  fn test_string_dates_pass() {
+    assert!(booking().sanitize().is_ok());
+    let booking = Booking {
+      departure: None,
+      ..booking()
+    };
+    assert!(booking.sanitize().is_ok());
+  }
+
+  #[test]
+  fn test_string_dates_fail() {
+    let booking = Booking {
+      arrival: "23/02/2026".into(),
+      departure: Some("2026-02-28".into()),
+      issued: "06/15/2031".into(),
+      signed: "2026-02-23".into(),
+      created: "2019-12-31T23:59:59".into(),
+    };
+    let err = booking.validate().unwrap_err();
+    assert_eq!(
+      err.get("arrival").unwrap()[0].violation_type(),
+      ViolationType::TypeMismatch
+    );
+    assert!(err.get("departure").is_some());
+    assert_eq!(
+      err.get("issued").unwrap()[0].violation_type(),
+      ViolationType::RangeOverflow
+    );
+    assert!(err.get("signed").is_some());
+    assert_eq!(
+      err.get("created").unwrap()[0].violation_type(),
+      ViolationType::RangeUnderflow
+    );
+  }
+
+  #[derive(Debug, DeriveFieldset)]
+  #[fieldset(async)]
+  struct Event {
+    #[validate(min = "2020-01-01", max = "2030-12-31")]
+    starts: NaiveDate,
+
+    #[validate(required, date_range(min = "2020-01-01"))]
+    ends: Option<NaiveDate>,
+
+    #[validate(date_range(max = "2030-12-31T23:59:59"))]
+    published_at: Option<chrono::NaiveDateTime>,
+  }
+
+  fn date(s: &str) -> NaiveDate {
+    s.parse().unwrap()
+  }
+
+  #[test]
+  fn test_native_dates() {
+    let event = Event {
+      starts: date("2026-02-23"),
+      ends: Some(date("2026-02-24")),
+      published_at: None,
+    };
+    assert!(event.validate().is_ok());
+
+    let event = Event {
+      starts: date("2019-12-31"),
+      ends: None,
+      published_at: Some("2031-01-01T00:00:00".parse::<NaiveDateTime>().unwrap()),
+    };
+    let err = event.validate().unwrap_err();
+    assert_eq!(
+      err.get("starts").unwrap()[0].violation_type(),
+      ViolationType::RangeUnderflow
+    );
+    assert_eq!(
+      err.get("ends").unwrap()[0].violation_type(),
+      ViolationType::ValueMissing
+    );
+    assert_eq!(
+      err.get("published_at").unwrap()[0].violation_type(),
+      ViolationType::RangeOverflow
+    );
+  }
+
+  #[cfg(feature = "async")]
+  #[tokio::test]
+  async fn test_native_dates_async() {
+    use walrs_fieldfilter::FieldsetAsync;
+
+    let event = Event {
+      starts: date("2031-01-01"),
+      ends: Some(date("2026-02-24")),
+      published_at: None,
+    };
+    let err = event.validate_async().await.unwrap_err();
+    assert!(err.get("starts").is_some());
+    assert_eq!(err.len(), 1);
+  }
+
+  #[derive(Debug, DeriveFieldset)]
+  #[cross_validate(dependent_required(trigger = ends, dependents(starts)))]
+  struct Period {
+    starts: Option<NaiveDate>,
+    ends: Option<NaiveDate>,
+  }
+
+  #[test]
+  fn test_optional_date_presence() {
+    let period = Period {
+      starts: None,
+      ends: Some(date("2026-01-01")),
+    };
+    let err = period.validate().unwrap_err();
+    assert!(err.form_violations().is_some());
+  }
+}
+
+#[cfg(all(feature = "derive", feature = "jiff"))]
+mod jiff_tests {
+  use jiff::civil::{Date, DateTime};
+  use walrs_fieldfilter::{DeriveFieldset, Fieldset};
+  use walrs_validation::ViolationType;
+
+  #[derive(Debug, DeriveFieldset)]
+  struct Event {
+    #[validate(date_range(min = "2020-01-01", max = "2030-12-31"))]
+    starts: Date,
+
+    #[validate(min = "2020-01-01T00:00:00")]
+    created: Option<jiff::civil::DateTime>,
+
+    #[validate(date)]
+    raw: String,
+  }
+
+  #[test]
+  fn test_jiff_dates() {
+    let event = Event {
+      starts: "2026-02-23".parse().unwrap(),
+      created: Some("2024-01-01T00:00:00".parse::<DateTime>().unwrap()),
+      raw: "2026-02-23".into(),
+    };
+    assert!(event.validate().is_ok());
+
+    let event = Event {
+      starts: "2031-01-01".parse().unwrap(),
+      created: Some("2019-01-01T00:00:00".parse::<DateTime>().unwrap()),
+      raw: "not a date".into(),
+    };
+    let err = event.validate().unwrap_err();
+    assert_eq!(
+      err.get("starts").unwrap()[0].violation_type(),
+      ViolationType::RangeOverflow
+    );
+    assert_eq!(
+      err.get("created").unwrap()[0].violation_type(),
+      ViolationType::RangeUnderflow
+    );
+    assert_eq!(
+      err.get("raw").unwrap()[0].violation_type(),
+      ViolationType::TypeMismatch
+    );
+  }
+}
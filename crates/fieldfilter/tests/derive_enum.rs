@@ -0,0 +1,237 @@
+//! Integration tests for `#[derive(Fieldset)]` on enums.
+
+#[cfg(feature = "derive")]
+mod enum_tests {
+  use serde::Deserialize;
+  use walrs_fieldfilter::{DeriveFieldset, Fieldset};
+  use walrs_validation::{Violation, ViolationType};
+
+  #[derive(Debug, Deserialize, DeriveFieldset)]
+  struct BillingAddress {
+    #[validate(required, pattern = r"^\d{5}$")]
+    #[filter(trim)]
+    zip: String,
+  }
+
+  #[derive(Debug, Deserialize, DeriveFieldset)]
+  #[serde(
+    tag = "type",
+    rename_all = "lowercase",
+    rename_all_fields = "camelCase"
+  )]
+  #[cross_validate(not_blocked)]
+  enum Payment {
+    #[cross_validate(fields_equal(number, confirm_number))]
+    Card {
+      #[validate(required, pattern = r"^\d{16}$")]
+      #[filter(digits)]
+      number: String,
+
+      #[filter(digits)]
+      confirm_number: String,
+
+      #[validate(range(min = 100, max = 9999))]
+      cvc: u16,
+
+      #[validate(nested)]
+      #[filter(nested)]
+      billing_address: BillingAddress,
+    },
+    Bank {
+      #[validate(required, min_length = 15, max_length = 34)]
+      #[filter(trim, uppercase)]
+      iban: String,
+
+      #[serde(rename = "holder")]
+      #[validate(required)]
+      account_holder: Option<String>,
+    },
+    Cash,
+  }
+
+  fn not_blocked(payment: &Payment) -> Result<(), Violation> {
+    match payment {
+      Payment::Bank { iban, .. } if iban.starts_with("XX") => Err(Violation::new(
+        ViolationType::CustomError,
+        "This bank is not accepted.",
+      )),
+      _ => Ok(()),
+    }
+  }
+
+  fn card() -> Payment {
+    Payment::Card {
+      number: "4111 1111 1111 1111".into(),
+      confirm_number: "4111-1111-1111-1111".into(),
+      cvc: 123,
+      billing_address: BillingAddress {
+        zip: " 12345 ".into(),
+      },
+    }
+  }
+
+  #[test]
+  fn test_valid_variants() {
+    let filtered = card().sanitize().unwrap();
+    let Payment::Card {
+      number,
+      billing_address,
+      ..
+    } = filtered
+    else {
+      panic!("filter must keep the variant");
+    };
+    assert_eq!(number, "4111111111111111");
+    assert_eq!(billing_address.zip, "12345");
+
+    let bank = Payment::Bank {
+      iban: " de89370400440532013000 ".into(),
+      account_holder: Some("Ada".into()),
+    };
+    assert!(bank.sanitize().is_ok());
+    assert!(Payment::Cash.sanitize().is_ok());
+  }
+
+  #[test]
+  fn test_variant_violations_use_field_paths() {
+    let payment: Payment = serde_json::from_value(serde_json::json!({
+      "type": "card",
+      "number": "4111",
+      "confirmNumber": "4112",
+      "cvc": 12,
+      "billingAddress": { "zip": "abc" },
+    }))
+    .unwrap();
+    let err = payment.validate().unwrap_err();
+    let keys: Vec<&str> = err.field_names().map(String::as_str).collect();
+    assert_eq!(keys, vec!["number", "cvc", "billingAddress.zip", ""]);
+    assert_eq!(
+      err.form_violations().unwrap()[0].message(),
+      "FieldsEqual: number and confirmNumber must be equal"
+    );
+  }
+
+  #[test]
+  fn test_other_variants_are_not_checked() {
+    let bank = Payment::Bank {
+      iban: "XX".into(),
+      account_holder: None,
+    };
+    let err = bank.validate().unwrap_err();
+    let keys: Vec<&str> = err.field_names().map(String::as_str).collect();
+    assert_eq!(keys, vec!["iban", "holder", ""]);
+    assert_eq!(
+      err.get("iban").unwrap()[0].violation_type(),
+      ViolationType::TooShort
+    );
+    assert_eq!(
+      err.form_violations().unwrap()[0].message(),
+      "This bank is not accepted."
+    );
+  }
+
+  #[derive(Debug, DeriveFieldset)]
+  #[fieldset(break_on_failure)]
+  enum Contact {
+    Email {
+      #[validate(email)]
+      address: String,
+      #[validate(required)]
+      label: String,
+    },
+  }
+
+  #[test]
+  fn test_break_on_failure() {
+    let contact = Contact::Email {
+      address: "nope".into(),
+      label: String::new(),
+    };
+    let err = contact.validate().unwrap_err();
+    assert_eq!(err.field_names().collect::<Vec<_>>(), vec!["Email.address"]);
+  }
+
+  #[derive(Debug, Deserialize, DeriveFieldset)]
+  #[serde(rename_all = "snake_case")]
+  enum Externally {
+    CreditCard {
+      #[validate(required)]
+      number: String,
+    },
+    #[serde(rename = "bank")]
+    Bank {
+      #[validate(required)]
+      iban: String,
+    },
+  }
+
+  #[test]
+  fn test_external_tagging_prefixes_variant_name() {
+    let card: Externally =
+      serde_json::from_value(serde_json::json!({ "credit_card": { "number": "" } })).unwrap();
+    let err = card.validate().unwrap_err();
+    assert!(err.get("credit_card.number").is_some());
+
+    let bank: Externally =
+      serde_json::from_value(serde_json::json!({ "bank": { "iban": "" } })).unwrap();
+    let err = bank.validate().unwrap_err();
+    assert_eq!(err.field_names().collect::<Vec<_>>(), vec!["bank.iban"]);
+  }
+
+  #[derive(Debug, Deserialize, DeriveFieldset)]
+  #[serde(tag = "kind", content = "data")]
+  enum Adjacently {
+    Card {
+      #[validate(required)]
+      number: String,
+      #[validate(nested)]
+      billing_address: BillingAddress,
+    },
+  }
+
+  #[test]
+  fn test_adjacent_tagging_prefixes_content_key() {
+    let card: Adjacently = serde_json::from_value(serde_json::json!({
+      "kind": "Card",
+      "data": { "number": "", "billing_address": { "zip": "abc" } },
+    }))
+    .unwrap();
+    let err = card.validate().unwrap_err();
+    let keys: Vec<&str> = err.field_names().map(String::as_str).collect();
+    assert_eq!(keys, vec!["data.number", "data.billing_address.zip"]);
+  }
+}
+
+#[cfg(all(feature = "derive", feature = "async"))]
+mod async_enum_tests {
+  use walrs_fieldfilter::{DeriveFieldset, FieldsetAsync};
+
+  #[derive(Debug, DeriveFieldset)]
+  #[fieldset(async)]
+  enum Destination {
+    Webhook {
+      #[validate(url)]
+      #[filter(trim)]
+      url: String,
+    },
+    Disabled,
+  }
+
+  #[tokio::test]
+  async fn test_async_enum() {
+    let hook = Destination::Webhook {
+      url: " https://example.com/hook ".into(),
+    };
+    let Destination::Webhook { url } = hook.sanitize_async().await.unwrap() else {
+      panic!("filter_async must keep the variant");
+    };
+    assert_eq!(url, "https://example.com/hook");
+
+    let hook = Destination::Webhook {
+      url: "not a url".into(),
+    };
+    let err = hook.validate_async().await.unwrap_err();
+    assert!(err.get("Webhook.url").is_some());
+    assert!(Destination::Disabled.validate_async().await.is_ok());
+  }
+}
@@ -674,6 +674,35 @@ mod derive_tests {
     assert!(err.get("n").is_some());
   }
 
+  #[derive(Debug, DeriveFieldset)]
+  struct TrimToIntForm {
+    #[filter(trim, to_int)]
+    n: String,
+  }
+
+  #[test]
+  fn test_filter_error_maps_to_structured_violation() {
+    let form = TrimToIntForm { n: " 4x2 ".into() };
+    let err = form.filter().unwrap_err();
+    let violations = err.get("n").unwrap();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].violation_type(), ViolationType::TypeMismatch);
+    assert_eq!(
+      walrs_fieldfilter::FilterErrorCode::from_violation(&violations[0]),
+      Some(walrs_fieldfilter::FilterErrorCode::NotANumber)
+    );
+    assert_eq!(violations[0].code(), Some("not_a_number"));
+    assert_eq!(violations[0].path(), &[1]);
+    assert_eq!(violations[0].span(), Some(0..3));
+    assert!(
+      violations[0]
+        .message()
+        .starts_with("Filter 'ToInt' failed at step [1], bytes 0..3:"),
+      "{}",
+      violations[0].message()
+    );
+  }
+
   #[derive(Debug, DeriveFieldset)]
   struct ToFloatForm {
     #[filter(to_float)]
@@ -5,6 +5,7 @@
 
 #![cfg(all(feature = "derive", feature = "async"))]
 
+use walrs_fieldfilter::FilterError;
 use walrs_fieldfilter::{DeriveFieldset, Fieldset, FieldsetAsync};
 use walrs_validation::{ValidatorResult, Violation, ViolationType};
 
@@ -84,7 +85,7 @@ async fn validate_async_collects_both_sync_and_async_failures() {
 }
 
 #[tokio::test]
-async fn filter_async_delegates_to_sync_filter() {
+async fn filter_async_applies_sync_filters() {
   let r = Registration {
     email: "  USER@EXAMPLE.COM  ".into(),
     username: "alice".into(),
@@ -207,3 +208,84 @@ async fn custom_async_on_optional_runs_when_some() {
   let err = r.validate_async().await.unwrap_err();
   assert!(err.get("handle").is_some());
 }
+
+// --- try_custom_async filters ---
+
+// Async fallible filter used by the derive — takes and returns the owned value.
+async fn expand_short_link(url: String) -> Result<String, FilterError> {
+  // Pretend we resolve the redirect over HTTP here.
+  match url.as_str() {
+    "https://t.co/rust" => Ok("https://www.rust-lang.org/".into()),
+    "https://t.co/gone" => Err(FilterError::new("short link not found")),
+    _ => Ok(url),
+  }
+}
+
+#[derive(Debug, DeriveFieldset)]
+#[fieldset(async)]
+struct Bookmark {
+  #[filter(trim, try_custom_async = "expand_short_link")]
+  url: String,
+
+  #[filter(try_custom_async = "expand_short_link", lowercase)]
+  mirror: Option<String>,
+}
+
+#[derive(Debug, DeriveFieldset)]
+#[fieldset(async)]
+struct Folder {
+  #[filter(nested)]
+  bookmark: Bookmark,
+}
+
+#[tokio::test]
+async fn filter_async_awaits_try_custom_async() {
+  let b = Bookmark {
+    url: "  https://t.co/rust ".into(),
+    mirror: Some("https://t.co/rust".into()),
+  };
+  let filtered = b.filter_async().await.unwrap();
+  assert_eq!(filtered.url, "https://www.rust-lang.org/");
+  assert_eq!(
+    filtered.mirror.as_deref(),
+    Some("https://www.rust-lang.org/")
+  );
+
+  let b = Bookmark {
+    url: "https://t.co/gone".into(),
+    mirror: None,
+  };
+  let err = b.filter_async().await.unwrap_err();
+  assert!(err.get("url").is_some());
+
+  let b = Bookmark {
+    url: "https://example.com".into(),
+    mirror: Some("https://t.co/gone".into()),
+  };
+  let err = b.sanitize_async().await.unwrap_err();
+  assert!(err.get("mirror").is_some());
+}
+
+#[tokio::test]
+async fn filter_async_recurses_into_nested_fieldsets() {
+  let f = Folder {
+    bookmark: Bookmark {
+      url: "https://t.co/gone".into(),
+      mirror: None,
+    },
+  };
+  let err = f.filter_async().await.unwrap_err();
+  assert!(err.get("bookmark.url").is_some());
+}
+
+#[test]
+fn sync_filter_rejects_try_custom_async() {
+  let b = Bookmark {
+    url: " https://t.co/rust ".into(),
+    mirror: None,
+  };
+  let err = Fieldset::filter(b).unwrap_err();
+  let violation = &err.get("url").unwrap()[0];
+  assert!(violation.message().contains("async filter applied synchronously"));
+  assert!(err.get("mirror").is_none());
+}
@@ -0,0 +1,90 @@
+//! Integration tests for violation keys following serde / `fieldset` rename rules.
+
+#[cfg(feature = "derive")]
+mod rename_tests {
+  use serde::Deserialize;
+  use walrs_fieldfilter::{DeriveFieldset, Fieldset};
+
+  #[derive(Debug, Deserialize, DeriveFieldset)]
+  #[serde(rename_all = "camelCase")]
+  struct ShippingAddress {
+    #[validate(required)]
+    street_line: String,
+
+    #[serde(rename = "postcode")]
+    #[validate(required, pattern = r"^\d{5}$")]
+    zip_code: String,
+  }
+
+  #[derive(Debug, Deserialize, DeriveFieldset)]
+  #[serde(rename_all = "camelCase", deny_unknown_fields)]
+  #[cross_validate(fields_equal(email_address, confirm_email))]
+  struct Checkout {
+    #[validate(email)]
+    email_address: String,
+
+    confirm_email: String,
+
+    #[serde(default, rename(serialize = "ser_name", deserialize = "giftNote"))]
+    #[validate(max_length = 5)]
+    gift_message: Option<String>,
+
+    #[validate(nested)]
+    #[filter(nested)]
+    shipping_address: ShippingAddress,
+
+    #[validate(each(min_length = 2), name = "tags")]
+    order_tags: Vec<String>,
+  }
+
+  #[derive(Debug, DeriveFieldset)]
+  #[fieldset(rename_all = "SCREAMING-KEBAB-CASE")]
+  struct Legacy {
+    #[validate(min = 1)]
+    item_count: u32,
+
+    #[validate(required)]
+    r#type: String,
+  }
+
+  #[test]
+  fn test_serde_rename_rules_set_violation_keys() {
+    let checkout: Checkout = serde_json::from_value(serde_json::json!({
+      "emailAddress": "nope",
+      "confirmEmail": "other",
+      "giftNote": "Happy birthday",
+      "shippingAddress": { "streetLine": "", "postcode": "1234" },
+      "orderTags": ["ok", "x"],
+    }))
+    .unwrap();
+
+    let err = checkout.validate().unwrap_err();
+    let keys: Vec<&str> = err.field_names().map(String::as_str).collect();
+    assert_eq!(
+      keys,
+      vec![
+        "emailAddress",
+        "giftNote",
+        "shippingAddress.streetLine",
+        "shippingAddress.postcode",
+        "tags[1]",
+        "",
+      ]
+    );
+    assert_eq!(
+      err.form_violations().unwrap()[0].message(),
+      "FieldsEqual: emailAddress and confirmEmail must be equal"
+    );
+  }
+
+  #[test]
+  fn test_fieldset_rename_all() {
+    let legacy = Legacy {
+      item_count: 0,
+      r#type: String::new(),
+    };
+    let err = legacy.validate().unwrap_err();
+    assert!(err.get("ITEM-COUNT").is_some());
+    assert!(err.get("TYPE").is_some());
+  }
+}
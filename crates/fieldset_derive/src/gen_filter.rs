@@ -1,36 +1,82 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::parse::{FieldInfo, FieldType, FilterAttr, NumericLit};
+use crate::parse::{FieldInfo, FieldType, FilterAttr, NumericLit, VariantInfo};
 
-/// Generate the body of `fn filter(self) -> Result<Self, FieldsetViolations>`.
+/// Generate `fn filter(self) -> Result<Self, FieldsetViolations>`.
 pub fn gen_filter(fields: &[FieldInfo]) -> TokenStream {
-  let field_filters: Vec<TokenStream> = fields.iter().map(gen_field_filter).collect();
+  let body = gen_filter_body(fields, quote! { Self }, false);
+  wrap_filter(body, false)
+}
+
+/// Generate `FieldsetAsync::filter_async`: like [`gen_filter`], but awaits
+/// `try_custom_async` filters and nested `FieldsetAsync::filter_async` calls.
+pub fn gen_filter_async(fields: &[FieldInfo]) -> TokenStream {
+  let body = gen_filter_body(fields, quote! { Self }, true);
+  wrap_filter(body, true)
+}
+
+/// Generate `filter` (or `filter_async`) for an enum: the active variant's fields are
+/// filtered and the same variant is rebuilt.
+pub fn gen_filter_enum(variants: &[VariantInfo], is_async: bool) -> TokenStream {
+  let arms = variants.iter().map(|variant| {
+    let pattern = variant.pattern();
+    let ident = &variant.ident;
+    let body = match &variant.fields {
+      Some(fields) => gen_filter_body(fields, quote! { Self::#ident }, is_async),
+      None => quote! { ::core::result::Result::Ok(Self::#ident) },
+    };
+    quote! { #pattern => { #body } }
+  });
+  wrap_filter(quote! { match self { #(#arms)* } }, is_async)
+}
+
+fn wrap_filter(body: TokenStream, is_async: bool) -> TokenStream {
+  if is_async {
+    quote! {
+      fn filter_async(
+        self,
+      ) -> impl ::core::future::Future<Output = ::core::result::Result<Self, walrs_validation::FieldsetViolations>> + Send {
+        async move { #body }
+      }
+    }
+  } else {
+    quote! {
+      fn filter(self) -> ::core::result::Result<Self, walrs_validation::FieldsetViolations> {
+        #body
+      }
+    }
+  }
+}
+
+fn gen_filter_body(fields: &[FieldInfo], constructor: TokenStream, is_async: bool) -> TokenStream {
+  let field_filters: Vec<TokenStream> = fields
+    .iter()
+    .map(|f| gen_field_filter(f, is_async))
+    .collect();
 
   let field_names: Vec<&syn::Ident> = fields.iter().map(|f| &f.ident).collect();
 
   quote! {
-    fn filter(self) -> ::core::result::Result<Self, walrs_validation::FieldsetViolations> {
-      #(#field_filters)*
-      ::core::result::Result::Ok(Self {
-        #(#field_names),*
-      })
-    }
+    #(#field_filters)*
+    ::core::result::Result::Ok(#constructor {
+      #(#field_names),*
+    })
   }
 }
 
-fn gen_field_filter(field: &FieldInfo) -> TokenStream {
+fn gen_field_filter(field: &FieldInfo, is_async: bool) -> TokenStream {
   let field_name = &field.ident;
-  let _field_name_str = field_name.to_string();
+  let source = field.owned();
 
   // No filters and not nested → passthrough
   if field.filters.is_empty() && !field.is_nested_filter {
-    return quote! { let #field_name = self.#field_name; };
+    return quote! { let #field_name = #source; };
   }
 
   // Nested filter
   if field.is_nested_filter {
-    return gen_nested_filter(field);
+    return gen_nested_filter(field, is_async);
   }
 
   // Has filters
@@ -42,25 +88,27 @@ fn gen_field_filter(field: &FieldInfo) -> TokenStream {
         | FilterAttr::ToInt
         | FilterAttr::ToFloat
         | FilterAttr::UrlDecode
+        | FilterAttr::TryCustomAsync(_)
     )
   });
 
   match &field.ty {
-    FieldType::String => gen_string_filter(field, has_try_filters),
+    FieldType::String => gen_string_filter(field, has_try_filters, is_async),
     FieldType::Numeric(ty_ident) => gen_numeric_filter(field, ty_ident),
-    FieldType::OptionString => gen_option_string_filter(field, has_try_filters),
+    FieldType::OptionString => gen_option_string_filter(field, has_try_filters, is_async),
     FieldType::OptionNumeric(ty_ident) => gen_option_numeric_filter(field, ty_ident),
     _ => {
       // For other types just passthrough
-      quote! { let #field_name = self.#field_name; }
+      quote! { let #field_name = #source; }
     }
   }
 }
 
-fn gen_string_filter(field: &FieldInfo, has_try: bool) -> TokenStream {
+fn gen_string_filter(field: &FieldInfo, has_try: bool, is_async: bool) -> TokenStream {
   let field_name = &field.ident;
-  let field_name_str = field_name.to_string();
-  let steps = gen_filter_steps(field, quote! { self.#field_name }, has_try, &field_name_str);
+  let source = field.owned();
+  let field_key = &field.key;
+  let steps = gen_filter_steps(field, quote! { #source }, has_try, field_key, is_async);
 
   quote! {
     let #field_name = {
@@ -71,8 +119,9 @@ fn gen_string_filter(field: &FieldInfo, has_try: bool) -> TokenStream {
 
 fn gen_numeric_filter(field: &FieldInfo, ty_ident: &syn::Ident) -> TokenStream {
   let field_name = &field.ident;
+  let source = field.owned();
   let mut steps = Vec::new();
-  let mut current = quote! { self.#field_name };
+  let mut current = quote! { #source };
 
   for filter in &field.filters {
     let apply = numeric_filter_token(filter, &current, ty_ident);
@@ -81,7 +130,7 @@ fn gen_numeric_filter(field: &FieldInfo, ty_ident: &syn::Ident) -> TokenStream {
   }
 
   if steps.is_empty() {
-    quote! { let #field_name = self.#field_name; }
+    quote! { let #field_name = #source; }
   } else {
     quote! {
       let #field_name = {
@@ -92,14 +141,32 @@ fn gen_numeric_filter(field: &FieldInfo, ty_ident: &syn::Ident) -> TokenStream {
   }
 }
 
-fn gen_option_string_filter(field: &FieldInfo, has_try: bool) -> TokenStream {
+fn gen_option_string_filter(field: &FieldInfo, has_try: bool, is_async: bool) -> TokenStream {
   let field_name = &field.ident;
-  let field_name_str = field_name.to_string();
-  let inner_steps = gen_filter_steps(field, quote! { v }, has_try, &field_name_str);
+  let source = field.owned();
+  let field_key = &field.key;
+  let inner_steps = gen_filter_steps(field, quote! { v }, has_try, field_key, is_async);
 
-  if has_try {
+  if has_try && is_async {
+    // An async block, unlike a closure, can `.await` its steps.
     quote! {
-      let #field_name = match self.#field_name {
+      let #field_name = match #source {
+        ::core::option::Option::Some(v) => {
+          let result: ::core::result::Result<String, walrs_validation::FieldsetViolations> = async {
+            ::core::result::Result::Ok({ #inner_steps })
+          }
+          .await;
+          match result {
+            ::core::result::Result::Ok(filtered) => ::core::option::Option::Some(filtered),
+            ::core::result::Result::Err(e) => return ::core::result::Result::Err(e),
+          }
+        }
+        ::core::option::Option::None => ::core::option::Option::None,
+      };
+    }
+  } else if has_try {
+    quote! {
+      let #field_name = match #source {
         ::core::option::Option::Some(v) => {
           let result = (|| -> ::core::result::Result<String, walrs_validation::FieldsetViolations> {
             ::core::result::Result::Ok({ #inner_steps })
@@ -114,7 +181,7 @@ fn gen_option_string_filter(field: &FieldInfo, has_try: bool) -> TokenStream {
     }
   } else {
     quote! {
-      let #field_name = match self.#field_name {
+      let #field_name = match #source {
         ::core::option::Option::Some(v) => {
           ::core::option::Option::Some({ #inner_steps })
         }
@@ -126,6 +193,7 @@ fn gen_option_string_filter(field: &FieldInfo, has_try: bool) -> TokenStream {
 
 fn gen_option_numeric_filter(field: &FieldInfo, ty_ident: &syn::Ident) -> TokenStream {
   let field_name = &field.ident;
+  let source = field.owned();
   let mut steps = Vec::new();
   let mut current = quote! { v };
 
@@ -136,10 +204,10 @@ fn gen_option_numeric_filter(field: &FieldInfo, ty_ident: &syn::Ident) -> TokenS
   }
 
   if steps.is_empty() {
-    quote! { let #field_name = self.#field_name; }
+    quote! { let #field_name = #source; }
   } else {
     quote! {
-      let #field_name = match self.#field_name {
+      let #field_name = match #source {
         ::core::option::Option::Some(v) => {
           #(#steps)*
           ::core::option::Option::Some(filtered)
@@ -150,20 +218,77 @@ fn gen_option_numeric_filter(field: &FieldInfo, ty_ident: &syn::Ident) -> TokenS
   }
 }
 
-fn gen_nested_filter(field: &FieldInfo) -> TokenStream {
+fn gen_nested_filter(field: &FieldInfo, is_async: bool) -> TokenStream {
   let field_name = &field.ident;
-  let field_name_str = field_name.to_string();
+  let source = field.owned();
+  let field_key = &field.key;
+  let filter_call = |value: TokenStream| {
+    if is_async {
+      quote! { walrs_fieldfilter::FieldsetAsync::filter_async(#value).await }
+    } else {
+      quote! { walrs_fieldfilter::Fieldset::filter(#value) }
+    }
+  };
+  let filter_some = filter_call(quote! { v });
+  let filter_field = filter_call(quote! { #source });
+
+  // Collections filter element-wise; the first failing element's violations are returned
+  // under `field[index]` (or `field[key]` for maps).
+  let filter_items = |items: TokenStream| {
+    let filter_item = filter_call(quote! { item });
+    let (entries, push, finish) = match &field.ty {
+      FieldType::Map { .. } | FieldType::OptionMap { .. } => (
+        quote! { #items },
+        quote! { filtered_items.push((key, filtered)) },
+        quote! { filtered_items.into_iter().collect() },
+      ),
+      _ => (
+        quote! { #items.into_iter().enumerate() },
+        quote! { filtered_items.push(filtered) },
+        quote! { filtered_items },
+      ),
+    };
+    quote! {
+      {
+        let mut filtered_items = ::std::vec::Vec::new();
+        for (key, item) in #entries {
+          match #filter_item {
+            ::core::result::Result::Ok(filtered) => #push,
+            ::core::result::Result::Err(e) => {
+              let mut fv = walrs_validation::FieldsetViolations::new();
+              fv.merge_prefixed(&::std::format!("{}[{}]", #field_key, key), e);
+              return ::core::result::Result::Err(fv);
+            }
+          }
+        }
+        #finish
+      }
+    }
+  };
 
   match &field.ty {
+    FieldType::Vec(_) | FieldType::Map { .. } => {
+      let filtered = filter_items(quote! { #source });
+      quote! { let #field_name = #filtered; }
+    }
+    FieldType::OptionVec(_) | FieldType::OptionMap { .. } => {
+      let filtered = filter_items(quote! { items });
+      quote! {
+        let #field_name = match #source {
+          ::core::option::Option::Some(items) => ::core::option::Option::Some(#filtered),
+          ::core::option::Option::None => ::core::option::Option::None,
+        };
+      }
+    }
     FieldType::OptionOther(_) | FieldType::OptionString => {
       quote! {
-        let #field_name = match self.#field_name {
+        let #field_name = match #source {
           ::core::option::Option::Some(v) => {
-            match walrs_fieldfilter::Fieldset::filter(v) {
+            match #filter_some {
               ::core::result::Result::Ok(filtered) => ::core::option::Option::Some(filtered),
               ::core::result::Result::Err(e) => {
                 let mut fv = walrs_validation::FieldsetViolations::new();
-                fv.merge_prefixed(#field_name_str, e);
+                fv.merge_prefixed(#field_key, e);
                 return ::core::result::Result::Err(fv);
               }
             }
@@ -174,10 +299,10 @@ fn gen_nested_filter(field: &FieldInfo) -> TokenStream {
     }
     _ => {
       quote! {
-        let #field_name = walrs_fieldfilter::Fieldset::filter(self.#field_name)
+        let #field_name = #filter_field
           .map_err(|e| {
             let mut fv = walrs_validation::FieldsetViolations::new();
-            fv.merge_prefixed(#field_name_str, e);
+            fv.merge_prefixed(#field_key, e);
             fv
           })?;
       }
@@ -187,16 +312,34 @@ fn gen_nested_filter(field: &FieldInfo) -> TokenStream {
 
 /// Emit a fallible filter step that wraps `try_apply` errors into a
 /// `FieldsetViolations` keyed by `fname`.
-fn emit_try_filter_step(op: TokenStream, src: &TokenStream, fname: &str) -> TokenStream {
+///
+/// `step` is the filter's position in the field's `#[filter(...)]` list; it is
+/// recorded in the error's step path, and the error's code selects the
+/// violation type.
+fn emit_try_filter_step(
+  op: TokenStream,
+  src: &TokenStream,
+  fname: &str,
+  step: usize,
+) -> TokenStream {
+  let map_err = map_filter_error(fname, step);
   quote! {
     let filtered = #op
       .try_apply(#src)
-      .map_err(|e| {
-        let mut fv = walrs_validation::FieldsetViolations::new();
-        let violation: walrs_validation::Violation = e.into();
-        fv.add(#fname, violation);
-        fv
-      })?;
+      .map_err(#map_err)?;
+  }
+}
+
+/// A closure converting a `FilterError` from filter `step` into `FieldsetViolations`
+/// keyed by `fname`.
+fn map_filter_error(fname: &str, step: usize) -> TokenStream {
+  quote! {
+    |e: walrs_filter::FilterError| {
+      let mut fv = walrs_validation::FieldsetViolations::new();
+      let violation: walrs_validation::Violation = e.with_step(#step).into();
+      fv.add(#fname, violation);
+      fv
+    }
   }
 }
 
@@ -205,12 +348,13 @@ fn gen_filter_steps(
   field: &FieldInfo,
   initial: TokenStream,
   _has_try: bool,
-  field_name_str: &str,
+  field_key: &str,
+  is_async: bool,
 ) -> TokenStream {
   let mut steps = Vec::new();
   let mut first = true;
 
-  for filter in &field.filters {
+  for (step, filter) in field.filters.iter().enumerate() {
     let src = if first {
       first = false;
       initial.clone()
@@ -245,13 +389,13 @@ fn gen_filter_steps(
           None => quote! { ::core::option::Option::None },
         };
         steps.push(
-          quote! { let filtered = walrs_filter::FilterOp::<String>::Slug { max_length: #ml }.apply(#src); },
+          quote! { let filtered = walrs_filter::FilterOp::<String>::Slug { max_length: #ml, separator: '-', stop_words: ::std::vec::Vec::new() }.apply(#src); },
         );
       }
       FilterAttr::Truncate { max_length } => {
         let n = *max_length;
         steps.push(
-          quote! { let filtered = walrs_filter::FilterOp::<String>::Truncate { max_length: #n }.apply(#src); },
+          quote! { let filtered = walrs_filter::FilterOp::<String>::Truncate { max_length: #n, unit: walrs_filter::TruncateUnit::Chars, suffix: ::std::string::String::new(), break_at_word: false }.apply(#src); },
         );
       }
       FilterAttr::Replace { from, to } => {
@@ -268,9 +412,33 @@ fn gen_filter_steps(
         steps.push(emit_try_filter_step(
           quote! { walrs_filter::TryFilterOp::<String>::TryCustom(::std::sync::Arc::new(#path)) },
           &src,
-          field_name_str,
+          field_key,
+          step,
         ));
       }
+      FilterAttr::TryCustomAsync(path) => {
+        if is_async {
+          let map_err = map_filter_error(field_key, step);
+          steps.push(quote! {
+            let filtered = #path(#src)
+              .await
+              .map_err(#map_err)?;
+          });
+        } else {
+          // Only awaited by `FieldsetAsync::filter_async`; the sync path must not
+          // silently skip the step.
+          let map_err = map_filter_error(field_key, step);
+          steps.push(quote! {
+            let _ = #src;
+            let filtered: String = ::core::result::Result::Err(
+              walrs_filter::FilterError::new("async filter applied synchronously")
+                .with_name("TryCustomAsync")
+                .with_code(walrs_filter::FilterErrorCode::AsyncOnly),
+            )
+            .map_err(#map_err)?;
+          });
+        }
+      }
       FilterAttr::Digits => {
         steps.push(quote! { let filtered = walrs_filter::FilterOp::<String>::Digits.apply(#src); });
       }
@@ -315,28 +483,32 @@ fn gen_filter_steps(
         steps.push(emit_try_filter_step(
           quote! { walrs_filter::TryFilterOp::<String>::ToBool },
           &src,
-          field_name_str,
+          field_key,
+          step,
         ));
       }
       FilterAttr::ToInt => {
         steps.push(emit_try_filter_step(
           quote! { walrs_filter::TryFilterOp::<String>::ToInt },
           &src,
-          field_name_str,
+          field_key,
+          step,
         ));
       }
       FilterAttr::ToFloat => {
         steps.push(emit_try_filter_step(
           quote! { walrs_filter::TryFilterOp::<String>::ToFloat },
           &src,
-          field_name_str,
+          field_key,
+          step,
         ));
       }
       FilterAttr::UrlDecode => {
         steps.push(emit_try_filter_step(
           quote! { walrs_filter::TryFilterOp::<String>::UrlDecode },
           &src,
-          field_name_str,
+          field_key,
+          step,
         ));
       }
       FilterAttr::Clamp { .. } => {
@@ -3,7 +3,8 @@ use quote::quote;
 use syn::Ident;
 
 use crate::parse::{
-  ConditionLiteral, CrossValidateRule, FieldInfo, FieldType, NumericLit, OneOfItem, ValidateAttr,
+  ConditionLiteral, CrossValidateRule, DateFormatLit, FieldInfo, FieldType, NumericLit, OneOfItem,
+  ValidateAttr, VariantInfo, collection_element,
 };
 
 /// Generate the body of `fn validate(&self) -> Result<(), FieldsetViolations>`.
@@ -11,11 +12,57 @@ pub fn gen_validate(
   fields: &[FieldInfo],
   cross_rules: &[CrossValidateRule],
   struct_break_on_failure: bool,
+) -> syn::Result<TokenStream> {
+  let checks = gen_checks(fields, cross_rules, struct_break_on_failure)?;
+  Ok(wrap_validate(checks))
+}
+
+/// Generate `fn validate(&self)` for an enum: the active variant's field checks and
+/// cross-validation, then the enum-level `#[cross_validate(fn)]` rules.
+pub fn gen_validate_enum(
+  variants: &[VariantInfo],
+  cross_rules: &[CrossValidateRule],
+  break_on_failure: bool,
+) -> syn::Result<TokenStream> {
+  let arms = gen_variant_arms(variants, |fields, rules| {
+    gen_checks(fields, rules, break_on_failure)
+  })?;
+  let enum_checks = gen_checks(&[], cross_rules, break_on_failure)?;
+  Ok(wrap_validate(quote! {
+    match self {
+      #(#arms)*
+    }
+    #enum_checks
+  }))
+}
+
+/// One `pattern => { checks }` arm per variant; unit variants get an empty body.
+pub(crate) fn gen_variant_arms(
+  variants: &[VariantInfo],
+  gen_checks: impl Fn(&[FieldInfo], &[CrossValidateRule]) -> syn::Result<TokenStream>,
+) -> syn::Result<Vec<TokenStream>> {
+  variants
+    .iter()
+    .map(|variant| {
+      let pattern = variant.pattern();
+      let checks = gen_checks(
+        variant.fields.as_deref().unwrap_or_default(),
+        &variant.cross_rules,
+      )?;
+      Ok(quote! { #pattern => { #checks } })
+    })
+    .collect()
+}
+
+fn gen_checks(
+  fields: &[FieldInfo],
+  cross_rules: &[CrossValidateRule],
+  break_on_failure: bool,
 ) -> syn::Result<TokenStream> {
   let field_checks: Vec<TokenStream> = fields
     .iter()
-    .filter(|f| !f.validations.is_empty() || f.is_nested_validate)
-    .map(|f| gen_field_validate(f, struct_break_on_failure))
+    .filter(|f| !f.validations.is_empty() || f.is_nested_validate || f.each.is_some())
+    .map(|f| gen_field_validate(f, break_on_failure))
     .collect();
 
   let cross_checks: Vec<TokenStream> = cross_rules
@@ -24,13 +71,19 @@ pub fn gen_validate(
     .collect::<syn::Result<Vec<_>>>()?;
 
   Ok(quote! {
+    #(#field_checks)*
+    #(#cross_checks)*
+  })
+}
+
+fn wrap_validate(checks: TokenStream) -> TokenStream {
+  quote! {
     fn validate(&self) -> ::core::result::Result<(), walrs_validation::FieldsetViolations> {
       let mut violations = walrs_validation::FieldsetViolations::new();
-      #(#field_checks)*
-      #(#cross_checks)*
+      #checks
       violations.into()
     }
-  })
+  }
 }
 
 /// Find a field by ident; error if missing. Returns its type for codegen decisions.
@@ -54,47 +107,50 @@ fn lookup_field<'a>(fields: &'a [FieldInfo], name: &Ident) -> syn::Result<&'a Fi
 /// - `String` → `!self.field.trim().is_empty()`
 /// - other scalars (numeric/bool/char) → always `true`
 fn emit_has_value(field: &FieldInfo) -> TokenStream {
-  let name = &field.ident;
+  let place = field.place();
   match &field.ty {
-    FieldType::String => quote! { !self.#name.trim().is_empty() },
+    FieldType::String => quote! { !#place.trim().is_empty() },
     FieldType::OptionString => {
-      quote! { self.#name.as_ref().map(|s| !s.trim().is_empty()).unwrap_or(false) }
+      quote! { #place.as_ref().map(|s| !s.trim().is_empty()).unwrap_or(false) }
     }
     FieldType::OptionBool
     | FieldType::OptionChar
     | FieldType::OptionNumeric(_)
-    | FieldType::OptionOther(_) => quote! { self.#name.is_some() },
+    | FieldType::OptionDate(_)
+    | FieldType::OptionVec(_)
+    | FieldType::OptionMap { .. }
+    | FieldType::OptionOther(_) => quote! { #place.is_some() },
     _ => quote! { true },
   }
 }
 
 /// Emit an expression of type `bool` that compares `self.<field>` against `lit`.
 fn emit_eq_literal(field: &FieldInfo, lit: &ConditionLiteral) -> TokenStream {
-  let name = &field.ident;
+  let place = field.place();
   match (&field.ty, lit) {
-    (FieldType::String, ConditionLiteral::Str(s)) => quote! { self.#name == #s },
+    (FieldType::String, ConditionLiteral::Str(s)) => quote! { #place == #s },
     (FieldType::OptionString, ConditionLiteral::Str(s)) => {
-      quote! { self.#name.as_deref() == ::core::option::Option::Some(#s) }
+      quote! { #place.as_deref() == ::core::option::Option::Some(#s) }
     }
-    (FieldType::Bool, ConditionLiteral::Bool(b)) => quote! { self.#name == #b },
+    (FieldType::Bool, ConditionLiteral::Bool(b)) => quote! { #place == #b },
     (FieldType::OptionBool, ConditionLiteral::Bool(b)) => {
-      quote! { self.#name == ::core::option::Option::Some(#b) }
+      quote! { #place == ::core::option::Option::Some(#b) }
     }
     (FieldType::Numeric(_), ConditionLiteral::Int(v)) => {
       let lit = proc_macro2::Literal::i128_unsuffixed(*v);
-      quote! { self.#name == #lit }
+      quote! { #place == #lit }
     }
     (FieldType::OptionNumeric(_), ConditionLiteral::Int(v)) => {
       let lit = proc_macro2::Literal::i128_unsuffixed(*v);
-      quote! { self.#name == ::core::option::Option::Some(#lit) }
+      quote! { #place == ::core::option::Option::Some(#lit) }
     }
     // Fallback: fall through to direct equality and let rustc surface a type
     // mismatch at the call site rather than silently codegen the wrong thing.
-    (_, ConditionLiteral::Str(s)) => quote! { self.#name == #s },
-    (_, ConditionLiteral::Bool(b)) => quote! { self.#name == #b },
+    (_, ConditionLiteral::Str(s)) => quote! { #place == #s },
+    (_, ConditionLiteral::Bool(b)) => quote! { #place == #b },
     (_, ConditionLiteral::Int(v)) => {
       let lit = proc_macro2::Literal::i128_unsuffixed(*v);
-      quote! { self.#name == #lit }
+      quote! { #place == #lit }
     }
   }
 }
@@ -110,12 +166,12 @@ pub(crate) fn gen_cross_validate(
       }
     }),
     CrossValidateRule::FieldsEqual { field_a, field_b } => {
-      let _ = lookup_field(fields, field_a)?;
-      let _ = lookup_field(fields, field_b)?;
-      let a_str = field_a.to_string();
-      let b_str = field_b.to_string();
+      let a_info = lookup_field(fields, field_a)?;
+      let b_info = lookup_field(fields, field_b)?;
+      let (a_str, b_str) = (&a_info.key, &b_info.key);
+      let (a_place, b_place) = (a_info.place(), b_info.place());
       Ok(quote! {
-        if self.#field_a != self.#field_b {
+        if #a_place != #b_place {
           violations.add_form_violation(walrs_validation::Violation::new(
             walrs_validation::ViolationType::NotEqual,
             ::std::format!("FieldsEqual: {} and {} must be equal", #a_str, #b_str),
@@ -132,8 +188,8 @@ pub(crate) fn gen_cross_validate(
       let cond_info = lookup_field(fields, condition_field)?;
       let has_value = emit_has_value(field_info);
       let cond_check = emit_eq_literal(cond_info, condition);
-      let f_str = field.to_string();
-      let c_str = condition_field.to_string();
+      let f_str = &field_info.key;
+      let c_str = &cond_info.key;
       Ok(quote! {
         if (#cond_check) && !(#has_value) {
           violations.add_form_violation(walrs_validation::Violation::new(
@@ -155,8 +211,8 @@ pub(crate) fn gen_cross_validate(
       let cond_info = lookup_field(fields, condition_field)?;
       let has_value = emit_has_value(field_info);
       let cond_check = emit_eq_literal(cond_info, condition);
-      let f_str = field.to_string();
-      let c_str = condition_field.to_string();
+      let f_str = &field_info.key;
+      let c_str = &cond_info.key;
       Ok(quote! {
         if !(#cond_check) && !(#has_value) {
           violations.add_form_violation(walrs_validation::Violation::new(
@@ -176,8 +232,8 @@ pub(crate) fn gen_cross_validate(
         .collect::<syn::Result<_>>()?;
       let names_csv = names
         .iter()
-        .map(|n| n.to_string())
-        .collect::<Vec<_>>()
+        .map(|n| lookup_field(fields, n).map(|info| info.key.as_str()))
+        .collect::<syn::Result<Vec<_>>>()?
         .join(", ");
       Ok(quote! {
         if !((#(#checks)||*)) {
@@ -195,8 +251,8 @@ pub(crate) fn gen_cross_validate(
         .collect::<syn::Result<_>>()?;
       let names_csv = names
         .iter()
-        .map(|n| n.to_string())
-        .collect::<Vec<_>>()
+        .map(|n| lookup_field(fields, n).map(|info| info.key.as_str()))
+        .collect::<syn::Result<Vec<_>>>()?
         .join(", ");
       Ok(quote! {
         {
@@ -221,8 +277,8 @@ pub(crate) fn gen_cross_validate(
         .map(|n| {
           let info = lookup_field(fields, n)?;
           let has = emit_has_value(info);
-          let n_str = n.to_string();
-          let t_str = trigger.to_string();
+          let n_str = &info.key;
+          let t_str = &trigger_info.key;
           Ok(quote! {
             if !(#has) {
               violations.add_form_violation(walrs_validation::Violation::new(
@@ -246,8 +302,8 @@ pub(crate) fn gen_cross_validate(
 }
 
 fn gen_field_validate(field: &FieldInfo, struct_break: bool) -> TokenStream {
-  let field_name = &field.ident;
-  let field_name_str = field_name.to_string();
+  let place = field.place();
+  let field_key = &field.key;
 
   let break_on_failure = field.break_on_failure_override.unwrap_or(struct_break);
 
@@ -261,6 +317,10 @@ fn gen_field_validate(field: &FieldInfo, struct_break: bool) -> TokenStream {
     quote! {}
   };
 
+  if collection_element(&field.ty).is_some() {
+    return gen_collection_validate(field, &break_check, false);
+  }
+
   // Handle nested validation
   if field.is_nested_validate {
     return gen_nested_validate(field, &break_check);
@@ -280,22 +340,22 @@ fn gen_field_validate(field: &FieldInfo, struct_break: bool) -> TokenStream {
         {
           let rule = #rule_expr;
           if let ::core::result::Result::Err(violation) =
-            walrs_validation::ValidateRef::validate_ref(&rule, self.#field_name.as_str())
+            walrs_validation::ValidateRef::validate_ref(&rule, #place.as_str())
           {
-            violations.add(#field_name_str, violation);
+            violations.add(#field_key, violation);
             #break_check
           }
         }
       }
     }
-    FieldType::Numeric(_) | FieldType::Bool | FieldType::Char => {
+    FieldType::Numeric(_) | FieldType::Bool | FieldType::Char | FieldType::Date(_) => {
       quote! {
         {
           let rule = #rule_expr;
           if let ::core::result::Result::Err(violation) =
-            walrs_validation::ValidateRef::validate_ref(&rule, &self.#field_name)
+            walrs_validation::ValidateRef::validate_ref(&rule, &#place)
           {
-            violations.add(#field_name_str, violation);
+            violations.add(#field_key, violation);
             #break_check
           }
         }
@@ -310,17 +370,17 @@ fn gen_field_validate(field: &FieldInfo, struct_break: bool) -> TokenStream {
         quote! {
           {
             let rule = #rule_expr;
-            match self.#field_name.as_ref() {
+            match #place.as_ref() {
               ::core::option::Option::Some(inner) => {
                 if let ::core::result::Result::Err(violation) =
                   walrs_validation::ValidateRef::validate_ref(&rule, inner.as_str())
                 {
-                  violations.add(#field_name_str, violation);
+                  violations.add(#field_key, violation);
                   #break_check
                 }
               }
               ::core::option::Option::None => {
-                violations.add(#field_name_str, walrs_validation::Violation::value_missing());
+                violations.add(#field_key, walrs_validation::Violation::value_missing());
                 #break_check
               }
             }
@@ -330,11 +390,11 @@ fn gen_field_validate(field: &FieldInfo, struct_break: bool) -> TokenStream {
         quote! {
           {
             let rule = #rule_expr;
-            if let ::core::option::Option::Some(inner) = self.#field_name.as_ref() {
+            if let ::core::option::Option::Some(inner) = #place.as_ref() {
               if let ::core::result::Result::Err(violation) =
                 walrs_validation::ValidateRef::validate_ref(&rule, inner.as_str())
               {
-                violations.add(#field_name_str, violation);
+                violations.add(#field_key, violation);
                 #break_check
               }
             }
@@ -342,7 +402,10 @@ fn gen_field_validate(field: &FieldInfo, struct_break: bool) -> TokenStream {
         }
       }
     }
-    FieldType::OptionNumeric(_) | FieldType::OptionBool | FieldType::OptionChar => {
+    FieldType::OptionNumeric(_)
+    | FieldType::OptionBool
+    | FieldType::OptionChar
+    | FieldType::OptionDate(_) => {
       let has_required = field
         .validations
         .iter()
@@ -351,17 +414,17 @@ fn gen_field_validate(field: &FieldInfo, struct_break: bool) -> TokenStream {
         quote! {
           {
             let rule = #rule_expr;
-            match self.#field_name.as_ref() {
+            match #place.as_ref() {
               ::core::option::Option::Some(inner) => {
                 if let ::core::result::Result::Err(violation) =
                   walrs_validation::ValidateRef::validate_ref(&rule, inner)
                 {
-                  violations.add(#field_name_str, violation);
+                  violations.add(#field_key, violation);
                   #break_check
                 }
               }
               ::core::option::Option::None => {
-                violations.add(#field_name_str, walrs_validation::Violation::value_missing());
+                violations.add(#field_key, walrs_validation::Violation::value_missing());
                 #break_check
               }
             }
@@ -371,11 +434,11 @@ fn gen_field_validate(field: &FieldInfo, struct_break: bool) -> TokenStream {
         quote! {
           {
             let rule = #rule_expr;
-            if let ::core::option::Option::Some(inner) = self.#field_name.as_ref() {
+            if let ::core::option::Option::Some(inner) = #place.as_ref() {
               if let ::core::result::Result::Err(violation) =
                 walrs_validation::ValidateRef::validate_ref(&rule, inner)
               {
-                violations.add(#field_name_str, violation);
+                violations.add(#field_key, violation);
                 #break_check
               }
             }
@@ -383,15 +446,19 @@ fn gen_field_validate(field: &FieldInfo, struct_break: bool) -> TokenStream {
         }
       }
     }
+    FieldType::Vec(_)
+    | FieldType::OptionVec(_)
+    | FieldType::Map { .. }
+    | FieldType::OptionMap { .. } => unreachable!("collections are handled above"),
     FieldType::Other(_) | FieldType::OptionOther(_) => {
       // For unknown types, attempt ValidateRef
       quote! {
         {
           let rule = #rule_expr;
           if let ::core::result::Result::Err(violation) =
-            walrs_validation::ValidateRef::validate_ref(&rule, &self.#field_name)
+            walrs_validation::ValidateRef::validate_ref(&rule, &#place)
           {
-            violations.add(#field_name_str, violation);
+            violations.add(#field_key, violation);
             #break_check
           }
         }
@@ -401,8 +468,8 @@ fn gen_field_validate(field: &FieldInfo, struct_break: bool) -> TokenStream {
 }
 
 fn gen_nested_validate(field: &FieldInfo, break_check: &TokenStream) -> TokenStream {
-  let field_name = &field.ident;
-  let field_name_str = field_name.to_string();
+  let place = field.place();
+  let field_key = &field.key;
 
   match &field.ty {
     FieldType::OptionOther(_) | FieldType::OptionString => {
@@ -412,28 +479,28 @@ fn gen_nested_validate(field: &FieldInfo, break_check: &TokenStream) -> TokenStr
         .any(|v| matches!(v, ValidateAttr::Required));
       if has_required {
         quote! {
-          match self.#field_name.as_ref() {
+          match #place.as_ref() {
             ::core::option::Option::Some(inner) => {
               if let ::core::result::Result::Err(nested_violations) =
                 walrs_fieldfilter::Fieldset::validate(inner)
               {
-                violations.merge_prefixed(#field_name_str, nested_violations);
+                violations.merge_prefixed(#field_key, nested_violations);
                 #break_check
               }
             }
             ::core::option::Option::None => {
-              violations.add(#field_name_str, walrs_validation::Violation::value_missing());
+              violations.add(#field_key, walrs_validation::Violation::value_missing());
               #break_check
             }
           }
         }
       } else {
         quote! {
-          if let ::core::option::Option::Some(inner) = self.#field_name.as_ref() {
+          if let ::core::option::Option::Some(inner) = #place.as_ref() {
             if let ::core::result::Result::Err(nested_violations) =
               walrs_fieldfilter::Fieldset::validate(inner)
             {
-              violations.merge_prefixed(#field_name_str, nested_violations);
+              violations.merge_prefixed(#field_key, nested_violations);
               #break_check
             }
           }
@@ -443,9 +510,9 @@ fn gen_nested_validate(field: &FieldInfo, break_check: &TokenStream) -> TokenStr
     _ => {
       quote! {
         if let ::core::result::Result::Err(nested_violations) =
-          walrs_fieldfilter::Fieldset::validate(&self.#field_name)
+          walrs_fieldfilter::Fieldset::validate(&#place)
         {
-          violations.merge_prefixed(#field_name_str, nested_violations);
+          violations.merge_prefixed(#field_key, nested_violations);
           #break_check
         }
       }
@@ -453,6 +520,143 @@ fn gen_nested_validate(field: &FieldInfo, break_check: &TokenStream) -> TokenStr
   }
 }
 
+/// Generate the checks for a `Vec` or map field, shared by the sync and async codegen.
+///
+/// Runs, in attribute order, `required` (a `None` or empty collection), `min_items`,
+/// `max_items`, and `unique` against the collection, then the `each(...)` rule against every
+/// element, then `nested` validation of every element. Element violations are keyed
+/// `field[index]` (or `field[key]` for maps); nested ones are merged under that prefix, e.g.
+/// `addresses[1].zip`.
+pub(crate) fn gen_collection_validate(
+  field: &FieldInfo,
+  break_check: &TokenStream,
+  is_async: bool,
+) -> TokenStream {
+  let place = field.place();
+  let field_key = &field.key;
+  let has_required = field
+    .validations
+    .iter()
+    .any(|v| matches!(v, ValidateAttr::Required));
+
+  let mut checks: Vec<TokenStream> = field
+    .validations
+    .iter()
+    .filter_map(|attr| match attr {
+      ValidateAttr::Required => Some(quote! {
+        if items.is_empty() {
+          violations.add(#field_key, walrs_validation::Violation::value_missing());
+          #break_check
+        }
+      }),
+      ValidateAttr::MinItems(n) => Some(quote! {
+        if items.len() < #n {
+          violations.add(#field_key, walrs_validation::Violation::too_short(#n, items.len()));
+          #break_check
+        }
+      }),
+      ValidateAttr::MaxItems(n) => Some(quote! {
+        if items.len() > #n {
+          violations.add(#field_key, walrs_validation::Violation::too_long(#n, items.len()));
+          #break_check
+        }
+      }),
+      ValidateAttr::Unique => Some(quote! {
+        if (1..items.len()).any(|i| items[..i].contains(&items[i])) {
+          violations.add(
+            #field_key,
+            walrs_validation::Violation::new(
+              walrs_validation::ViolationType::CustomError,
+              "Items must be unique.",
+            ),
+          );
+          #break_check
+        }
+      }),
+      _ => None,
+    })
+    .collect();
+
+  // Element loop: `(key, item)` pairs, where `key` is the index or map key.
+  let entries = match &field.ty {
+    FieldType::Map { sorted: true, .. } | FieldType::OptionMap { sorted: true, .. } => quote! {
+      {
+        let mut entries: ::std::vec::Vec<_> = items.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        entries
+      }
+    },
+    FieldType::Map { .. } | FieldType::OptionMap { .. } => quote! { items.iter() },
+    _ => quote! { items.iter().enumerate() },
+  };
+  let item_key = quote! { ::std::format!("{}[{}]", #field_key, key) };
+
+  if let Some(rule_expr) = field.each.as_deref().and_then(build_rules) {
+    let item_ref = match &field.each.as_deref().map(|each| &each.ty) {
+      Some(FieldType::String) => quote! { item.as_str() },
+      _ => quote! { item },
+    };
+    checks.push(quote! {
+      {
+        let rule = #rule_expr;
+        for (key, item) in #entries {
+          if let ::core::result::Result::Err(violation) =
+            walrs_validation::ValidateRef::validate_ref(&rule, #item_ref)
+          {
+            violations.add(#item_key, violation);
+            #break_check
+          }
+        }
+      }
+    });
+  }
+
+  if field.is_nested_validate {
+    let validate_item = if is_async {
+      quote! { walrs_fieldfilter::FieldsetAsync::validate_async(item).await }
+    } else {
+      quote! { walrs_fieldfilter::Fieldset::validate(item) }
+    };
+    checks.push(quote! {
+      for (key, item) in #entries {
+        if let ::core::result::Result::Err(nested_violations) = #validate_item {
+          violations.merge_prefixed(&#item_key, nested_violations);
+          #break_check
+        }
+      }
+    });
+  }
+
+  match &field.ty {
+    FieldType::OptionVec(_) | FieldType::OptionMap { .. } => {
+      let none_check = if has_required {
+        quote! {
+          violations.add(#field_key, walrs_validation::Violation::value_missing());
+          #break_check
+        }
+      } else {
+        quote! {}
+      };
+      quote! {
+        match #place.as_ref() {
+          ::core::option::Option::Some(items) => {
+            #(#checks)*
+          }
+          ::core::option::Option::None => {
+            #none_check
+          }
+        }
+      }
+    }
+    _ => quote! {
+      {
+        let items = &#place;
+        #(#checks)*
+      }
+    },
+  }
+}
+
 /// Build the rule expression tokens for a field.
 fn build_rules(field: &FieldInfo) -> Option<TokenStream> {
   let rule_type = match &field.ty {
@@ -460,7 +664,13 @@ fn build_rules(field: &FieldInfo) -> Option<TokenStream> {
     FieldType::Numeric(id) | FieldType::OptionNumeric(id) => quote! { #id },
     FieldType::Bool | FieldType::OptionBool => quote! { bool },
     FieldType::Char | FieldType::OptionChar => quote! { char },
-    FieldType::Other(_) | FieldType::OptionOther(_) => return None,
+    FieldType::Date(ty) | FieldType::OptionDate(ty) => quote! { #ty },
+    FieldType::Vec(_)
+    | FieldType::OptionVec(_)
+    | FieldType::Map { .. }
+    | FieldType::OptionMap { .. }
+    | FieldType::Other(_)
+    | FieldType::OptionOther(_) => return None,
   };
 
   // Separate message/locale modifiers from actual rules
@@ -557,6 +767,41 @@ fn attr_to_rule_token(attr: &ValidateAttr, rule_type: &TokenStream) -> TokenStre
       let lit = numeric_lit_token(n);
       quote! { walrs_validation::Rule::<#rule_type>::Step(#lit) }
     }
+    ValidateAttr::Date { format, allow_time } => {
+      let format = date_format_token(format);
+      quote! {
+        walrs_validation::Rule::<#rule_type>::Date(walrs_validation::DateOptions {
+          format: #format,
+          allow_time: #allow_time,
+        })
+      }
+    }
+    ValidateAttr::DateRange {
+      format,
+      allow_time,
+      min,
+      max,
+    } => {
+      let format = date_format_token(format);
+      let min = option_string_token(min);
+      let max = option_string_token(max);
+      quote! {
+        walrs_validation::Rule::<#rule_type>::DateRange(walrs_validation::DateRangeOptions {
+          format: #format,
+          allow_time: #allow_time,
+          min: #min,
+          max: #max,
+        })
+      }
+    }
+    ValidateAttr::MinDate(s) => {
+      let lit = date_lit_token(s, rule_type);
+      quote! { walrs_validation::Rule::<#rule_type>::Min(#lit) }
+    }
+    ValidateAttr::MaxDate(s) => {
+      let lit = date_lit_token(s, rule_type);
+      quote! { walrs_validation::Rule::<#rule_type>::Max(#lit) }
+    }
     ValidateAttr::OneOf(items) => {
       let item_tokens: Vec<TokenStream> = items.iter().map(one_of_item_token).collect();
       quote! { walrs_validation::Rule::<#rule_type>::OneOf(::std::vec![#(#item_tokens),*]) }
@@ -566,11 +811,15 @@ fn attr_to_rule_token(attr: &ValidateAttr, rule_type: &TokenStream) -> TokenStre
         walrs_validation::Rule::<#rule_type>::Custom(::std::sync::Arc::new(#path))
       }
     }
-    // Message/MessageFn/Locale and CustomAsync are handled separately (or in async codegen)
+    // Message/MessageFn/Locale and CustomAsync are handled separately (or in async codegen);
+    // collection-level checks are emitted by `gen_collection_validate`.
     ValidateAttr::Message(_)
     | ValidateAttr::MessageFn(_)
     | ValidateAttr::Locale(_)
-    | ValidateAttr::CustomAsync(_) => {
+    | ValidateAttr::CustomAsync(_)
+    | ValidateAttr::MinItems(_)
+    | ValidateAttr::MaxItems(_)
+    | ValidateAttr::Unique => {
       quote! {}
     }
   }
@@ -590,6 +839,33 @@ fn numeric_lit_token(n: &NumericLit) -> TokenStream {
   }
 }
 
+pub(crate) fn date_format_token(format: &DateFormatLit) -> TokenStream {
+  match format {
+    DateFormatLit::Iso8601 => quote! { walrs_validation::DateFormat::Iso8601 },
+    DateFormatLit::UsDate => quote! { walrs_validation::DateFormat::UsDate },
+    DateFormatLit::EuDate => quote! { walrs_validation::DateFormat::EuDate },
+    DateFormatLit::Rfc2822 => quote! { walrs_validation::DateFormat::Rfc2822 },
+    DateFormatLit::Custom(f) => {
+      quote! { walrs_validation::DateFormat::Custom(::std::string::String::from(#f)) }
+    }
+  }
+}
+
+pub(crate) fn option_string_token(value: &Option<String>) -> TokenStream {
+  match value {
+    Some(s) => quote! { ::core::option::Option::Some(::std::string::String::from(#s)) },
+    None => quote! { ::core::option::Option::None },
+  }
+}
+
+/// Parse a date bound into the field's date type. The literal was checked in parse.rs.
+pub(crate) fn date_lit_token(value: &str, date_type: &TokenStream) -> TokenStream {
+  quote! {
+    <#date_type as ::core::str::FromStr>::from_str(#value)
+      .expect("date literal validated at macro expansion time")
+  }
+}
+
 fn one_of_item_token(item: &OneOfItem) -> TokenStream {
   match item {
     OneOfItem::Str(s) => quote! { #s.to_string() },
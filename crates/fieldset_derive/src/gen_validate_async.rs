@@ -9,19 +9,52 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::gen_validate::gen_cross_validate;
-use crate::parse::{CrossValidateRule, FieldInfo, FieldType, NumericLit, OneOfItem, ValidateAttr};
+use crate::gen_validate::{
+  date_format_token, date_lit_token, gen_collection_validate, gen_cross_validate, gen_variant_arms,
+  option_string_token,
+};
+use crate::parse::{
+  CrossValidateRule, FieldInfo, FieldType, NumericLit, OneOfItem, ValidateAttr, VariantInfo,
+  collection_element,
+};
 
 /// Generate the body of `fn validate_async(&self) -> ... + Send`.
 pub fn gen_validate_async(
   fields: &[FieldInfo],
   cross_rules: &[CrossValidateRule],
   struct_break_on_failure: bool,
+) -> syn::Result<TokenStream> {
+  let checks = gen_checks_async(fields, cross_rules, struct_break_on_failure)?;
+  Ok(wrap_validate_async(checks))
+}
+
+/// Async counterpart of [`gen_validate_enum`](crate::gen_validate::gen_validate_enum).
+pub fn gen_validate_async_enum(
+  variants: &[VariantInfo],
+  cross_rules: &[CrossValidateRule],
+  break_on_failure: bool,
+) -> syn::Result<TokenStream> {
+  let arms = gen_variant_arms(variants, |fields, rules| {
+    gen_checks_async(fields, rules, break_on_failure)
+  })?;
+  let enum_checks = gen_checks_async(&[], cross_rules, break_on_failure)?;
+  Ok(wrap_validate_async(quote! {
+    match self {
+      #(#arms)*
+    }
+    #enum_checks
+  }))
+}
+
+fn gen_checks_async(
+  fields: &[FieldInfo],
+  cross_rules: &[CrossValidateRule],
+  break_on_failure: bool,
 ) -> syn::Result<TokenStream> {
   let field_checks: Vec<TokenStream> = fields
     .iter()
-    .filter(|f| !f.validations.is_empty() || f.is_nested_validate)
-    .map(|f| gen_field_validate_async(f, struct_break_on_failure))
+    .filter(|f| !f.validations.is_empty() || f.is_nested_validate || f.each.is_some())
+    .map(|f| gen_field_validate_async(f, break_on_failure))
     .collect();
 
   let cross_checks: Vec<TokenStream> = cross_rules
@@ -30,22 +63,27 @@ pub fn gen_validate_async(
     .collect::<syn::Result<Vec<_>>>()?;
 
   Ok(quote! {
+    #(#field_checks)*
+    #(#cross_checks)*
+  })
+}
+
+fn wrap_validate_async(checks: TokenStream) -> TokenStream {
+  quote! {
     fn validate_async(
       &self,
     ) -> impl ::core::future::Future<Output = ::core::result::Result<(), walrs_validation::FieldsetViolations>> + Send {
       async move {
         let mut violations = walrs_validation::FieldsetViolations::new();
-        #(#field_checks)*
-        #(#cross_checks)*
+        #checks
         violations.into()
       }
     }
-  })
+  }
 }
 
 fn gen_field_validate_async(field: &FieldInfo, struct_break: bool) -> TokenStream {
-  let field_name = &field.ident;
-  let field_name_str = field_name.to_string();
+  let field_key = &field.key;
 
   let break_on_failure = field.break_on_failure_override.unwrap_or(struct_break);
 
@@ -59,13 +97,17 @@ fn gen_field_validate_async(field: &FieldInfo, struct_break: bool) -> TokenStrea
     quote! {}
   };
 
+  if collection_element(&field.ty).is_some() {
+    return gen_collection_validate(field, &break_check, true);
+  }
+
   // Nested fieldset delegates to the nested type's FieldsetAsync impl.
   if field.is_nested_validate {
     return gen_nested_validate_async(field, &break_check);
   }
 
   let sync_block = gen_sync_rule_block(field, &break_check);
-  let async_block = gen_custom_async_block(field, &field_name_str, &break_check);
+  let async_block = gen_custom_async_block(field, field_key, &break_check);
 
   quote! {
     #sync_block
@@ -75,8 +117,8 @@ fn gen_field_validate_async(field: &FieldInfo, struct_break: bool) -> TokenStrea
 
 /// Emit the synchronous rule evaluation (calls `validate_ref_async` on a `Rule`).
 fn gen_sync_rule_block(field: &FieldInfo, break_check: &TokenStream) -> TokenStream {
-  let field_name = &field.ident;
-  let field_name_str = field_name.to_string();
+  let place = field.place();
+  let field_key = &field.key;
 
   let rules = build_rules(field);
   let Some(rule_expr) = rules else {
@@ -89,22 +131,22 @@ fn gen_sync_rule_block(field: &FieldInfo, break_check: &TokenStream) -> TokenStr
         {
           let rule = #rule_expr;
           if let ::core::result::Result::Err(violation) =
-            walrs_validation::ValidateRefAsync::validate_ref_async(&rule, self.#field_name.as_str()).await
+            walrs_validation::ValidateRefAsync::validate_ref_async(&rule, #place.as_str()).await
           {
-            violations.add(#field_name_str, violation);
+            violations.add(#field_key, violation);
             #break_check
           }
         }
       }
     }
-    FieldType::Numeric(_) | FieldType::Bool | FieldType::Char => {
+    FieldType::Numeric(_) | FieldType::Bool | FieldType::Char | FieldType::Date(_) => {
       quote! {
         {
           let rule = #rule_expr;
           if let ::core::result::Result::Err(violation) =
-            walrs_validation::ValidateRefAsync::validate_ref_async(&rule, &self.#field_name).await
+            walrs_validation::ValidateRefAsync::validate_ref_async(&rule, &#place).await
           {
-            violations.add(#field_name_str, violation);
+            violations.add(#field_key, violation);
             #break_check
           }
         }
@@ -119,17 +161,17 @@ fn gen_sync_rule_block(field: &FieldInfo, break_check: &TokenStream) -> TokenStr
         quote! {
           {
             let rule = #rule_expr;
-            match self.#field_name.as_ref() {
+            match #place.as_ref() {
               ::core::option::Option::Some(inner) => {
                 if let ::core::result::Result::Err(violation) =
                   walrs_validation::ValidateRefAsync::validate_ref_async(&rule, inner.as_str()).await
                 {
-                  violations.add(#field_name_str, violation);
+                  violations.add(#field_key, violation);
                   #break_check
                 }
               }
               ::core::option::Option::None => {
-                violations.add(#field_name_str, walrs_validation::Violation::value_missing());
+                violations.add(#field_key, walrs_validation::Violation::value_missing());
                 #break_check
               }
             }
@@ -139,11 +181,11 @@ fn gen_sync_rule_block(field: &FieldInfo, break_check: &TokenStream) -> TokenStr
         quote! {
           {
             let rule = #rule_expr;
-            if let ::core::option::Option::Some(inner) = self.#field_name.as_ref() {
+            if let ::core::option::Option::Some(inner) = #place.as_ref() {
               if let ::core::result::Result::Err(violation) =
                 walrs_validation::ValidateRefAsync::validate_ref_async(&rule, inner.as_str()).await
               {
-                violations.add(#field_name_str, violation);
+                violations.add(#field_key, violation);
                 #break_check
               }
             }
@@ -151,7 +193,10 @@ fn gen_sync_rule_block(field: &FieldInfo, break_check: &TokenStream) -> TokenStr
         }
       }
     }
-    FieldType::OptionNumeric(_) | FieldType::OptionBool | FieldType::OptionChar => {
+    FieldType::OptionNumeric(_)
+    | FieldType::OptionBool
+    | FieldType::OptionChar
+    | FieldType::OptionDate(_) => {
       let has_required = field
         .validations
         .iter()
@@ -160,17 +205,17 @@ fn gen_sync_rule_block(field: &FieldInfo, break_check: &TokenStream) -> TokenStr
         quote! {
           {
             let rule = #rule_expr;
-            match self.#field_name.as_ref() {
+            match #place.as_ref() {
               ::core::option::Option::Some(inner) => {
                 if let ::core::result::Result::Err(violation) =
                   walrs_validation::ValidateRefAsync::validate_ref_async(&rule, inner).await
                 {
-                  violations.add(#field_name_str, violation);
+                  violations.add(#field_key, violation);
                   #break_check
                 }
               }
               ::core::option::Option::None => {
-                violations.add(#field_name_str, walrs_validation::Violation::value_missing());
+                violations.add(#field_key, walrs_validation::Violation::value_missing());
                 #break_check
               }
             }
@@ -180,11 +225,11 @@ fn gen_sync_rule_block(field: &FieldInfo, break_check: &TokenStream) -> TokenStr
         quote! {
           {
             let rule = #rule_expr;
-            if let ::core::option::Option::Some(inner) = self.#field_name.as_ref() {
+            if let ::core::option::Option::Some(inner) = #place.as_ref() {
               if let ::core::result::Result::Err(violation) =
                 walrs_validation::ValidateRefAsync::validate_ref_async(&rule, inner).await
               {
-                violations.add(#field_name_str, violation);
+                violations.add(#field_key, violation);
                 #break_check
               }
             }
@@ -192,14 +237,18 @@ fn gen_sync_rule_block(field: &FieldInfo, break_check: &TokenStream) -> TokenStr
         }
       }
     }
+    FieldType::Vec(_)
+    | FieldType::OptionVec(_)
+    | FieldType::Map { .. }
+    | FieldType::OptionMap { .. } => unreachable!("collections are handled by the caller"),
     FieldType::Other(_) | FieldType::OptionOther(_) => {
       quote! {
         {
           let rule = #rule_expr;
           if let ::core::result::Result::Err(violation) =
-            walrs_validation::ValidateRefAsync::validate_ref_async(&rule, &self.#field_name).await
+            walrs_validation::ValidateRefAsync::validate_ref_async(&rule, &#place).await
           {
-            violations.add(#field_name_str, violation);
+            violations.add(#field_key, violation);
             #break_check
           }
         }
@@ -217,10 +266,10 @@ fn gen_sync_rule_block(field: &FieldInfo, break_check: &TokenStream) -> TokenStr
 /// would be redundant.
 fn gen_custom_async_block(
   field: &FieldInfo,
-  field_name_str: &str,
+  field_key: &str,
   break_check: &TokenStream,
 ) -> TokenStream {
-  let field_name = &field.ident;
+  let place = field.place();
 
   let async_paths: Vec<&syn::Path> = field
     .validations
@@ -236,12 +285,21 @@ fn gen_custom_async_block(
   }
 
   let inner_value_expr: TokenStream = match &field.ty {
-    FieldType::String => quote! { self.#field_name.as_str() },
+    FieldType::String => quote! { #place.as_str() },
     FieldType::OptionString => quote! { inner.as_str() },
-    FieldType::Numeric(_) | FieldType::Bool | FieldType::Char => quote! { &self.#field_name },
-    FieldType::OptionNumeric(_) | FieldType::OptionBool | FieldType::OptionChar => quote! { inner },
-    FieldType::Other(_) => quote! { &self.#field_name },
-    FieldType::OptionOther(_) => quote! { inner },
+    FieldType::Numeric(_) | FieldType::Bool | FieldType::Char | FieldType::Date(_) => {
+      quote! { &#place }
+    }
+    FieldType::OptionNumeric(_)
+    | FieldType::OptionBool
+    | FieldType::OptionChar
+    | FieldType::OptionDate(_) => quote! { inner },
+    FieldType::Vec(_) | FieldType::Map { .. } | FieldType::Other(_) => {
+      quote! { &#place }
+    }
+    FieldType::OptionVec(_) | FieldType::OptionMap { .. } | FieldType::OptionOther(_) => {
+      quote! { inner }
+    }
   };
 
   let calls: Vec<TokenStream> = async_paths
@@ -249,7 +307,7 @@ fn gen_custom_async_block(
     .map(|path| {
       quote! {
         if let ::core::result::Result::Err(violation) = #path(#inner_value_expr).await {
-          violations.add(#field_name_str, violation);
+          violations.add(#field_key, violation);
           #break_check
         }
       }
@@ -265,9 +323,10 @@ fn gen_custom_async_block(
     | FieldType::OptionNumeric(_)
     | FieldType::OptionBool
     | FieldType::OptionChar
+    | FieldType::OptionDate(_)
     | FieldType::OptionOther(_) => {
       quote! {
-        if let ::core::option::Option::Some(inner) = self.#field_name.as_ref() {
+        if let ::core::option::Option::Some(inner) = #place.as_ref() {
           #body
         }
       }
@@ -277,8 +336,8 @@ fn gen_custom_async_block(
 }
 
 fn gen_nested_validate_async(field: &FieldInfo, break_check: &TokenStream) -> TokenStream {
-  let field_name = &field.ident;
-  let field_name_str = field_name.to_string();
+  let place = field.place();
+  let field_key = &field.key;
 
   match &field.ty {
     FieldType::OptionOther(_) | FieldType::OptionString => {
@@ -288,28 +347,28 @@ fn gen_nested_validate_async(field: &FieldInfo, break_check: &TokenStream) -> To
         .any(|v| matches!(v, ValidateAttr::Required));
       if has_required {
         quote! {
-          match self.#field_name.as_ref() {
+          match #place.as_ref() {
             ::core::option::Option::Some(inner) => {
               if let ::core::result::Result::Err(nested_violations) =
                 walrs_fieldfilter::FieldsetAsync::validate_async(inner).await
               {
-                violations.merge_prefixed(#field_name_str, nested_violations);
+                violations.merge_prefixed(#field_key, nested_violations);
                 #break_check
               }
             }
             ::core::option::Option::None => {
-              violations.add(#field_name_str, walrs_validation::Violation::value_missing());
+              violations.add(#field_key, walrs_validation::Violation::value_missing());
               #break_check
             }
           }
         }
       } else {
         quote! {
-          if let ::core::option::Option::Some(inner) = self.#field_name.as_ref() {
+          if let ::core::option::Option::Some(inner) = #place.as_ref() {
             if let ::core::result::Result::Err(nested_violations) =
               walrs_fieldfilter::FieldsetAsync::validate_async(inner).await
             {
-              violations.merge_prefixed(#field_name_str, nested_violations);
+              violations.merge_prefixed(#field_key, nested_violations);
               #break_check
             }
           }
@@ -319,9 +378,9 @@ fn gen_nested_validate_async(field: &FieldInfo, break_check: &TokenStream) -> To
     _ => {
       quote! {
         if let ::core::result::Result::Err(nested_violations) =
-          walrs_fieldfilter::FieldsetAsync::validate_async(&self.#field_name).await
+          walrs_fieldfilter::FieldsetAsync::validate_async(&#place).await
         {
-          violations.merge_prefixed(#field_name_str, nested_violations);
+          violations.merge_prefixed(#field_key, nested_violations);
           #break_check
         }
       }
@@ -339,7 +398,13 @@ fn build_rules(field: &FieldInfo) -> Option<TokenStream> {
     FieldType::Numeric(id) | FieldType::OptionNumeric(id) => quote! { #id },
     FieldType::Bool | FieldType::OptionBool => quote! { bool },
     FieldType::Char | FieldType::OptionChar => quote! { char },
-    FieldType::Other(_) | FieldType::OptionOther(_) => return None,
+    FieldType::Date(ty) | FieldType::OptionDate(ty) => quote! { #ty },
+    FieldType::Vec(_)
+    | FieldType::OptionVec(_)
+    | FieldType::Map { .. }
+    | FieldType::OptionMap { .. }
+    | FieldType::Other(_)
+    | FieldType::OptionOther(_) => return None,
   };
 
   let mut message: Option<String> = None;
@@ -435,6 +500,41 @@ fn attr_to_rule_token(attr: &ValidateAttr, rule_type: &TokenStream) -> TokenStre
       let lit = numeric_lit_token(n);
       quote! { walrs_validation::Rule::<#rule_type>::Step(#lit) }
     }
+    ValidateAttr::Date { format, allow_time } => {
+      let format = date_format_token(format);
+      quote! {
+        walrs_validation::Rule::<#rule_type>::Date(walrs_validation::DateOptions {
+          format: #format,
+          allow_time: #allow_time,
+        })
+      }
+    }
+    ValidateAttr::DateRange {
+      format,
+      allow_time,
+      min,
+      max,
+    } => {
+      let format = date_format_token(format);
+      let min = option_string_token(min);
+      let max = option_string_token(max);
+      quote! {
+        walrs_validation::Rule::<#rule_type>::DateRange(walrs_validation::DateRangeOptions {
+          format: #format,
+          allow_time: #allow_time,
+          min: #min,
+          max: #max,
+        })
+      }
+    }
+    ValidateAttr::MinDate(s) => {
+      let lit = date_lit_token(s, rule_type);
+      quote! { walrs_validation::Rule::<#rule_type>::Min(#lit) }
+    }
+    ValidateAttr::MaxDate(s) => {
+      let lit = date_lit_token(s, rule_type);
+      quote! { walrs_validation::Rule::<#rule_type>::Max(#lit) }
+    }
     ValidateAttr::OneOf(items) => {
       let item_tokens: Vec<TokenStream> = items.iter().map(one_of_item_token).collect();
       quote! { walrs_validation::Rule::<#rule_type>::OneOf(::std::vec![#(#item_tokens),*]) }
@@ -447,7 +547,10 @@ fn attr_to_rule_token(attr: &ValidateAttr, rule_type: &TokenStream) -> TokenStre
     ValidateAttr::Message(_)
     | ValidateAttr::MessageFn(_)
     | ValidateAttr::Locale(_)
-    | ValidateAttr::CustomAsync(_) => {
+    | ValidateAttr::CustomAsync(_)
+    | ValidateAttr::MinItems(_)
+    | ValidateAttr::MaxItems(_)
+    | ValidateAttr::Unique => {
       quote! {}
     }
   }
@@ -7,13 +7,23 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{Data, DeriveInput, Fields, parse_macro_input};
 
-use gen_filter::gen_filter;
-use gen_validate::gen_validate;
-use gen_validate_async::gen_validate_async;
-use parse::{parse_cross_validate_attrs, parse_field_info, parse_fieldset_struct_attrs};
+use gen_filter::{gen_filter, gen_filter_async, gen_filter_enum};
+use gen_validate::{gen_validate, gen_validate_enum};
+use gen_validate_async::{gen_validate_async, gen_validate_async_enum};
+use parse::{
+  CrossValidateRule, EnumTagging, check_try_custom_async, parse_cross_validate_attrs,
+  parse_field_info, parse_fieldset_struct_attrs, parse_variant_info,
+};
 
 /// Derive macro for the `Fieldset` trait.
 ///
+/// Supports structs with named fields and enums whose variants have named fields (or
+/// none). On an enum, only the active variant's fields are validated and filtered, keyed
+/// by their field paths where serde's tagging puts them: `Card.number` by default,
+/// `<content>.number` with `tag` + `content`, and `number` with only `tag` or with
+/// `untagged`; `filter` rebuilds the same variant. Field-based `#[cross_validate(...)]` rules go on the variant, and
+/// `#[cross_validate(fn_name)]` on the enum runs for every variant.
+///
 /// # Attributes
 ///
 /// ## Struct-level
@@ -34,6 +44,9 @@ use parse::{parse_cross_validate_attrs, parse_field_info, parse_fieldset_struct_
 ///
 ///   Without this, the generated `impl FieldsetAsync` is silently elided
 ///   even though the dependency tree has async support enabled.
+/// - `#[fieldset(rename_all = "camelCase")]` — case convention for violation keys, as in
+///   serde; defaults to the struct's `#[serde(rename_all = "...")]` (on enums,
+///   `#[serde(rename_all_fields = "...")]`, overridden per variant by `rename_all`)
 /// - `#[cross_validate(fn_name)]` — call `fn_name(&self) -> RuleResult` after per-field validation
 /// - `#[cross_validate(fields_equal(a, b))]` — both fields must be equal
 /// - `#[cross_validate(required_if(field, condition_field = <literal>))]`
@@ -45,8 +58,8 @@ use parse::{parse_cross_validate_attrs, parse_field_info, parse_fieldset_struct_
 /// ## Field-level validation (`#[validate(...)]`)
 ///
 /// - `required` — field must not be empty/missing
-/// - `min_length = N` — minimum string/collection length
-/// - `max_length = N` — maximum string/collection length
+/// - `min_length = N` — minimum string length (`min_items` on collections)
+/// - `max_length = N` — maximum string length (`max_items` on collections)
 /// - `exact_length = N` — exact length
 /// - `email` — valid email format
 /// - `url` — valid URL format
@@ -58,10 +71,22 @@ use parse::{parse_cross_validate_attrs, parse_field_info, parse_fieldset_struct_
 /// - `max = N` — maximum numeric value
 /// - `range(min = A, max = B)` — numeric range
 /// - `step = N` — numeric step/divisibility
+/// - `date` / `date(format = "...", allow_time)` — string parses as a date (`format`:
+///   `iso8601`, `us_date`, `eu_date`, `rfc2822`, or a strftime pattern)
+/// - `date_range(min = "...", max = "...")` — date string within bounds (also takes
+///   `format` and `allow_time`); on date-typed fields, ISO 8601 bounds
+/// - `min = "..."` / `max = "..."` — ISO 8601 bound on a `NaiveDate`/`NaiveDateTime` or
+///   `jiff::civil::Date`/`DateTime` field
 /// - `one_of = [a, b, c]` — value must be one of the listed values
 /// - `custom = "path::to::fn"` — custom validation function
 /// - `custom_async = "path::to::fn"` — async custom validation function (only invoked by `FieldsetAsync`)
-/// - `nested` — field implements Fieldset; delegate validation
+/// - `nested` — field implements Fieldset; delegate validation (per element on collections)
+/// - `min_items = N` / `max_items = N` — element count bounds on `Vec` and map fields
+/// - `unique` — no two `Vec` elements are equal
+/// - `each(...)` — validate every element (or map value); violations are keyed
+///   `field[index]` / `field[key]`
+/// - `name = "..."` — violation key for the field (defaults to `#[serde(rename = "...")]`,
+///   then the identifier with `rename_all` applied)
 /// - `message = "..."` — custom error message
 /// - `message_fn = "path"` — dynamic message provider
 /// - `locale = "en"` — locale for messages
@@ -79,6 +104,9 @@ use parse::{parse_cross_validate_attrs, parse_field_info, parse_fieldset_struct_
 /// - `clamp(min = A, max = B)` — clamp numeric value
 /// - `custom = "path::to::fn"` — custom filter function
 /// - `try_custom = "path::to::fn"` — fallible custom filter
+/// - `try_custom_async = "path::to::fn"` — async fallible custom filter; requires
+///   `#[fieldset(async)]`. Awaited by `FieldsetAsync::filter_async`; the sync `Fieldset::filter`
+///   fails for the field with `FilterErrorCode::AsyncOnly` instead of skipping the step
 /// - `nested` — field implements Fieldset; delegate filtering
 #[proc_macro_derive(Fieldset, attributes(validate, filter, cross_validate, fieldset))]
 pub fn derive_fieldset(input: TokenStream) -> TokenStream {
@@ -90,74 +118,126 @@ pub fn derive_fieldset(input: TokenStream) -> TokenStream {
 }
 
 fn derive_fieldset_impl(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
-  let struct_name = &input.ident;
+  let type_name = &input.ident;
   let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-  // Only support named structs
-  let fields = match &input.data {
-    Data::Struct(ds) => match &ds.fields {
-      Fields::Named(named) => &named.named,
-      _ => {
+  let (struct_attrs, validate_fn, filter_fn, async_fns) = match &input.data {
+    // Named structs
+    Data::Struct(ds) => {
+      let Fields::Named(named) = &ds.fields else {
         return Err(syn::Error::new_spanned(
-          struct_name,
+          type_name,
           "Fieldset can only be derived for structs with named fields",
         ));
+      };
+
+      // Parse struct-level attributes
+      let struct_attrs = parse_fieldset_struct_attrs(&input.attrs, "rename_all")?;
+      let cross_validate = parse_cross_validate_attrs(&input.attrs)?;
+
+      // Parse all fields
+      let field_infos: Vec<_> = named
+        .named
+        .iter()
+        .map(|field| parse_field_info(field, struct_attrs.rename_all))
+        .collect::<syn::Result<Vec<_>>>()?;
+      check_try_custom_async(&field_infos, struct_attrs.async_emit)?;
+
+      // Generate validate and filter methods
+      let validate_fn = gen_validate(
+        &field_infos,
+        &cross_validate.rules,
+        struct_attrs.break_on_failure,
+      )?;
+      let filter_fn = gen_filter(&field_infos);
+      let async_fns = if struct_attrs.async_emit {
+        let validate_async_fn = gen_validate_async(
+          &field_infos,
+          &cross_validate.rules,
+          struct_attrs.break_on_failure,
+        )?;
+        Some((validate_async_fn, gen_filter_async(&field_infos)))
+      } else {
+        None
+      };
+      (struct_attrs, validate_fn, filter_fn, async_fns)
+    }
+    // Enums whose variants have named fields (or none)
+    Data::Enum(de) => {
+      if de.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+          type_name,
+          "Fieldset cannot be derived for an enum with no variants",
+        ));
       }
-    },
-    _ => {
+
+      // On enums, serde's `rename_all` renames variants; `rename_all_fields` renames fields.
+      let struct_attrs = parse_fieldset_struct_attrs(&input.attrs, "rename_all_fields")?;
+      let cross_validate = parse_cross_validate_attrs(&input.attrs)?;
+      if cross_validate
+        .rules
+        .iter()
+        .any(|rule| !matches!(rule, CrossValidateRule::Custom(_)))
+      {
+        return Err(syn::Error::new_spanned(
+          type_name,
+          "field-based cross_validate rules go on the enum variant whose fields they name",
+        ));
+      }
+
+      let tagging = EnumTagging::from_attrs(&input.attrs)?;
+      let variant_infos: Vec<_> = de
+        .variants
+        .iter()
+        .map(|variant| parse_variant_info(variant, struct_attrs.rename_all, &tagging))
+        .collect::<syn::Result<Vec<_>>>()?;
+      for fields in variant_infos.iter().filter_map(|v| v.fields.as_deref()) {
+        check_try_custom_async(fields, struct_attrs.async_emit)?;
+      }
+
+      let validate_fn = gen_validate_enum(
+        &variant_infos,
+        &cross_validate.rules,
+        struct_attrs.break_on_failure,
+      )?;
+      let filter_fn = gen_filter_enum(&variant_infos, false);
+      let async_fns = if struct_attrs.async_emit {
+        let validate_async_fn = gen_validate_async_enum(
+          &variant_infos,
+          &cross_validate.rules,
+          struct_attrs.break_on_failure,
+        )?;
+        Some((validate_async_fn, gen_filter_enum(&variant_infos, true)))
+      } else {
+        None
+      };
+      (struct_attrs, validate_fn, filter_fn, async_fns)
+    }
+    Data::Union(_) => {
       return Err(syn::Error::new_spanned(
-        struct_name,
-        "Fieldset can only be derived for structs",
+        type_name,
+        "Fieldset can only be derived for structs and enums",
       ));
     }
   };
 
-  // Parse struct-level attributes
-  let struct_attrs = parse_fieldset_struct_attrs(&input.attrs);
-  let cross_validate = parse_cross_validate_attrs(&input.attrs)?;
-
-  // Parse all fields
-  let field_infos: Vec<_> = fields
-    .iter()
-    .map(parse_field_info)
-    .collect::<syn::Result<Vec<_>>>()?;
-
   // Generate the const
   let break_on_failure = struct_attrs.break_on_failure;
 
-  // Generate validate and filter methods
-  let validate_fn = gen_validate(
-    &field_infos,
-    &cross_validate.rules,
-    struct_attrs.break_on_failure,
-  )?;
-  let filter_fn = gen_filter(&field_infos);
-
-  let async_impl = if struct_attrs.async_emit {
-    let validate_async_fn = gen_validate_async(
-      &field_infos,
-      &cross_validate.rules,
-      struct_attrs.break_on_failure,
-    )?;
-    quote! {
+  let async_impl = match async_fns {
+    Some((validate_async_fn, filter_async_fn)) => quote! {
       #[cfg(feature = "async")]
       #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
-      impl #impl_generics walrs_fieldfilter::FieldsetAsync for #struct_name #ty_generics #where_clause {
+      impl #impl_generics walrs_fieldfilter::FieldsetAsync for #type_name #ty_generics #where_clause {
         #validate_async_fn
-
-        fn filter_async(
-          self,
-        ) -> impl ::core::future::Future<Output = ::core::result::Result<Self, walrs_validation::FieldsetViolations>> + Send {
-          async move { <Self as walrs_fieldfilter::Fieldset>::filter(self) }
-        }
+        #filter_async_fn
       }
-    }
-  } else {
-    quote! {}
+    },
+    None => quote! {},
   };
 
   Ok(quote! {
-    impl #impl_generics walrs_fieldfilter::Fieldset for #struct_name #ty_generics #where_clause {
+    impl #impl_generics walrs_fieldfilter::Fieldset for #type_name #ty_generics #where_clause {
       const BREAK_ON_FAILURE: bool = #break_on_failure;
 
       #validate_fn
@@ -1,8 +1,10 @@
-use quote::ToTokens;
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::ext::IdentExt;
 use syn::{
-  Attribute, Expr, ExprLit, Field, Ident, Lit, LitFloat, LitInt, LitStr, MetaNameValue, Path,
-  Token, Type, TypePath, parenthesized, parse::Parse, parse::ParseStream, punctuated::Punctuated,
-  token,
+  Attribute, Expr, ExprLit, Field, Fields, Ident, Lit, LitFloat, LitInt, LitStr, MetaNameValue,
+  Path, Token, Type, TypePath, Variant, meta::ParseNestedMeta, parenthesized, parse::Parse,
+  parse::ParseStream, punctuated::Punctuated, token,
 };
 
 // ---------------------------------------------------------------------------
@@ -17,6 +19,173 @@ pub struct FieldsetStructAttrs {
   // Field renamed from `r#async` because `r#async` cannot be used as an idiomatic struct field
   // name in some downstream tooling; meaning is "emit async impl".
   pub async_emit: bool,
+  /// `#[fieldset(rename_all = "...")]`, falling back to `#[serde(rename_all = "...")]`.
+  pub rename_all: Option<RenameRule>,
+}
+
+/// A `rename_all` case convention, applied to field names the way serde does.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RenameRule {
+  Lower,
+  Upper,
+  Pascal,
+  Camel,
+  Snake,
+  ScreamingSnake,
+  Kebab,
+  ScreamingKebab,
+}
+
+impl RenameRule {
+  const NAMES: &[(&str, RenameRule)] = &[
+    ("lowercase", RenameRule::Lower),
+    ("UPPERCASE", RenameRule::Upper),
+    ("PascalCase", RenameRule::Pascal),
+    ("camelCase", RenameRule::Camel),
+    ("snake_case", RenameRule::Snake),
+    ("SCREAMING_SNAKE_CASE", RenameRule::ScreamingSnake),
+    ("kebab-case", RenameRule::Kebab),
+    ("SCREAMING-KEBAB-CASE", RenameRule::ScreamingKebab),
+  ];
+
+  fn from_lit(lit: &LitStr) -> syn::Result<Self> {
+    let value = lit.value();
+    Self::NAMES
+      .iter()
+      .find(|(name, _)| *name == value)
+      .map(|(_, rule)| *rule)
+      .ok_or_else(|| {
+        let names: Vec<&str> = Self::NAMES.iter().map(|(name, _)| *name).collect();
+        syn::Error::new_spanned(
+          lit,
+          format!(
+            "unknown rename_all rule \"{value}\"; expected one of: {}",
+            names.join(", ")
+          ),
+        )
+      })
+  }
+
+  /// Renames a snake_case field name.
+  pub fn apply_to_field(self, field: &str) -> String {
+    match self {
+      RenameRule::Lower | RenameRule::Snake => field.to_string(),
+      RenameRule::Upper | RenameRule::ScreamingSnake => field.to_ascii_uppercase(),
+      RenameRule::Pascal => field
+        .split('_')
+        .map(|word| {
+          let mut chars = word.chars();
+          chars
+            .next()
+            .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+            .unwrap_or_default()
+        })
+        .collect(),
+      RenameRule::Camel => {
+        let pascal = RenameRule::Pascal.apply_to_field(field);
+        let mut chars = pascal.chars();
+        chars
+          .next()
+          .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+          .unwrap_or_default()
+      }
+      RenameRule::Kebab => field.replace('_', "-"),
+      RenameRule::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+    }
+  }
+
+  /// Renames a PascalCase variant name.
+  pub fn apply_to_variant(self, variant: &str) -> String {
+    match self {
+      RenameRule::Pascal => variant.to_string(),
+      RenameRule::Lower => variant.to_ascii_lowercase(),
+      RenameRule::Upper => variant.to_ascii_uppercase(),
+      RenameRule::Camel => {
+        let mut chars = variant.chars();
+        chars
+          .next()
+          .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+          .unwrap_or_default()
+      }
+      RenameRule::Snake => {
+        let mut snake = String::new();
+        for (i, ch) in variant.char_indices() {
+          if i > 0 && ch.is_uppercase() {
+            snake.push('_');
+          }
+          snake.push(ch.to_ascii_lowercase());
+        }
+        snake
+      }
+      RenameRule::ScreamingSnake => RenameRule::Snake
+        .apply_to_variant(variant)
+        .to_ascii_uppercase(),
+      RenameRule::Kebab => RenameRule::Snake
+        .apply_to_variant(variant)
+        .replace('_', "-"),
+      RenameRule::ScreamingKebab => RenameRule::ScreamingSnake
+        .apply_to_variant(variant)
+        .replace('_', "-"),
+    }
+  }
+}
+
+/// How serde tags an enum, which decides where a variant's fields sit in the payload and so
+/// how their violation keys are prefixed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnumTagging {
+  /// The default, `{"Card": {"number": ...}}`: keys are prefixed with the variant name,
+  /// renamed by the enum's `#[serde(rename_all = "...")]` if set.
+  External(Option<RenameRule>),
+  /// `#[serde(tag = "...")]`: fields sit beside the tag, so keys are not prefixed.
+  Internal,
+  /// `#[serde(tag = "...", content = "...")]`: keys are prefixed with the content key.
+  Adjacent(String),
+  /// `#[serde(untagged)]`: fields sit at the top level, so keys are not prefixed.
+  Untagged,
+}
+
+impl EnumTagging {
+  /// Reads `tag`, `content`, `untagged` and `rename_all` from the enum's `#[serde(...)]`.
+  pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+    if serde_flag(attrs, "untagged") {
+      return Ok(EnumTagging::Untagged);
+    }
+    Ok(
+      match (serde_rename(attrs, "tag"), serde_rename(attrs, "content")) {
+        (Some(_), Some(content)) => EnumTagging::Adjacent(content.value()),
+        (Some(_), None) => EnumTagging::Internal,
+        (None, _) => EnumTagging::External(
+          serde_rename(attrs, "rename_all")
+            .map(|lit| RenameRule::from_lit(&lit))
+            .transpose()?,
+        ),
+      },
+    )
+  }
+
+  /// The key prefix for `variant`'s fields, if any. A variant marked `#[serde(untagged)]`
+  /// has none.
+  fn prefix(&self, variant: &Variant) -> Option<String> {
+    if serde_flag(&variant.attrs, "untagged") {
+      return None;
+    }
+    match self {
+      EnumTagging::External(rename_all) => Some(
+        serde_rename(&variant.attrs, "rename")
+          .map(|lit| lit.value())
+          .unwrap_or_else(|| {
+            let raw = variant.ident.unraw().to_string();
+            match rename_all {
+              Some(rule) => rule.apply_to_variant(&raw),
+              None => raw,
+            }
+          }),
+      ),
+      EnumTagging::Adjacent(content) => Some(content.clone()),
+      EnumTagging::Internal | EnumTagging::Untagged => None,
+    }
+  }
 }
 
 /// Parsed `#[cross_validate(...)]` attributes on the struct.
@@ -71,12 +240,70 @@ pub enum ConditionLiteral {
 #[derive(Debug)]
 pub struct FieldInfo {
   pub ident: Ident,
+  /// Violation key: `#[validate(name = "...")]`, `#[serde(rename = "...")]`, or the
+  /// identifier with the struct's `rename_all` rule applied.
+  pub key: String,
   pub ty: FieldType,
   pub validations: Vec<ValidateAttr>,
   pub filters: Vec<FilterAttr>,
   pub is_nested_validate: bool,
   pub is_nested_filter: bool,
   pub break_on_failure_override: Option<bool>,
+  /// Element rules from `#[validate(each(...))]` on a collection field, as a pseudo-field
+  /// typed by the element (or map value) type.
+  pub each: Option<Box<FieldInfo>>,
+  /// Name the field is bound to in a `match self` arm, for enum variant fields.
+  pub binding: Option<Ident>,
+}
+
+impl FieldInfo {
+  /// Place expression for the field's value: `self.field` on structs, or the dereferenced
+  /// variant binding (`(*__field_x)`) inside `match self` on `&self`.
+  pub fn place(&self) -> TokenStream {
+    let ident = &self.ident;
+    match &self.binding {
+      Some(binding) => quote! { (*#binding) },
+      None => quote! { self.#ident },
+    }
+  }
+
+  /// The field's value in by-value (`self`) methods: `self.field`, or the variant binding.
+  pub fn owned(&self) -> TokenStream {
+    let ident = &self.ident;
+    match &self.binding {
+      Some(binding) => quote! { #binding },
+      None => quote! { self.#ident },
+    }
+  }
+}
+
+/// One variant of an enum deriving `Fieldset`.
+#[derive(Debug)]
+pub struct VariantInfo {
+  pub ident: Ident,
+  /// `None` for unit variants.
+  pub fields: Option<Vec<FieldInfo>>,
+  /// `#[cross_validate(...)]` rules on the variant, checked when it is the active one.
+  pub cross_rules: Vec<CrossValidateRule>,
+}
+
+impl VariantInfo {
+  /// Match pattern binding every field by its [`FieldInfo::binding`], e.g.
+  /// `Self::Card { number: __field_number }`.
+  pub fn pattern(&self) -> TokenStream {
+    let ident = &self.ident;
+    match &self.fields {
+      Some(fields) => {
+        let bindings = fields.iter().map(|field| {
+          let name = &field.ident;
+          let binding = &field.binding;
+          quote! { #name: #binding }
+        });
+        quote! { Self::#ident { #(#bindings),* } }
+      }
+      None => quote! { Self::#ident },
+    }
+  }
 }
 
 /// Simplified type classification.
@@ -91,6 +318,23 @@ pub enum FieldType {
   OptionBool,
   OptionChar,
   OptionNumeric(Ident),
+  /// `chrono::NaiveDate`/`NaiveDateTime` or `jiff::civil::Date`/`DateTime`.
+  Date(Type),
+  OptionDate(Type),
+  /// `Vec<T>`; holds the element type.
+  Vec(Type),
+  OptionVec(Type),
+  /// `HashMap<String, T>`, `BTreeMap<String, T>`, or `IndexMap<String, T>`; holds the value
+  /// type. `sorted` is set for `HashMap`, whose entries are sorted by key before validating
+  /// so violations come out in a stable order.
+  Map {
+    value: Type,
+    sorted: bool,
+  },
+  OptionMap {
+    value: Type,
+    sorted: bool,
+  },
   Other(Type), // for nested types
   OptionOther(Type),
 }
@@ -118,6 +362,28 @@ pub enum ValidateAttr {
     max: NumericLit,
   },
   Step(NumericLit),
+  /// `date` / `date(format = "...", allow_time)` — string must parse as a date.
+  Date {
+    format: DateFormatLit,
+    allow_time: bool,
+  },
+  /// `date_range(min = "...", max = "...", format = "...", allow_time)` on string fields.
+  DateRange {
+    format: DateFormatLit,
+    allow_time: bool,
+    min: Option<String>,
+    max: Option<String>,
+  },
+  /// `min_items = N` on a collection field.
+  MinItems(usize),
+  /// `max_items = N` on a collection field.
+  MaxItems(usize),
+  /// `unique` on a `Vec` field — no two elements may be equal.
+  Unique,
+  /// `min = "..."` on a date field (also produced by `date_range` on date fields).
+  MinDate(String),
+  /// `max = "..."` on a date field (also produced by `date_range` on date fields).
+  MaxDate(String),
   OneOf(Vec<OneOfItem>),
   Custom(Path),
   /// `custom_async = "path::fn"` — async custom validator. Only honored by the async codegen
@@ -135,6 +401,18 @@ pub enum NumericLit {
   Float(f64),
 }
 
+/// Parsed `format = "..."` of `date(...)` / `date_range(...)`.
+#[derive(Debug, Clone, Default)]
+pub enum DateFormatLit {
+  #[default]
+  Iso8601,
+  UsDate,
+  EuDate,
+  Rfc2822,
+  /// A strftime-style format string (contains `%`).
+  Custom(String),
+}
+
 /// Items in a `one_of = [...]` list.
 #[derive(Debug, Clone)]
 pub enum OneOfItem {
@@ -154,17 +432,35 @@ pub enum FilterAttr {
   Uppercase,
   StripTags,
   HtmlEntities,
-  Slug { max_length: Option<usize> },
-  Truncate { max_length: usize },
-  Replace { from: String, to: String },
-  Clamp { min: NumericLit, max: NumericLit },
+  Slug {
+    max_length: Option<usize>,
+  },
+  Truncate {
+    max_length: usize,
+  },
+  Replace {
+    from: String,
+    to: String,
+  },
+  Clamp {
+    min: NumericLit,
+    max: NumericLit,
+  },
   Digits,
-  Alnum { allow_whitespace: bool },
-  Alpha { allow_whitespace: bool },
+  Alnum {
+    allow_whitespace: bool,
+  },
+  Alpha {
+    allow_whitespace: bool,
+  },
   StripNewlines,
   NormalizeWhitespace,
-  AllowChars { set: String },
-  DenyChars { set: String },
+  AllowChars {
+    set: String,
+  },
+  DenyChars {
+    set: String,
+  },
   UrlEncode,
   ToBool,
   ToInt,
@@ -172,6 +468,9 @@ pub enum FilterAttr {
   UrlDecode,
   Custom(Path),
   TryCustom(Path),
+  /// `try_custom_async = "path::fn"` — async fallible filter. Only awaited by the async
+  /// codegen path; the sync codegen fails with `FilterErrorCode::AsyncOnly`.
+  TryCustomAsync(Path),
 }
 
 // ---------------------------------------------------------------------------
@@ -210,6 +509,18 @@ pub fn classify_type(ty: &Type) -> FieldType {
     if NUMERIC_TYPES.contains(&name.as_str()) {
       return FieldType::Numeric(seg.ident.clone());
     }
+    if is_date_path(path) {
+      return FieldType::Date(ty.clone());
+    }
+    if let Some(elem) = vec_element(seg) {
+      return FieldType::Vec(elem.clone());
+    }
+    if let Some((value, sorted)) = map_value(seg) {
+      return FieldType::Map {
+        value: value.clone(),
+        sorted,
+      };
+    }
   }
   FieldType::Other(ty.clone())
 }
@@ -231,15 +542,98 @@ fn classify_option_inner(inner: &Type, _outer: &Type) -> FieldType {
     if NUMERIC_TYPES.contains(&name.as_str()) {
       return FieldType::OptionNumeric(seg.ident.clone());
     }
+    if is_date_path(path) {
+      return FieldType::OptionDate(inner.clone());
+    }
+    if let Some(elem) = vec_element(seg) {
+      return FieldType::OptionVec(elem.clone());
+    }
+    if let Some((value, sorted)) = map_value(seg) {
+      return FieldType::OptionMap {
+        value: value.clone(),
+        sorted,
+      };
+    }
   }
   FieldType::OptionOther(inner.clone())
 }
 
+/// Generic type arguments of a path segment, e.g. `[K, V]` for `HashMap<K, V>`.
+fn type_args(seg: &syn::PathSegment) -> Vec<&Type> {
+  match &seg.arguments {
+    syn::PathArguments::AngleBracketed(args) => args
+      .args
+      .iter()
+      .filter_map(|arg| match arg {
+        syn::GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+      })
+      .collect(),
+    _ => Vec::new(),
+  }
+}
+
+/// The element type of `Vec<T>`.
+fn vec_element(seg: &syn::PathSegment) -> Option<&Type> {
+  match type_args(seg).as_slice() {
+    [elem] if seg.ident == "Vec" => Some(elem),
+    _ => None,
+  }
+}
+
+/// The value type of a `String`-keyed `HashMap`, `BTreeMap`, or `IndexMap`, and whether its
+/// entries need sorting for a stable iteration order.
+fn map_value(seg: &syn::PathSegment) -> Option<(&Type, bool)> {
+  let name = seg.ident.to_string();
+  if !matches!(name.as_str(), "HashMap" | "BTreeMap" | "IndexMap") {
+    return None;
+  }
+  match type_args(seg).as_slice() {
+    [key, value, ..] if matches!(classify_type(key), FieldType::String) => {
+      Some((value, name == "HashMap"))
+    }
+    _ => None,
+  }
+}
+
+/// Whether `path` names a date type with native `Rule` support: `NaiveDate` /
+/// `NaiveDateTime` (any path), or `Date` / `DateTime` written bare or as
+/// `civil::Date` / `civil::DateTime` (jiff). Generic types such as
+/// `chrono::DateTime<Utc>` are not dates here.
+fn is_date_path(path: &Path) -> bool {
+  let Some(seg) = path.segments.last() else {
+    return false;
+  };
+  if !seg.arguments.is_empty() {
+    return false;
+  }
+  match seg.ident.to_string().as_str() {
+    "NaiveDate" | "NaiveDateTime" => true,
+    "Date" | "DateTime" => {
+      let len = path.segments.len();
+      len == 1 || path.segments[len - 2].ident == "civil"
+    }
+    _ => false,
+  }
+}
+
+/// Whether a date type (as classified by [`is_date_path`]) carries a time.
+fn date_type_has_time(ty: &Type) -> bool {
+  matches!(ty, Type::Path(TypePath { path, .. })
+    if path.segments.last().is_some_and(|seg| seg.ident.to_string().ends_with("DateTime")))
+}
+
 // ---------------------------------------------------------------------------
 // Parse struct-level `#[fieldset(...)]`
 // ---------------------------------------------------------------------------
 
-pub fn parse_fieldset_struct_attrs(attrs: &[Attribute]) -> FieldsetStructAttrs {
+/// Parses `#[fieldset(...)]` on the struct or enum. `serde_rename_all` names the serde
+/// option `rename_all` falls back to: `rename_all` on structs, `rename_all_fields` on enums
+/// (where serde's `rename_all` renames the variants instead).
+pub fn parse_fieldset_struct_attrs(
+  attrs: &[Attribute],
+  serde_rename_all: &str,
+) -> syn::Result<FieldsetStructAttrs> {
   let mut result = FieldsetStructAttrs::default();
   for attr in attrs {
     if attr.path().is_ident("fieldset") {
@@ -253,7 +647,82 @@ pub fn parse_fieldset_struct_attrs(attrs: &[Attribute]) -> FieldsetStructAttrs {
       });
     }
   }
-  result
+  result.rename_all = parse_rename_all(attrs, serde_rename_all)?;
+  Ok(result)
+}
+
+/// `#[fieldset(rename_all = "...")]`, falling back to the `serde_key` serde option.
+fn parse_rename_all(attrs: &[Attribute], serde_key: &str) -> syn::Result<Option<RenameRule>> {
+  let mut rename_all = None;
+  for attr in attrs.iter().filter(|attr| attr.path().is_ident("fieldset")) {
+    let _ = attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident("rename_all") {
+        rename_all = Some(meta.value().and_then(|value| value.parse::<LitStr>()));
+        Ok(())
+      } else {
+        skip_meta_value(&meta)
+      }
+    });
+  }
+  match rename_all {
+    Some(lit) => RenameRule::from_lit(&lit?).map(Some),
+    None => serde_rename(attrs, serde_key)
+      .map(|lit| RenameRule::from_lit(&lit))
+      .transpose(),
+  }
+}
+
+/// The deserialize-side value of `#[serde(<key> = "...")]` or
+/// `#[serde(<key>(deserialize = "..."))]`, if any — the name the payload arrives under.
+///
+/// Other serde options are skipped; malformed ones are left for serde to report.
+fn serde_rename(attrs: &[Attribute], key: &str) -> Option<LitStr> {
+  let mut found = None;
+  for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+    let _ = attr.parse_nested_meta(|meta| {
+      if !meta.path.is_ident(key) {
+        return skip_meta_value(&meta);
+      }
+      if meta.input.peek(Token![=]) {
+        found = Some(meta.value()?.parse::<LitStr>()?);
+      } else {
+        meta.parse_nested_meta(|inner| {
+          if inner.path.is_ident("deserialize") {
+            found = Some(inner.value()?.parse::<LitStr>()?);
+            Ok(())
+          } else {
+            skip_meta_value(&inner)
+          }
+        })?;
+      }
+      Ok(())
+    });
+  }
+  found
+}
+
+/// Whether a bare `#[serde(<key>)]` flag is present.
+fn serde_flag(attrs: &[Attribute], key: &str) -> bool {
+  let mut found = false;
+  for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+    let _ = attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident(key) {
+        found = true;
+      }
+      skip_meta_value(&meta)
+    });
+  }
+  found
+}
+
+/// Consumes the `= value` or `(...)` following a meta path we do not interpret.
+fn skip_meta_value(meta: &ParseNestedMeta<'_>) -> syn::Result<()> {
+  if meta.input.peek(Token![=]) {
+    meta.value()?.parse::<Expr>()?;
+  } else if meta.input.peek(token::Paren) {
+    meta.parse_nested_meta(|inner| skip_meta_value(&inner))?;
+  }
+  Ok(())
 }
 
 // ---------------------------------------------------------------------------
@@ -472,21 +941,30 @@ fn parse_dependent_required(
 // Parse field-level attributes
 // ---------------------------------------------------------------------------
 
-pub fn parse_field_info(field: &Field) -> syn::Result<FieldInfo> {
+pub fn parse_field_info(field: &Field, rename_all: Option<RenameRule>) -> syn::Result<FieldInfo> {
   let ident = field
     .ident
     .clone()
     .ok_or_else(|| syn::Error::new_spanned(field, "Fieldset derive only supports named fields"))?;
+  let mut name = serde_rename(&field.attrs, "rename").map(|lit| lit.value());
   let ty = classify_type(&field.ty);
   let mut validations = Vec::new();
   let mut filters = Vec::new();
   let mut is_nested_validate = false;
   let mut is_nested_filter = false;
   let mut break_on_failure_override = None;
+  let mut each_validations = Vec::new();
 
   for attr in &field.attrs {
     if attr.path().is_ident("validate") {
-      parse_validate_attr(attr, &mut validations, &mut is_nested_validate)?;
+      parse_validate_attr(
+        attr,
+        &ty,
+        &mut validations,
+        &mut is_nested_validate,
+        &mut each_validations,
+        &mut name,
+      )?;
     } else if attr.path().is_ident("filter") {
       parse_filter_attr(attr, &mut filters, &mut is_nested_filter)?;
     } else if attr.path().is_ident("fieldset") {
@@ -505,25 +983,159 @@ pub fn parse_field_info(field: &Field) -> syn::Result<FieldInfo> {
     }
   }
 
+  let key = name.unwrap_or_else(|| {
+    let raw = ident.unraw().to_string();
+    match rename_all {
+      Some(rule) => rule.apply_to_field(&raw),
+      None => raw,
+    }
+  });
+
+  let each = match collection_element(&ty) {
+    Some(elem_ty) if !each_validations.is_empty() => Some(Box::new(FieldInfo {
+      ident: ident.clone(),
+      key: key.clone(),
+      ty: classify_type(elem_ty),
+      validations: each_validations,
+      filters: Vec::new(),
+      is_nested_validate: false,
+      is_nested_filter: false,
+      break_on_failure_override: None,
+      each: None,
+      binding: None,
+    })),
+    _ => None,
+  };
+
   Ok(FieldInfo {
     ident,
+    key,
     ty,
     validations,
     filters,
     is_nested_validate,
     is_nested_filter,
     break_on_failure_override,
+    each,
+    binding: None,
   })
 }
 
+/// Parses one enum variant. Field keys use the variant's `#[fieldset(rename_all)]` or
+/// `#[serde(rename_all)]`, falling back to the enum's `rename_all`, and are prefixed the
+/// way `tagging` nests the variant's fields.
+pub fn parse_variant_info(
+  variant: &Variant,
+  rename_all: Option<RenameRule>,
+  tagging: &EnumTagging,
+) -> syn::Result<VariantInfo> {
+  let rename_all = parse_rename_all(&variant.attrs, "rename_all")?.or(rename_all);
+  let prefix = tagging.prefix(variant);
+  let fields = match &variant.fields {
+    Fields::Named(named) => Some(
+      named
+        .named
+        .iter()
+        .map(|field| {
+          let mut info = parse_field_info(field, rename_all)?;
+          info.binding = Some(format_ident!("__field_{}", info.ident.unraw()));
+          if let Some(prefix) = &prefix {
+            info.key = format!("{prefix}.{}", info.key);
+            if let Some(each) = &mut info.each {
+              each.key.clone_from(&info.key);
+            }
+          }
+          Ok(info)
+        })
+        .collect::<syn::Result<Vec<_>>>()?,
+    ),
+    Fields::Unit => None,
+    Fields::Unnamed(_) => {
+      return Err(syn::Error::new_spanned(
+        variant,
+        "Fieldset can only be derived for enum variants with named fields or no fields",
+      ));
+    }
+  };
+  Ok(VariantInfo {
+    ident: variant.ident.clone(),
+    fields,
+    cross_rules: parse_cross_validate_attrs(&variant.attrs)?.rules,
+  })
+}
+
+/// The element type of a `Vec` field, or the value type of a map field.
+pub fn collection_element(ty: &FieldType) -> Option<&Type> {
+  match ty {
+    FieldType::Vec(elem) | FieldType::OptionVec(elem) => Some(elem),
+    FieldType::Map { value, .. } | FieldType::OptionMap { value, .. } => Some(value),
+    _ => None,
+  }
+}
+
+/// Attributes accepted directly on a collection field; everything else belongs in `each(...)`.
+const COLLECTION_ATTRS: &[&str] = &[
+  "required",
+  "min_items",
+  "max_items",
+  "unique",
+  "each",
+  "nested",
+];
+
 fn parse_validate_attr(
   attr: &Attribute,
+  ty: &FieldType,
   validations: &mut Vec<ValidateAttr>,
   is_nested: &mut bool,
+  each_validations: &mut Vec<ValidateAttr>,
+  name: &mut Option<String>,
 ) -> syn::Result<()> {
   attr.parse_nested_meta(|meta| {
-    let path = &meta.path;
+    if meta.path.is_ident("name") {
+      let lit: LitStr = meta.value()?.parse()?;
+      if lit.value().is_empty() {
+        return Err(syn::Error::new_spanned(
+          lit,
+          "`name` must not be empty; the empty key is reserved for form-level violations",
+        ));
+      }
+      *name = Some(lit.value());
+      return Ok(());
+    }
+    parse_validate_meta(meta, ty, validations, is_nested, Some(each_validations))
+  })
+}
+
+/// Parses one item of `#[validate(...)]`. `each_validations` is `None` while parsing the
+/// contents of `each(...)`, where `each` and `nested` may not appear again.
+fn parse_validate_meta(
+  meta: ParseNestedMeta<'_>,
+  ty: &FieldType,
+  validations: &mut Vec<ValidateAttr>,
+  is_nested: &mut bool,
+  each_validations: Option<&mut Vec<ValidateAttr>>,
+) -> syn::Result<()> {
+  let date_ty = match ty {
+    FieldType::Date(date_ty) | FieldType::OptionDate(date_ty) => Some(date_ty),
+    _ => None,
+  };
+  let elem_ty = collection_element(ty);
+  let path = &meta.path;
+
+  if elem_ty.is_some() && !COLLECTION_ATTRS.iter().any(|name| path.is_ident(name)) {
+    return Err(syn::Error::new_spanned(
+      path,
+      format!(
+        "`{}` does not apply to a collection field; use `each({})` to validate its elements, \
+         or `min_items`/`max_items` to bound its length",
+        format_meta_path(path),
+        format_meta_path(path),
+      ),
+    ));
+  }
 
+  {
     if path.is_ident("required") {
       validations.push(ValidateAttr::Required);
     } else if path.is_ident("min_length") {
@@ -559,12 +1171,98 @@ fn parse_validate_attr(
         ));
       }
       validations.push(ValidateAttr::Pattern(pat));
+    } else if let Some(date_ty) = date_ty.filter(|_| path.is_ident("min") || path.is_ident("max")) {
+      let _: Token![=] = meta.input.parse()?;
+      let lit: LitStr = meta.input.parse().map_err(|e| {
+        syn::Error::new(
+          e.span(),
+          "date fields take a string bound, e.g. `min = \"2020-01-01\"`",
+        )
+      })?;
+      let bound = check_date_literal(&lit, date_ty)?;
+      validations.push(if path.is_ident("min") {
+        ValidateAttr::MinDate(bound)
+      } else {
+        ValidateAttr::MaxDate(bound)
+      });
     } else if path.is_ident("min") {
       let _: Token![=] = meta.input.parse()?;
       validations.push(ValidateAttr::Min(parse_numeric_lit(&meta.input)?));
     } else if path.is_ident("max") {
       let _: Token![=] = meta.input.parse()?;
       validations.push(ValidateAttr::Max(parse_numeric_lit(&meta.input)?));
+    } else if path.is_ident("date") {
+      if date_ty.is_some() {
+        return Err(syn::Error::new_spanned(
+          path,
+          "`date` validates date strings; date-typed fields are already dates \
+           (use `min`, `max`, or `date_range`)",
+        ));
+      }
+      let mut format = DateFormatLit::default();
+      let mut allow_time = false;
+      if meta.input.peek(token::Paren) {
+        meta.parse_nested_meta(|inner| {
+          if inner.path.is_ident("format") {
+            format = parse_date_format(&inner.value()?.parse()?)?;
+          } else if inner.path.is_ident("allow_time") {
+            allow_time = true;
+          } else {
+            return Err(inner.error("expected `format = \"...\"` or `allow_time`"));
+          }
+          Ok(())
+        })?;
+      }
+      validations.push(ValidateAttr::Date { format, allow_time });
+    } else if path.is_ident("date_range") {
+      let mut format = None;
+      let mut allow_time = false;
+      let mut min = None;
+      let mut max = None;
+      meta.parse_nested_meta(|inner| {
+        if inner.path.is_ident("format") {
+          format = Some(parse_date_format(&inner.value()?.parse()?)?);
+        } else if inner.path.is_ident("allow_time") {
+          allow_time = true;
+        } else if inner.path.is_ident("min") {
+          min = Some(inner.value()?.parse::<LitStr>()?);
+        } else if inner.path.is_ident("max") {
+          max = Some(inner.value()?.parse::<LitStr>()?);
+        } else {
+          return Err(inner.error(
+            "expected `min = \"...\"`, `max = \"...\"`, `format = \"...\"`, or `allow_time`",
+          ));
+        }
+        Ok(())
+      })?;
+      if min.is_none() && max.is_none() {
+        return Err(syn::Error::new_spanned(
+          path,
+          "date_range requires `min`, `max`, or both",
+        ));
+      }
+      match date_ty {
+        Some(date_ty) => {
+          if format.is_some() || allow_time {
+            return Err(syn::Error::new_spanned(
+              path,
+              "`format` and `allow_time` apply to date strings, not date-typed fields",
+            ));
+          }
+          if let Some(min) = &min {
+            validations.push(ValidateAttr::MinDate(check_date_literal(min, date_ty)?));
+          }
+          if let Some(max) = &max {
+            validations.push(ValidateAttr::MaxDate(check_date_literal(max, date_ty)?));
+          }
+        }
+        None => validations.push(ValidateAttr::DateRange {
+          format: format.unwrap_or_default(),
+          allow_time,
+          min: min.map(|lit| lit.value()),
+          max: max.map(|lit| lit.value()),
+        }),
+      }
     } else if path.is_ident("range") {
       let content;
       parenthesized!(content in meta.input);
@@ -635,7 +1333,69 @@ fn parse_validate_attr(
       let lit: LitStr = meta.input.parse()?;
       let path: Path = lit.parse()?;
       validations.push(ValidateAttr::CustomAsync(path));
+    } else if path.is_ident("min_items") || path.is_ident("max_items") || path.is_ident("unique") {
+      if elem_ty.is_none() {
+        return Err(syn::Error::new_spanned(
+          path,
+          format!(
+            "`{}` only applies to `Vec` and map fields",
+            format_meta_path(path)
+          ),
+        ));
+      }
+      if path.is_ident("unique") {
+        if !matches!(ty, FieldType::Vec(_) | FieldType::OptionVec(_)) {
+          return Err(syn::Error::new_spanned(
+            path,
+            "`unique` only applies to `Vec` fields; map keys are already unique",
+          ));
+        }
+        validations.push(ValidateAttr::Unique);
+      } else {
+        let _: Token![=] = meta.input.parse()?;
+        let lit: LitInt = meta.input.parse()?;
+        validations.push(if path.is_ident("min_items") {
+          ValidateAttr::MinItems(lit.base10_parse()?)
+        } else {
+          ValidateAttr::MaxItems(lit.base10_parse()?)
+        });
+      }
+    } else if path.is_ident("each") {
+      let (Some(elem_ty), Some(each_validations)) = (elem_ty, each_validations) else {
+        return Err(syn::Error::new_spanned(
+          path,
+          "`each(...)` only applies to `Vec` and map fields",
+        ));
+      };
+      let elem = classify_type(elem_ty);
+      if !matches!(
+        elem,
+        FieldType::String
+          | FieldType::Bool
+          | FieldType::Char
+          | FieldType::Numeric(_)
+          | FieldType::Date(_)
+      ) {
+        return Err(syn::Error::new_spanned(
+          elem_ty,
+          "`each(...)` requires String, numeric, bool, char, or date elements; \
+           use `nested` for elements that derive Fieldset",
+        ));
+      }
+      let mut each_nested = false;
+      meta.parse_nested_meta(|inner| {
+        if inner.path.is_ident("custom_async") {
+          return Err(inner.error("`custom_async` is not supported inside `each(...)`"));
+        }
+        parse_validate_meta(inner, &elem, each_validations, &mut each_nested, None)
+      })?;
     } else if path.is_ident("nested") {
+      if each_validations.is_none() {
+        return Err(syn::Error::new_spanned(
+          path,
+          "`nested` goes directly on the collection field, not inside `each(...)`",
+        ));
+      }
       *is_nested = true;
     } else if path.is_ident("message") {
       let _: Token![=] = meta.input.parse()?;
@@ -656,8 +1416,8 @@ fn parse_validate_attr(
         format!("Unknown validate attribute: {}", format_meta_path(path)),
       ));
     }
-    Ok(())
-  })
+  }
+  Ok(())
 }
 
 fn parse_filter_attr(
@@ -785,6 +1545,11 @@ fn parse_filter_attr(
       let lit: LitStr = meta.input.parse()?;
       let path: Path = lit.parse()?;
       filters.push(FilterAttr::TryCustom(path));
+    } else if path.is_ident("try_custom_async") {
+      let _: Token![=] = meta.input.parse()?;
+      let lit: LitStr = meta.input.parse()?;
+      let path: Path = lit.parse()?;
+      filters.push(FilterAttr::TryCustomAsync(path));
     } else if path.is_ident("nested") {
       *is_nested = true;
     } else {
@@ -892,6 +1657,69 @@ fn expr_to_usize(expr: &Expr) -> syn::Result<usize> {
   }
 }
 
+/// Parse a `format = "..."` value: `iso8601`, `us_date`, `eu_date`, `rfc2822`, or a
+/// strftime-style string containing `%`.
+fn parse_date_format(lit: &LitStr) -> syn::Result<DateFormatLit> {
+  let value = lit.value();
+  match value.as_str() {
+    "iso8601" => Ok(DateFormatLit::Iso8601),
+    "us_date" => Ok(DateFormatLit::UsDate),
+    "eu_date" => Ok(DateFormatLit::EuDate),
+    "rfc2822" => Ok(DateFormatLit::Rfc2822),
+    _ if value.contains('%') => Ok(DateFormatLit::Custom(value)),
+    _ => Err(syn::Error::new_spanned(
+      lit,
+      format!(
+        "unknown date format \"{value}\"; expected iso8601, us_date, eu_date, rfc2822, \
+         or a strftime pattern such as \"%d %B %Y\""
+      ),
+    )),
+  }
+}
+
+/// Check that `lit` is an ISO 8601 literal for the date type `ty`
+/// (`YYYY-MM-DD`, or `YYYY-MM-DDTHH:MM:SS[.fff]` for datetime types), so the
+/// generated `FromStr` call cannot fail at runtime.
+fn check_date_literal(lit: &LitStr, ty: &Type) -> syn::Result<String> {
+  let value = lit.value();
+  let with_time = date_type_has_time(ty);
+  let pattern = if with_time {
+    r"^(\d{4})-(\d{2})-(\d{2})T([01]\d|2[0-3]):[0-5]\d:[0-5]\d(\.\d{1,9})?$"
+  } else {
+    r"^(\d{4})-(\d{2})-(\d{2})$"
+  };
+  let valid = regex::Regex::new(pattern)
+    .expect("static pattern")
+    .captures(&value)
+    .is_some_and(|caps| {
+      let year: u32 = caps[1].parse().unwrap_or(0);
+      let month: u32 = caps[2].parse().unwrap_or(0);
+      let day: u32 = caps[3].parse().unwrap_or(0);
+      let leap = year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+      let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => 0,
+      };
+      (1..=days_in_month).contains(&day)
+    });
+  if valid {
+    Ok(value)
+  } else {
+    let expected = if with_time {
+      "YYYY-MM-DDTHH:MM:SS"
+    } else {
+      "YYYY-MM-DD"
+    };
+    Err(syn::Error::new_spanned(
+      lit,
+      format!("invalid date \"{value}\"; expected a valid {expected} literal"),
+    ))
+  }
+}
+
 fn expr_to_string(expr: &Expr) -> syn::Result<String> {
   if let Expr::Lit(ExprLit {
     lit: Lit::Str(s), ..
@@ -903,6 +1731,28 @@ fn expr_to_string(expr: &Expr) -> syn::Result<String> {
   }
 }
 
+/// Reject `try_custom_async` filters unless the type opts into `#[fieldset(async)]`:
+/// without a `FieldsetAsync` impl nothing could ever run them.
+pub fn check_try_custom_async(fields: &[FieldInfo], async_emit: bool) -> syn::Result<()> {
+  if async_emit {
+    return Ok(());
+  }
+  let path = fields
+    .iter()
+    .flat_map(|f| &f.filters)
+    .find_map(|filter| match filter {
+      FilterAttr::TryCustomAsync(path) => Some(path),
+      _ => None,
+    });
+  match path {
+    Some(path) => Err(syn::Error::new_spanned(
+      path,
+      "`try_custom_async` requires `#[fieldset(async)]`",
+    )),
+    None => Ok(()),
+  }
+}
+
 /// Check whether a meta path is the identifier `async`.
 ///
 /// `async` is a Rust keyword, so `path.is_ident("async")` does not match in
@@ -949,7 +1799,7 @@ mod tests {
   #[test]
   fn parse_field_info_rejects_unknown_validate_attrs() {
     let field = parse_named_field("struct S { #[validate(nonsense)] x: String }");
-    let err = parse_field_info(&field).expect_err("unknown validate attr should error");
+    let err = parse_field_info(&field, None).expect_err("unknown validate attr should error");
     assert!(
       err.to_string().contains("Unknown validate attribute"),
       "expected 'Unknown validate attribute' in error, got: {}",
@@ -965,7 +1815,7 @@ mod tests {
   #[test]
   fn parse_field_info_rejects_invalid_regex_pattern() {
     let field = parse_named_field(r#"struct S { #[validate(pattern = "[")] x: String }"#);
-    let err = parse_field_info(&field).expect_err("invalid regex should error");
+    let err = parse_field_info(&field, None).expect_err("invalid regex should error");
     assert!(
       err.to_string().contains("invalid regex pattern"),
       "expected 'invalid regex pattern' in error, got: {}",
@@ -973,6 +1823,15 @@ mod tests {
     );
   }
 
+  #[test]
+  fn try_custom_async_requires_fieldset_async() {
+    let field = parse_named_field(r#"struct S { #[filter(try_custom_async = "f")] x: String }"#);
+    let fields = vec![parse_field_info(&field, None).unwrap()];
+    let err = check_try_custom_async(&fields, false).expect_err("should require async");
+    assert!(err.to_string().contains("requires `#[fieldset(async)]`"));
+    assert!(check_try_custom_async(&fields, true).is_ok());
+  }
+
   #[test]
   fn parse_field_info_rejects_unknown_filter_attrs() {
     let field = parse_named_field_from_tokens(quote! {
@@ -982,7 +1841,7 @@ mod tests {
       }
     });
 
-    let err = parse_field_info(&field).expect_err("unknown filter should error");
+    let err = parse_field_info(&field, None).expect_err("unknown filter should error");
     assert!(err.to_string().contains("Unknown filter attribute"));
   }
 
@@ -995,7 +1854,7 @@ mod tests {
       }
     });
 
-    let err = parse_field_info(&field).expect_err("empty alnum parens should error");
+    let err = parse_field_info(&field, None).expect_err("empty alnum parens should error");
     assert!(
       err
         .to_string()
@@ -1003,6 +1862,174 @@ mod tests {
     );
   }
 
+  // -------------------------------------------------------------------------
+  // Date fields
+  // -------------------------------------------------------------------------
+
+  #[test]
+  fn classify_type_recognizes_dates() {
+    let date = |src: &str| classify_type(&parse_str::<Type>(src).unwrap());
+    assert!(matches!(date("NaiveDate"), FieldType::Date(_)));
+    assert!(matches!(date("chrono::NaiveDateTime"), FieldType::Date(_)));
+    assert!(matches!(date("jiff::civil::Date"), FieldType::Date(_)));
+    assert!(matches!(date("Option<DateTime>"), FieldType::OptionDate(_)));
+    assert!(matches!(date("chrono::DateTime<Utc>"), FieldType::Other(_)));
+    assert!(matches!(date("my::Date"), FieldType::Other(_)));
+  }
+
+  #[test]
+  fn parse_date_attrs() {
+    let field = parse_named_field(
+      r#"struct S { #[validate(date(format = "%d %B %Y", allow_time), date_range(max = "2030-01-01"))] x: String }"#,
+    );
+    let info = parse_field_info(&field, None).unwrap();
+    assert!(matches!(
+      &info.validations[0],
+      ValidateAttr::Date { format: DateFormatLit::Custom(f), allow_time: true } if f == "%d %B %Y"
+    ));
+    assert!(matches!(
+      &info.validations[1],
+      ValidateAttr::DateRange {
+        min: None,
+        max: Some(_),
+        ..
+      }
+    ));
+
+    // `date_range` on a date-typed field becomes `min`/`max` bounds.
+    let field = parse_named_field(
+      r#"struct S { #[validate(date_range(min = "2020-02-29", max = "2030-01-01"))] x: NaiveDate }"#,
+    );
+    let info = parse_field_info(&field, None).unwrap();
+    assert!(matches!(&info.validations[0], ValidateAttr::MinDate(s) if s == "2020-02-29"));
+    assert!(matches!(&info.validations[1], ValidateAttr::MaxDate(_)));
+  }
+
+  #[test]
+  fn parse_date_attrs_reject_misuse() {
+    let cases = [
+      (
+        r#"struct S { #[validate(min = "2021-02-29")] x: NaiveDate }"#,
+        "invalid date",
+      ),
+      (
+        r#"struct S { #[validate(max = "2021-01-01")] x: NaiveDateTime }"#,
+        "YYYY-MM-DDTHH:MM:SS",
+      ),
+      (
+        r#"struct S { #[validate(min = 5)] x: NaiveDate }"#,
+        "string bound",
+      ),
+      (
+        r#"struct S { #[validate(date)] x: NaiveDate }"#,
+        "already dates",
+      ),
+      (
+        r#"struct S { #[validate(date_range(min = "2020-01-01", format = "us_date"))] x: NaiveDate }"#,
+        "not date-typed fields",
+      ),
+      (
+        r#"struct S { #[validate(date(format = "dd/mm"))] x: String }"#,
+        "unknown date format",
+      ),
+      (
+        r#"struct S { #[validate(date_range(format = "iso8601"))] x: String }"#,
+        "requires `min`, `max`",
+      ),
+    ];
+    for (src, expected) in cases {
+      let err = parse_field_info(&parse_named_field(src), None).expect_err(src);
+      assert!(
+        err.to_string().contains(expected),
+        "expected {expected:?} in error for {src}, got: {err}"
+      );
+    }
+  }
+
+  #[test]
+  fn classify_type_recognizes_collections() {
+    let classify = |src: &str| classify_type(&parse_str::<Type>(src).unwrap());
+    assert!(matches!(classify("Vec<Address>"), FieldType::Vec(_)));
+    assert!(matches!(
+      classify("Option<Vec<String>>"),
+      FieldType::OptionVec(_)
+    ));
+    assert!(matches!(
+      classify("HashMap<String, Address>"),
+      FieldType::Map { sorted: true, .. }
+    ));
+    assert!(matches!(
+      classify("Option<std::collections::BTreeMap<String, u8>>"),
+      FieldType::OptionMap { sorted: false, .. }
+    ));
+    assert!(matches!(
+      classify("HashMap<u32, String>"),
+      FieldType::Other(_)
+    ));
+  }
+
+  #[test]
+  fn parse_collection_attrs() {
+    let field = parse_named_field(
+      r#"struct S { #[validate(required, min_items = 1, max_items = 5, unique, each(email, message = "Bad email."))] x: Vec<String> }"#,
+    );
+    let info = parse_field_info(&field, None).unwrap();
+    assert!(matches!(info.validations[0], ValidateAttr::Required));
+    assert!(matches!(info.validations[1], ValidateAttr::MinItems(1)));
+    assert!(matches!(info.validations[2], ValidateAttr::MaxItems(5)));
+    assert!(matches!(info.validations[3], ValidateAttr::Unique));
+    let each = info.each.expect("each(...) rules");
+    assert!(matches!(each.ty, FieldType::String));
+    assert!(matches!(each.validations[0], ValidateAttr::Email));
+    assert!(matches!(&each.validations[1], ValidateAttr::Message(m) if m == "Bad email."));
+
+    let field = parse_named_field(r#"struct S { #[validate(nested)] x: Option<Vec<Address>> }"#);
+    let info = parse_field_info(&field, None).unwrap();
+    assert!(info.is_nested_validate);
+    assert!(info.each.is_none());
+  }
+
+  #[test]
+  fn parse_collection_attrs_reject_misuse() {
+    let cases = [
+      (
+        r#"struct S { #[validate(min_length = 2)] x: Vec<String> }"#,
+        "use `each(min_length)`",
+      ),
+      (
+        r#"struct S { #[validate(min_items = 1)] x: String }"#,
+        "only applies to `Vec` and map fields",
+      ),
+      (
+        r#"struct S { #[validate(each(email))] x: Option<String> }"#,
+        "only applies to `Vec` and map fields",
+      ),
+      (
+        r#"struct S { #[validate(unique)] x: HashMap<String, u8> }"#,
+        "map keys are already unique",
+      ),
+      (
+        r#"struct S { #[validate(each(required))] x: Vec<Address> }"#,
+        "use `nested`",
+      ),
+      (
+        r#"struct S { #[validate(each(nested))] x: Vec<String> }"#,
+        "not inside `each(...)`",
+      ),
+      (
+        r#"struct S { #[validate(each(max_items = 2))] x: Vec<String> }"#,
+        "only applies to `Vec` and map fields",
+      ),
+    ];
+    for (src, expected) in cases {
+      let err = parse_field_info(&parse_named_field(src), None).expect_err(src);
+      assert!(
+        err.to_string().contains(expected),
+        "expected {expected:?} in error for {src}, got: {err}"
+      );
+    }
+  }
+
   // -------------------------------------------------------------------------
   // cross_validate: structured variants
   // -------------------------------------------------------------------------
@@ -1097,6 +2124,200 @@ mod tests {
     }
   }
 
+  #[test]
+  fn rename_rules_match_serde() {
+    let cases = [
+      (RenameRule::Lower, "order_id"),
+      (RenameRule::Upper, "ORDER_ID"),
+      (RenameRule::Pascal, "OrderId"),
+      (RenameRule::Camel, "orderId"),
+      (RenameRule::Snake, "order_id"),
+      (RenameRule::ScreamingSnake, "ORDER_ID"),
+      (RenameRule::Kebab, "order-id"),
+      (RenameRule::ScreamingKebab, "ORDER-ID"),
+    ];
+    for (rule, expected) in cases {
+      assert_eq!(rule.apply_to_field("order_id"), expected, "{rule:?}");
+    }
+    assert_eq!(RenameRule::Camel.apply_to_field("id"), "id");
+  }
+
+  #[test]
+  fn parse_struct_rename_all() {
+    let item: ItemStruct = parse_str(
+      r#"#[serde(default, rename_all(serialize = "kebab-case", deserialize = "camelCase"))]
+         struct S { a: String }"#,
+    )
+    .unwrap();
+    let attrs = parse_fieldset_struct_attrs(&item.attrs, "rename_all").unwrap();
+    assert_eq!(attrs.rename_all, Some(RenameRule::Camel));
+
+    // `#[fieldset(rename_all)]` wins over serde's.
+    let item: ItemStruct = parse_str(
+      r#"#[serde(rename_all = "camelCase")]
+         #[fieldset(break_on_failure, rename_all = "PascalCase")]
+         struct S { a: String }"#,
+    )
+    .unwrap();
+    let attrs = parse_fieldset_struct_attrs(&item.attrs, "rename_all").unwrap();
+    assert_eq!(attrs.rename_all, Some(RenameRule::Pascal));
+    assert!(attrs.break_on_failure);
+
+    let item: ItemStruct =
+      parse_str(r#"#[fieldset(rename_all = "Title Case")] struct S { a: String }"#).unwrap();
+    let err = parse_fieldset_struct_attrs(&item.attrs, "rename_all").unwrap_err();
+    assert!(err.to_string().contains("unknown rename_all rule"), "{err}");
+  }
+
+  #[test]
+  fn parse_field_key() {
+    let key = |src: &str, rename_all| {
+      parse_field_info(&parse_named_field(src), rename_all).map(|info| info.key)
+    };
+    assert_eq!(
+      key("struct S { zip_code: String }", None).unwrap(),
+      "zip_code"
+    );
+    assert_eq!(key("struct S { r#type: String }", None).unwrap(), "type");
+    assert_eq!(
+      key("struct S { zip_code: String }", Some(RenameRule::Camel)).unwrap(),
+      "zipCode"
+    );
+    assert_eq!(
+      key(
+        r#"struct S { #[serde(rename = "postcode")] zip_code: String }"#,
+        Some(RenameRule::Camel)
+      )
+      .unwrap(),
+      "postcode"
+    );
+    assert_eq!(
+      key(
+        r#"struct S { #[serde(rename = "postcode")] #[validate(required, name = "zip")] zip_code: String }"#,
+        None
+      )
+      .unwrap(),
+      "zip"
+    );
+    let err = key(
+      r#"struct S { #[validate(name = "")] zip_code: String }"#,
+      None,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("must not be empty"), "{err}");
+  }
+
+  #[test]
+  fn parse_variants() {
+    let item: syn::ItemEnum = parse_str(
+      r#"enum E {
+           #[serde(rename_all = "kebab-case")]
+           #[cross_validate(fields_equal(card_number, confirm))]
+           Card { card_number: String, confirm: String },
+           Bank { r#type: String, iban_code: String },
+           Cash,
+         }"#,
+    )
+    .unwrap();
+    let variants: Vec<VariantInfo> = item
+      .variants
+      .iter()
+      .map(|variant| parse_variant_info(variant, Some(RenameRule::Camel), &EnumTagging::Internal))
+      .collect::<syn::Result<_>>()
+      .unwrap();
+
+    let card = variants[0].fields.as_ref().unwrap();
+    assert_eq!(card[0].key, "card-number");
+    assert_eq!(card[0].binding.as_ref().unwrap(), "__field_card_number");
+    assert_eq!(variants[0].cross_rules.len(), 1);
+
+    let bank = variants[1].fields.as_ref().unwrap();
+    assert_eq!(bank[0].key, "type");
+    assert_eq!(bank[0].binding.as_ref().unwrap(), "__field_type");
+    assert_eq!(bank[1].key, "ibanCode");
+    assert_eq!(
+      variants[1].pattern().to_string(),
+      quote! { Self::Bank { r#type: __field_type, iban_code: __field_iban_code } }.to_string()
+    );
+
+    assert!(variants[2].fields.is_none());
+    assert_eq!(variants[2].pattern().to_string(), "Self :: Cash");
+
+    let item: syn::ItemEnum = parse_str("enum E { Id(u32) }").unwrap();
+    let err = parse_variant_info(&item.variants[0], None, &EnumTagging::Internal).unwrap_err();
+    assert!(
+      err.to_string().contains("named fields or no fields"),
+      "{err}"
+    );
+  }
+
+  #[test]
+  fn variant_keys_follow_enum_tagging() {
+    let keys = |src: &str| {
+      let item: syn::ItemEnum = parse_str(src).unwrap();
+      let tagging = EnumTagging::from_attrs(&item.attrs).unwrap();
+      item
+        .variants
+        .iter()
+        .flat_map(|variant| {
+          parse_variant_info(variant, None, &tagging)
+            .unwrap()
+            .fields
+            .unwrap_or_default()
+        })
+        .map(|info| info.key)
+        .collect::<Vec<_>>()
+    };
+
+    // External tagging (the default) nests fields under the variant name.
+    assert_eq!(
+      keys(
+        r#"#[serde(rename_all = "snake_case")]
+           enum E {
+             CreditCard { number: String },
+             #[serde(rename = "bank")] Bank { iban: String },
+             #[serde(untagged)] Other { note: String },
+           }"#
+      ),
+      vec!["credit_card.number", "bank.iban", "note"]
+    );
+    assert_eq!(
+      keys("enum E { Card { number: String } }"),
+      vec!["Card.number"]
+    );
+    // Adjacent tagging nests them under the content key.
+    assert_eq!(
+      keys(r#"#[serde(tag = "t", content = "c")] enum E { Card { number: String } }"#),
+      vec!["c.number"]
+    );
+    // Internal tagging and untagged enums keep them at the top level.
+    assert_eq!(
+      keys(r#"#[serde(tag = "t")] enum E { Card { number: String } }"#),
+      vec!["number"]
+    );
+    assert_eq!(
+      keys("#[serde(untagged)] enum E { Card { number: String } }"),
+      vec!["number"]
+    );
+  }
+
+  #[test]
+  fn rename_variant() {
+    let cases = [
+      (RenameRule::Lower, "creditcard"),
+      (RenameRule::Upper, "CREDITCARD"),
+      (RenameRule::Pascal, "CreditCard"),
+      (RenameRule::Camel, "creditCard"),
+      (RenameRule::Snake, "credit_card"),
+      (RenameRule::ScreamingSnake, "CREDIT_CARD"),
+      (RenameRule::Kebab, "credit-card"),
+      (RenameRule::ScreamingKebab, "CREDIT-CARD"),
+    ];
+    for (rule, expected) in cases {
+      assert_eq!(rule.apply_to_variant("CreditCard"), expected, "{rule:?}");
+    }
+  }
+
   #[test]
   fn parse_cross_validate_unknown_kind_errors() {
     let err = parse_struct_cross_validate(quote! {
@@ -1114,7 +2335,10 @@ mod tests {
       struct S { country: String, addr: Option<String> }
     })
     .expect("trailing comma after literal should be accepted");
-    assert!(matches!(attrs.rules[0], CrossValidateRule::RequiredIf { .. }));
+    assert!(matches!(
+      attrs.rules[0],
+      CrossValidateRule::RequiredIf { .. }
+    ));
   }
 
   #[test]
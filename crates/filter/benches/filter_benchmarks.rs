@@ -7,7 +7,9 @@
 use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
 use std::borrow::Cow;
 use std::sync::Arc;
-use walrs_filter::{Filter, FilterOp, SlugFilter, StripTagsFilter, TryFilterOp, XmlEntitiesFilter};
+use walrs_filter::{
+  CompiledFilter, Filter, FilterOp, SlugFilter, StripTagsFilter, TryFilterOp, XmlEntitiesFilter,
+};
 
 const NORMALIZE_WHITESPACE_DIRTY_INPUT: &str = r#"  Lorem   ipsum	dolor
 
@@ -192,7 +194,11 @@ fn bench_filter_op_noop(c: &mut Criterion) {
   });
 
   // Slug
-  let slug = FilterOp::<String>::Slug { max_length: None };
+  let slug = FilterOp::<String>::Slug {
+    max_length: None,
+    separator: '-',
+    stop_words: Vec::new(),
+  };
   group.bench_function("slug_noop", |b| {
     b.iter(|| slug.apply_ref(black_box("already-a-slug")))
   });
@@ -217,7 +223,11 @@ fn bench_filter_op_chain(c: &mut Criterion) {
     FilterOp::Lowercase,
     FilterOp::StripTags,
     FilterOp::HtmlEntities,
-    FilterOp::Slug { max_length: None },
+    FilterOp::Slug {
+      max_length: None,
+      separator: '-',
+      stop_words: Vec::new(),
+    },
   ]);
 
   let input = "  <b>Hello World & Friends</b>  ";
@@ -235,6 +245,60 @@ fn bench_filter_op_chain(c: &mut Criterion) {
   group.finish();
 }
 
+fn bench_compiled_filter(c: &mut Criterion) {
+  let mut group = c.benchmark_group("CompiledFilter");
+
+  // Fusable char-level pipeline: one pass when compiled vs one pass per op.
+  let normalize: FilterOp<String> = FilterOp::Chain(vec![
+    FilterOp::Trim,
+    FilterOp::Lowercase,
+    FilterOp::Alnum {
+      allow_whitespace: true,
+    },
+    FilterOp::NormalizeWhitespace,
+  ]);
+  let normalize_compiled = CompiledFilter::new(&normalize);
+
+  // Mixed pipeline: fused runs around struct-backed stages.
+  let mixed: FilterOp<String> = FilterOp::Chain(vec![
+    FilterOp::Trim,
+    FilterOp::Lowercase,
+    FilterOp::StripTags,
+    FilterOp::HtmlEntities,
+    FilterOp::NormalizeWhitespace,
+  ]);
+  let mixed_compiled = mixed.compile();
+
+  let inputs = [
+    ("noop", "already clean lowercase text"),
+    ("mutation", "  Hello,   WORLD! Mixed   Case  "),
+    ("dirty_long", NORMALIZE_WHITESPACE_DIRTY_INPUT),
+  ];
+
+  for (name, input) in inputs {
+    group.bench_with_input(
+      BenchmarkId::new("chain_normalize", name),
+      &input,
+      |b, input| b.iter(|| normalize.apply_ref(black_box(input))),
+    );
+    group.bench_with_input(
+      BenchmarkId::new("compiled_normalize", name),
+      &input,
+      |b, input| b.iter(|| normalize_compiled.apply_ref(black_box(input))),
+    );
+    group.bench_with_input(BenchmarkId::new("chain_mixed", name), &input, |b, input| {
+      b.iter(|| mixed.apply_ref(black_box(input)))
+    });
+    group.bench_with_input(
+      BenchmarkId::new("compiled_mixed", name),
+      &input,
+      |b, input| b.iter(|| mixed_compiled.apply_ref(black_box(input))),
+    );
+  }
+
+  group.finish();
+}
+
 fn bench_filter_op_sanitize(c: &mut Criterion) {
   let mut group = c.benchmark_group("FilterOp_Sanitize");
 
@@ -457,6 +521,7 @@ criterion_group!(
   bench_filter_comparison,
   bench_filter_op_noop,
   bench_filter_op_chain,
+  bench_compiled_filter,
   bench_filter_op_sanitize,
   bench_try_filter_op_conversions,
   bench_filter_op_clamp,
@@ -9,7 +9,7 @@
 
 use std::borrow::Cow;
 use std::sync::Arc;
-use walrs_filter::FilterOp;
+use walrs_filter::{FilterOp, TruncateUnit};
 
 fn main() {
   println!("=== FilterOp Usage Examples ===\n");
@@ -23,8 +23,23 @@ fn main() {
     ("Uppercase", FilterOp::Uppercase),
     ("StripTags", FilterOp::StripTags),
     ("HtmlEntities", FilterOp::HtmlEntities),
-    ("Slug", FilterOp::Slug { max_length: None }),
-    ("Truncate(10)", FilterOp::Truncate { max_length: 10 }),
+    (
+      "Slug",
+      FilterOp::Slug {
+        max_length: None,
+        separator: '-',
+        stop_words: Vec::new(),
+      },
+    ),
+    (
+      "Truncate(10)",
+      FilterOp::Truncate {
+        max_length: 10,
+        unit: TruncateUnit::Chars,
+        suffix: String::new(),
+        break_at_word: false,
+      },
+    ),
     (
       "Replace(hello→hi)",
       FilterOp::Replace {
@@ -134,7 +149,12 @@ fn main() {
       from: " ".to_string(),
       to: "-".to_string(),
     },
-    FilterOp::Truncate { max_length: 20 },
+    FilterOp::Truncate {
+      max_length: 20,
+      unit: TruncateUnit::Chars,
+      suffix: String::new(),
+      break_at_word: false,
+    },
   ]);
 
   let inputs = ["  Hello World!  ", "  RUST PROGRAMMING IS GREAT  "];
@@ -191,6 +211,8 @@ fn main() {
     FilterOp::Lowercase,
     FilterOp::Slug {
       max_length: Some(50),
+      separator: '-',
+      stop_words: Vec::new(),
     },
   ]);
 
@@ -210,7 +232,12 @@ fn main() {
       from: "foo".to_string(),
       to: "bar".to_string(),
     },
-    FilterOp::Truncate { max_length: 30 },
+    FilterOp::Truncate {
+      max_length: 30,
+      unit: TruncateUnit::Chars,
+      suffix: String::new(),
+      break_at_word: false,
+    },
   ]);
   let json = serde_json::to_string(&text_pipeline).unwrap();
   println!("  Truncate+Replace chain JSON: {}", json);
@@ -2,7 +2,7 @@
 
 use arbitrary::Arbitrary;
 use libfuzzer_sys::fuzz_target;
-use walrs_filter::FilterOp;
+use walrs_filter::{FilterOp, TruncateUnit};
 
 #[derive(Debug, Arbitrary)]
 struct FuzzInput {
@@ -24,6 +24,9 @@ fuzz_target!(|input: FuzzInput| {
         },
         5 => FilterOp::Truncate {
             max_length: input.max_length as usize,
+            unit: TruncateUnit::Graphemes,
+            suffix: "…".to_string(),
+            break_at_word: true,
         },
         6 => FilterOp::Replace {
             from: input.from.clone(),
@@ -0,0 +1,379 @@
+//! Case-style conversion (`camelCase`, `snake_case`, `kebab-case`, Title Case, …).
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use crate::Filter;
+use crate::words::split_words;
+
+/// Target style for [`CaseFilter`] / [`FilterOp::Case`](crate::FilterOp::Case).
+///
+/// Input is segmented into words on non-alphanumeric characters, lowercase → uppercase
+/// humps, and acronym ends (`XMLHttpRequest` → `XML`, `Http`, `Request`), so any style
+/// can be converted into any other.
+///
+/// Acronyms are treated consistently: identifier styles (`Camel`, `Pascal`, `Snake`, …)
+/// normalize them like any other word (`HTTP server` → `httpServer`), while prose styles
+/// (`Title`, `Sentence`) keep all-uppercase words of two or more letters as-is
+/// (`nasa launches` → `Nasa Launches`, `NASA launches` → `NASA Launches`).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CaseStyle {
+  /// `camelCase`.
+  Camel,
+
+  /// `PascalCase`.
+  Pascal,
+
+  /// `snake_case`.
+  Snake,
+
+  /// `SCREAMING_SNAKE_CASE`.
+  ScreamingSnake,
+
+  /// `kebab-case`.
+  Kebab,
+
+  /// `Title Case`, words joined by a single space.
+  ///
+  /// When `locale` names a supported language (`en`, `es`, `fr`, `de`, `it`, `pt`, `nl`;
+  /// region subtags such as `en-US` are ignored), that language's small words
+  /// (articles, conjunctions, short prepositions) stay lowercase unless they are
+  /// the first or last word. Without a (supported) locale every word is capitalized.
+  Title {
+    /// BCP 47 language tag selecting the small-word list.
+    #[serde(default)]
+    locale: Option<String>,
+  },
+
+  /// `Sentence case`, words joined by a single space.
+  Sentence,
+}
+
+const SMALL_WORDS_EN: &[&str] = &[
+  "a", "an", "and", "as", "at", "but", "by", "for", "from", "in", "into", "nor", "of", "on", "or",
+  "over", "per", "the", "to", "up", "via", "vs", "with",
+];
+const SMALL_WORDS_ES: &[&str] = &[
+  "a", "al", "con", "de", "del", "e", "el", "en", "la", "las", "lo", "los", "ni", "o", "para",
+  "por", "que", "u", "un", "una", "unas", "unos", "y",
+];
+const SMALL_WORDS_FR: &[&str] = &[
+  "à", "au", "aux", "d", "de", "des", "du", "en", "et", "l", "la", "le", "les", "ou", "par",
+  "pour", "sur", "un", "une",
+];
+const SMALL_WORDS_DE: &[&str] = &[
+  "am", "an", "auf", "das", "dem", "den", "der", "des", "die", "ein", "eine", "für", "im", "in",
+  "mit", "oder", "und", "von", "zu", "zum", "zur",
+];
+const SMALL_WORDS_IT: &[&str] = &[
+  "a", "al", "con", "da", "dal", "del", "della", "di", "e", "ed", "fra", "gli", "i", "il", "in",
+  "la", "le", "lo", "o", "per", "su", "tra", "un", "una",
+];
+const SMALL_WORDS_PT: &[&str] = &[
+  "a", "à", "ao", "as", "com", "da", "das", "de", "do", "dos", "e", "em", "na", "nas", "no", "nos",
+  "o", "os", "ou", "para", "por", "um", "uma",
+];
+const SMALL_WORDS_NL: &[&str] = &[
+  "aan", "bij", "de", "een", "en", "het", "in", "met", "of", "op", "te", "van", "voor",
+];
+
+/// Returns the small-word list for a BCP 47 language tag's primary subtag.
+fn small_words_for(locale: &str) -> &'static [&'static str] {
+  let lang = locale.split(['-', '_']).next().unwrap_or_default();
+  match lang.to_ascii_lowercase().as_str() {
+    "en" => SMALL_WORDS_EN,
+    "es" => SMALL_WORDS_ES,
+    "fr" => SMALL_WORDS_FR,
+    "de" => SMALL_WORDS_DE,
+    "it" => SMALL_WORDS_IT,
+    "pt" => SMALL_WORDS_PT,
+    "nl" => SMALL_WORDS_NL,
+    _ => &[],
+  }
+}
+
+/// Returns `true` for all-uppercase words with at least two letters (`NASA`, `HTTP2`).
+fn is_acronym(word: &str) -> bool {
+  let mut letters = 0;
+  for c in word.chars().filter(|c| c.is_alphabetic()) {
+    if !c.is_uppercase() {
+      return false;
+    }
+    letters += 1;
+  }
+  letters >= 2
+}
+
+fn push_lower(out: &mut String, word: &str) {
+  out.extend(word.chars().flat_map(char::to_lowercase));
+}
+
+fn push_upper(out: &mut String, word: &str) {
+  out.extend(word.chars().flat_map(char::to_uppercase));
+}
+
+fn push_capitalized(out: &mut String, word: &str) {
+  let mut chars = word.chars();
+  if let Some(first) = chars.next() {
+    out.extend(first.to_uppercase());
+    out.extend(chars.flat_map(char::to_lowercase));
+  }
+}
+
+fn join_words(
+  words: &[&str],
+  separator: &str,
+  mut push: impl FnMut(&mut String, usize, &str),
+) -> String {
+  let mut out = String::with_capacity(words.iter().map(|w| w.len() + separator.len()).sum());
+  for (i, word) in words.iter().enumerate() {
+    if i > 0 {
+      out.push_str(separator);
+    }
+    push(&mut out, i, word);
+  }
+  out
+}
+
+/// Converts `value` to the given [`CaseStyle`].
+///
+/// Returns `Cow::Borrowed` when the input is already in the target style.
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use walrs_filter::{to_case, CaseStyle};
+///
+/// assert_eq!(to_case(Cow::Borrowed("XMLHttpRequest"), &CaseStyle::Snake), "xml_http_request");
+/// assert_eq!(to_case(Cow::Borrowed("user-id"), &CaseStyle::Camel), "userId");
+/// assert_eq!(
+///   to_case(
+///     Cow::Borrowed("the lord of the rings"),
+///     &CaseStyle::Title { locale: Some("en".to_string()) },
+///   ),
+///   "The Lord of the Rings"
+/// );
+/// ```
+pub fn to_case<'a>(value: Cow<'a, str>, style: &CaseStyle) -> Cow<'a, str> {
+  let words = split_words(&value);
+  let converted = match style {
+    CaseStyle::Camel => join_words(&words, "", |out, i, w| {
+      if i == 0 {
+        push_lower(out, w)
+      } else {
+        push_capitalized(out, w)
+      }
+    }),
+    CaseStyle::Pascal => join_words(&words, "", |out, _, w| push_capitalized(out, w)),
+    CaseStyle::Snake => join_words(&words, "_", |out, _, w| push_lower(out, w)),
+    CaseStyle::ScreamingSnake => join_words(&words, "_", |out, _, w| push_upper(out, w)),
+    CaseStyle::Kebab => join_words(&words, "-", |out, _, w| push_lower(out, w)),
+    CaseStyle::Title { locale } => {
+      let small_words = locale.as_deref().map(small_words_for).unwrap_or_default();
+      let last = words.len().saturating_sub(1);
+      join_words(&words, " ", |out, i, w| {
+        if is_acronym(w) {
+          out.push_str(w);
+        } else if i != 0 && i != last && small_words.contains(&w.to_lowercase().as_str()) {
+          push_lower(out, w);
+        } else {
+          push_capitalized(out, w);
+        }
+      })
+    }
+    CaseStyle::Sentence => join_words(&words, " ", |out, i, w| {
+      if is_acronym(w) {
+        out.push_str(w);
+      } else if i == 0 {
+        push_capitalized(out, w);
+      } else {
+        push_lower(out, w);
+      }
+    }),
+  };
+
+  if converted == value {
+    value
+  } else {
+    Cow::Owned(converted)
+  }
+}
+
+/// Converts strings to a configured [`CaseStyle`].
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use walrs_filter::{CaseFilter, CaseStyle, Filter};
+///
+/// let filter = CaseFilter::new(CaseStyle::Kebab);
+/// assert_eq!(filter.filter(Cow::Borrowed("Primary Button")), "primary-button");
+/// ```
+#[must_use]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CaseFilter {
+  pub style: CaseStyle,
+}
+
+impl CaseFilter {
+  pub fn new(style: CaseStyle) -> Self {
+    Self { style }
+  }
+}
+
+impl<'a> Filter<Cow<'a, str>> for CaseFilter {
+  type Output = Cow<'a, str>;
+
+  fn filter(&self, value: Cow<'a, str>) -> Self::Output {
+    to_case(value, &self.style)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn convert(value: &str, style: CaseStyle) -> String {
+    to_case(Cow::Borrowed(value), &style).into_owned()
+  }
+
+  fn title(locale: Option<&str>) -> CaseStyle {
+    CaseStyle::Title {
+      locale: locale.map(str::to_string),
+    }
+  }
+
+  #[test]
+  fn test_identifier_styles() {
+    let input = "Hello world-fooBar";
+    assert_eq!(convert(input, CaseStyle::Camel), "helloWorldFooBar");
+    assert_eq!(convert(input, CaseStyle::Pascal), "HelloWorldFooBar");
+    assert_eq!(convert(input, CaseStyle::Snake), "hello_world_foo_bar");
+    assert_eq!(
+      convert(input, CaseStyle::ScreamingSnake),
+      "HELLO_WORLD_FOO_BAR"
+    );
+    assert_eq!(convert(input, CaseStyle::Kebab), "hello-world-foo-bar");
+  }
+
+  #[test]
+  fn test_acronyms_in_identifier_styles() {
+    assert_eq!(convert("HTTPServer", CaseStyle::Snake), "http_server");
+    assert_eq!(convert("HTTP server", CaseStyle::Camel), "httpServer");
+    assert_eq!(convert("userID", CaseStyle::Pascal), "UserId");
+    assert_eq!(
+      convert("XMLHttpRequest", CaseStyle::Kebab),
+      "xml-http-request"
+    );
+  }
+
+  #[test]
+  fn test_round_trip_between_styles() {
+    let snake = convert("parseHTMLDocument", CaseStyle::Snake);
+    assert_eq!(snake, "parse_html_document");
+    assert_eq!(convert(&snake, CaseStyle::Camel), "parseHtmlDocument");
+    assert_eq!(convert(&snake, CaseStyle::Kebab), "parse-html-document");
+  }
+
+  #[test]
+  fn test_title_case_without_locale_capitalizes_every_word() {
+    assert_eq!(
+      convert("the lord of the rings", title(None)),
+      "The Lord Of The Rings"
+    );
+  }
+
+  #[test]
+  fn test_title_case_small_words_en() {
+    assert_eq!(
+      convert("the lord of the rings", title(Some("en-US"))),
+      "The Lord of the Rings"
+    );
+    // First and last words are always capitalized.
+    assert_eq!(
+      convert("of mice and men", title(Some("en"))),
+      "Of Mice and Men"
+    );
+    assert_eq!(
+      convert("what are you looking at", title(Some("en"))),
+      "What Are You Looking At"
+    );
+  }
+
+  #[test]
+  fn test_title_case_small_words_other_locales() {
+    assert_eq!(
+      convert("el señor de los anillos", title(Some("es"))),
+      "El Señor de los Anillos"
+    );
+    assert_eq!(
+      convert("le seigneur des anneaux", title(Some("FR"))),
+      "Le Seigneur des Anneaux"
+    );
+    // Unknown locales fall back to capitalizing every word.
+    assert_eq!(convert("a tale of two", title(Some("xx"))), "A Tale Of Two");
+  }
+
+  #[test]
+  fn test_title_and_sentence_keep_acronyms() {
+    assert_eq!(
+      convert("NASA launches a new API", title(Some("en"))),
+      "NASA Launches a New API"
+    );
+    assert_eq!(
+      convert("the NASA API is HERE", CaseStyle::Sentence),
+      "The NASA API is HERE"
+    );
+    assert_eq!(convert("user_name", CaseStyle::Sentence), "User name");
+  }
+
+  #[test]
+  fn test_unicode_words() {
+    assert_eq!(convert("Été à Paris", CaseStyle::Snake), "été_à_paris");
+    assert_eq!(convert("straße_café", CaseStyle::Pascal), "StraßeCafé");
+    assert_eq!(convert("émile zola", title(None)), "Émile Zola");
+  }
+
+  #[test]
+  fn test_apostrophes_stay_inside_words() {
+    assert_eq!(convert("don't stop", title(None)), "Don't Stop");
+    assert_eq!(convert("don't stop", CaseStyle::Snake), "don't_stop");
+  }
+
+  #[test]
+  fn test_already_converted_is_borrowed() {
+    for (input, style) in [
+      ("hello_world", CaseStyle::Snake),
+      ("helloWorld", CaseStyle::Camel),
+      ("hello-world", CaseStyle::Kebab),
+      ("Hello World", title(None)),
+      ("", CaseStyle::Pascal),
+    ] {
+      let result = to_case(Cow::Borrowed(input), &style);
+      assert!(
+        matches!(result, Cow::Borrowed(_)),
+        "expected Cow::Borrowed for {input:?} / {style:?}"
+      );
+    }
+  }
+
+  #[test]
+  fn test_no_words_yields_empty_string() {
+    assert_eq!(convert("  --__ ", CaseStyle::Snake), "");
+  }
+
+  #[test]
+  fn test_case_filter() {
+    let filter = CaseFilter::new(CaseStyle::ScreamingSnake);
+    assert_eq!(filter.filter(Cow::Borrowed("max-retries")), "MAX_RETRIES");
+  }
+
+  #[test]
+  fn test_case_style_serde_roundtrip() {
+    for style in [CaseStyle::Camel, title(Some("en")), title(None)] {
+      let json = serde_json::to_string(&style).unwrap();
+      let deserialized: CaseStyle = serde_json::from_str(&json).unwrap();
+      assert_eq!(style, deserialized);
+    }
+    let style: CaseStyle = serde_json::from_str(r#"{"Title":{}}"#).unwrap();
+    assert_eq!(style, title(None));
+  }
+}
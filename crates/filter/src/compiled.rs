@@ -0,0 +1,782 @@
+//! Precompiled, single-pass filter pipelines.
+//!
+//! [`CompiledFilter`] is built once from a [`FilterOp<String>`] and applied many
+//! times. Compilation:
+//!
+//! - flattens nested `Chain`s (and `Each`, which is its inner filter on a single value);
+//! - drops variants that are no-ops on a single string (numeric and collection filters);
+//! - fuses runs of adjacent char-level ops (`Trim`, `NormalizeWhitespace`,
+//!   `Lowercase`, `Uppercase`, `Digits`, `Alnum`, `Alpha`, `StripNewlines`,
+//!   `AllowChars`, `DenyChars`) into a single pass over the input;
+//! - builds the `StripTags`, `HtmlEntities`, and `Slug` filter structs up front.
+//!
+//! Results match applying the original `FilterOp` with [`FilterOp::apply_ref`].
+//! Each fused pass first scans for input it would leave unchanged and returns it
+//! borrowed, so clean input is never copied; otherwise the pass writes straight
+//! into a single output buffer.
+
+use std::borrow::Cow;
+use std::fmt::{self, Debug};
+
+use crate::{Filter, FilterOp, SlugFilter, StripTagsFilter, XmlEntitiesFilter};
+
+/// Maximum number of char-level ops fused into one pass; longer runs are split
+/// into consecutive passes. Keeps per-call op state on the stack.
+const MAX_FUSED: usize = 8;
+
+/// A [`FilterOp<String>`] compiled for repeated, high-volume application.
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_filter::{CompiledFilter, FilterOp};
+/// use std::borrow::Cow;
+///
+/// let filter = CompiledFilter::new(&FilterOp::Chain(vec![
+///     FilterOp::Trim,
+///     FilterOp::Lowercase,
+///     FilterOp::Alnum { allow_whitespace: true },
+///     FilterOp::NormalizeWhitespace,
+/// ]));
+///
+/// assert_eq!(filter.apply_ref("  Hello,   WORLD!  "), "hello world");
+///
+/// // Clean input is returned without allocating.
+/// assert!(matches!(filter.apply_ref("hello world"), Cow::Borrowed(_)));
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct CompiledFilter {
+  stages: Vec<Stage>,
+}
+
+#[derive(Debug)]
+enum Stage {
+  Chars(CharPass),
+  StripTags(Box<StripTagsFilter<'static>>),
+  HtmlEntities(XmlEntitiesFilter<'static>),
+  Slug(SlugFilter),
+  Op(FilterOp<String>),
+}
+
+impl CompiledFilter {
+  /// Compiles `op` into a single-pass pipeline.
+  pub fn new(op: &FilterOp<String>) -> Self {
+    let mut stages = Vec::new();
+    let mut run: Vec<(CharOp, &FilterOp<String>)> = Vec::new();
+
+    let mut stack = vec![op];
+    while let Some(op) = stack.pop() {
+      match op {
+        FilterOp::Chain(inner) => {
+          stack.extend(inner.iter().rev());
+          continue;
+        }
+        FilterOp::Each(inner) => {
+          stack.push(inner);
+          continue;
+        }
+        _ => {}
+      }
+      if let Some(char_op) = CharOp::from_filter_op(op) {
+        if run.len() == MAX_FUSED {
+          stages.push(Stage::Chars(CharPass::new(std::mem::take(&mut run))));
+        }
+        run.push((char_op, op));
+        continue;
+      }
+      let stage = match op {
+        // No-ops on a single string value.
+        FilterOp::Clamp { .. }
+        | FilterOp::Round { .. }
+        | FilterOp::Floor
+        | FilterOp::Ceil
+        | FilterOp::Abs
+        | FilterOp::SnapToStep { .. }
+        | FilterOp::NanTo(_)
+        | FilterOp::Split { .. }
+        | FilterOp::Join { .. }
+        | FilterOp::Dedup
+        | FilterOp::Sort
+        | FilterOp::RemoveEmpty
+        | FilterOp::Take(_) => continue,
+        FilterOp::StripTags => Stage::StripTags(Box::new(StripTagsFilter::new())),
+        FilterOp::HtmlEntities => Stage::HtmlEntities(XmlEntitiesFilter::new()),
+        FilterOp::Slug {
+          max_length,
+          separator,
+          stop_words,
+        } => Stage::Slug(SlugFilter {
+          separator: *separator,
+          stop_words: stop_words.clone(),
+          ..SlugFilter::new(max_length.unwrap_or(200), false)
+        }),
+        other => Stage::Op(other.clone()),
+      };
+      if !run.is_empty() {
+        stages.push(Stage::Chars(CharPass::new(std::mem::take(&mut run))));
+      }
+      stages.push(stage);
+    }
+    if !run.is_empty() {
+      stages.push(Stage::Chars(CharPass::new(run)));
+    }
+    Self { stages }
+  }
+
+  /// Applies the compiled pipeline to a `&str`.
+  ///
+  /// Returns `Cow::Borrowed` (possibly a sub-slice of `value`, e.g. after
+  /// `Truncate`) when no stage needed to rewrite the input.
+  pub fn apply_ref<'a>(&self, value: &'a str) -> Cow<'a, str> {
+    let mut result = Cow::Borrowed(value);
+    for stage in &self.stages {
+      result = match result {
+        Cow::Borrowed(s) => stage.apply_ref(s),
+        Cow::Owned(s) => Cow::Owned(stage.apply_owned(s)),
+      };
+    }
+    result
+  }
+
+  /// Applies the compiled pipeline to an owned `String`.
+  pub fn apply(&self, value: String) -> String {
+    self
+      .stages
+      .iter()
+      .fold(value, |v, stage| stage.apply_owned(v))
+  }
+}
+
+impl From<&FilterOp<String>> for CompiledFilter {
+  fn from(op: &FilterOp<String>) -> Self {
+    Self::new(op)
+  }
+}
+
+impl FilterOp<String> {
+  /// Compiles this filter into a [`CompiledFilter`] for repeated application.
+  pub fn compile(&self) -> CompiledFilter {
+    CompiledFilter::new(self)
+  }
+}
+
+impl Filter<String> for CompiledFilter {
+  type Output = String;
+
+  fn filter(&self, value: String) -> String {
+    self.apply(value)
+  }
+}
+
+impl<'a> Filter<Cow<'a, str>> for CompiledFilter {
+  type Output = Cow<'a, str>;
+
+  fn filter(&self, value: Cow<'a, str>) -> Cow<'a, str> {
+    match value {
+      Cow::Borrowed(s) => self.apply_ref(s),
+      Cow::Owned(s) => Cow::Owned(self.apply(s)),
+    }
+  }
+}
+
+impl Stage {
+  fn apply_ref<'a>(&self, value: &'a str) -> Cow<'a, str> {
+    match self {
+      Stage::Chars(pass) => pass.apply_ref(value),
+      Stage::StripTags(filter) => filter.filter(Cow::Borrowed(value)),
+      Stage::HtmlEntities(filter) => filter.filter(Cow::Borrowed(value)),
+      Stage::Slug(filter) => filter.filter(Cow::Borrowed(value)),
+      Stage::Op(op) => op.apply_ref(value),
+    }
+  }
+
+  /// Applies the stage to an owned value, reusing its buffer when the stage
+  /// returns (a sub-slice of) its input unchanged.
+  fn apply_owned(&self, mut value: String) -> String {
+    let range = match self.apply_ref(&value) {
+      Cow::Owned(s) => return s,
+      Cow::Borrowed(b) => {
+        let outer = value.as_bytes().as_ptr_range();
+        let inner = b.as_bytes().as_ptr_range();
+        if inner.start < outer.start || inner.end > outer.end {
+          return b.to_string();
+        }
+        let start = inner.start as usize - outer.start as usize;
+        start..start + b.len()
+      }
+    };
+    value.truncate(range.end);
+    value.drain(..range.start);
+    value
+  }
+}
+
+// ============================================================================
+// Fused char-level pass
+// ============================================================================
+
+/// A char-level operation that can take part in a fused pass.
+#[derive(Debug)]
+enum CharOp {
+  Trim,
+  NormalizeWhitespace,
+  Lowercase,
+  Uppercase,
+  Keep(CharClass),
+}
+
+/// Characters retained by a filtering [`CharOp`].
+#[derive(Debug)]
+enum CharClass {
+  Digits,
+  Alnum { allow_whitespace: bool },
+  Alpha { allow_whitespace: bool },
+  NotNewline,
+  In(CharSet),
+  NotIn(CharSet),
+}
+
+/// A precomputed character set: an ASCII bitmap plus a sorted list for the rest.
+#[derive(Debug)]
+struct CharSet {
+  ascii: u128,
+  other: Vec<char>,
+}
+
+impl CharSet {
+  fn new(set: &str) -> Self {
+    let mut ascii = 0u128;
+    let mut other = Vec::new();
+    for c in set.chars() {
+      if c.is_ascii() {
+        ascii |= 1 << (c as u32);
+      } else {
+        other.push(c);
+      }
+    }
+    other.sort_unstable();
+    other.dedup();
+    Self { ascii, other }
+  }
+
+  fn contains(&self, c: char) -> bool {
+    if c.is_ascii() {
+      self.ascii & (1 << (c as u32)) != 0
+    } else {
+      self.other.binary_search(&c).is_ok()
+    }
+  }
+}
+
+impl CharClass {
+  fn contains(&self, c: char) -> bool {
+    match self {
+      CharClass::Digits => c.is_ascii_digit(),
+      CharClass::Alnum { allow_whitespace } => {
+        c.is_alphanumeric() || (*allow_whitespace && c.is_whitespace())
+      }
+      CharClass::Alpha { allow_whitespace } => {
+        c.is_alphabetic() || (*allow_whitespace && c.is_whitespace())
+      }
+      CharClass::NotNewline => c != '\n' && c != '\r',
+      CharClass::In(set) => set.contains(c),
+      CharClass::NotIn(set) => !set.contains(c),
+    }
+  }
+}
+
+impl CharOp {
+  fn from_filter_op(op: &FilterOp<String>) -> Option<Self> {
+    Some(match op {
+      FilterOp::Trim => CharOp::Trim,
+      FilterOp::NormalizeWhitespace => CharOp::NormalizeWhitespace,
+      FilterOp::Lowercase => CharOp::Lowercase,
+      FilterOp::Uppercase => CharOp::Uppercase,
+      FilterOp::Digits => CharOp::Keep(CharClass::Digits),
+      FilterOp::Alnum { allow_whitespace } => CharOp::Keep(CharClass::Alnum {
+        allow_whitespace: *allow_whitespace,
+      }),
+      FilterOp::Alpha { allow_whitespace } => CharOp::Keep(CharClass::Alpha {
+        allow_whitespace: *allow_whitespace,
+      }),
+      FilterOp::StripNewlines => CharOp::Keep(CharClass::NotNewline),
+      FilterOp::AllowChars { set } => CharOp::Keep(CharClass::In(CharSet::new(set))),
+      FilterOp::DenyChars { set } => CharOp::Keep(CharClass::NotIn(CharSet::new(set))),
+      _ => return None,
+    })
+  }
+}
+
+/// Per-call state of a whitespace-aware op (`Trim`, `NormalizeWhitespace`).
+#[derive(Clone, Copy, Default)]
+struct OpState {
+  /// A non-whitespace character has passed through the op.
+  started: bool,
+  /// `NormalizeWhitespace`: a whitespace run is waiting to be emitted as one space.
+  pending_space: bool,
+  /// `Trim`: output length before the current (possibly trailing) whitespace run.
+  mark: Option<usize>,
+}
+
+/// Marks a dropped byte in an ASCII lookup table.
+const DROP: u8 = 0x80;
+
+/// A step of the ASCII fast path: adjacent stateless ops collapse into one
+/// lookup table; whitespace-aware ops keep their index into `CharPass::ops`.
+#[derive(Debug)]
+enum Segment {
+  Table(Box<[u8; 128]>),
+  Ws(usize),
+}
+
+/// A run of up to [`MAX_FUSED`] char-level ops applied in one pass.
+///
+/// ASCII bytes go through `segments`; other chars are fed through `ops` one
+/// op at a time. Both paths share the per-call [`OpState`]s.
+struct CharPass {
+  ops: Vec<CharOp>,
+  segments: Vec<Segment>,
+  /// Bytes that every stateless op in the pass maps back to themselves.
+  stable: [bool; 128],
+  has_trim: bool,
+  has_normalize: bool,
+  /// The original ops, used when the input needs context-sensitive handling.
+  fallback: FilterOp<String>,
+  lowercases: bool,
+  /// An `Uppercase` precedes a `Lowercase`, so 'σ'/'ς' in the input become 'Σ'
+  /// mid-pass.
+  uppercases_then_lowercases: bool,
+}
+
+impl Debug for CharPass {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_tuple("CharPass").field(&self.ops).finish()
+  }
+}
+
+impl CharPass {
+  fn new(run: Vec<(CharOp, &FilterOp<String>)>) -> Self {
+    let fallback = FilterOp::Chain(run.iter().map(|(_, op)| (*op).clone()).collect());
+    let ops: Vec<CharOp> = run.into_iter().map(|(op, _)| op).collect();
+
+    let identity: [u8; 128] = std::array::from_fn(|b| b as u8);
+    let mut composite = identity;
+    let mut segments = Vec::new();
+    let mut table: Option<[u8; 128]> = None;
+    for (i, op) in ops.iter().enumerate() {
+      if matches!(op, CharOp::Trim | CharOp::NormalizeWhitespace) {
+        segments.extend(table.take().map(|t| Segment::Table(Box::new(t))));
+        segments.push(Segment::Ws(i));
+        continue;
+      }
+      let map = |b: u8| {
+        if b == DROP {
+          return DROP;
+        }
+        let c = b as char;
+        match op {
+          CharOp::Lowercase => c.to_ascii_lowercase() as u8,
+          CharOp::Uppercase => c.to_ascii_uppercase() as u8,
+          CharOp::Keep(class) if class.contains(c) => b,
+          _ => DROP,
+        }
+      };
+      let t = table.get_or_insert(identity);
+      t.iter_mut().for_each(|b| *b = map(*b));
+      composite.iter_mut().for_each(|b| *b = map(*b));
+    }
+    segments.extend(table.map(|t| Segment::Table(Box::new(t))));
+
+    Self {
+      stable: std::array::from_fn(|b| composite[b] as usize == b),
+      has_trim: ops.iter().any(|op| matches!(op, CharOp::Trim)),
+      has_normalize: ops
+        .iter()
+        .any(|op| matches!(op, CharOp::NormalizeWhitespace)),
+      lowercases: ops.iter().any(|op| matches!(op, CharOp::Lowercase)),
+      uppercases_then_lowercases: ops
+        .iter()
+        .position(|op| matches!(op, CharOp::Uppercase))
+        .is_some_and(|i| ops[i..].iter().any(|op| matches!(op, CharOp::Lowercase))),
+      segments,
+      ops,
+      fallback,
+    }
+  }
+
+  fn apply_ref<'a>(&self, value: &'a str) -> Cow<'a, str> {
+    if self.is_fixed_point(value) {
+      return Cow::Borrowed(value);
+    }
+    // `str::to_lowercase` maps a word-final 'Σ' to 'ς', which needs lookahead.
+    if self.needs_sigma_context(value) {
+      return self.fallback.apply_ref(value);
+    }
+
+    let mut states = [OpState::default(); MAX_FUSED];
+    let mut out = String::with_capacity(value.len());
+    let bytes = value.as_bytes();
+    let mut pos = 0;
+    while pos < bytes.len() {
+      if bytes[pos].is_ascii() {
+        self.feed_ascii(bytes[pos], &mut states, &mut out);
+        pos += 1;
+      } else {
+        let c = value[pos..].chars().next().unwrap_or_default();
+        self.feed(0, c, &mut states, &mut out);
+        pos += c.len_utf8();
+      }
+    }
+    for (op, state) in self.ops.iter().zip(&states) {
+      if let (CharOp::Trim, Some(mark)) = (op, state.mark) {
+        out.truncate(mark);
+      }
+    }
+    if out == value {
+      Cow::Borrowed(value)
+    } else {
+      Cow::Owned(out)
+    }
+  }
+
+  /// Returns `true` when a `Lowercase` in the pass may see a 'Σ', either from the
+  /// input or produced from 'σ'/'ς' by an earlier `Uppercase`.
+  fn needs_sigma_context(&self, value: &str) -> bool {
+    if !self.lowercases {
+      return false;
+    }
+    if self.uppercases_then_lowercases {
+      value.contains(['Σ', 'σ', 'ς'])
+    } else {
+      value.contains('Σ')
+    }
+  }
+
+  /// Single scan detecting ASCII input that every op in the pass leaves unchanged.
+  ///
+  /// Stateless ops only ever see stable bytes (whose whitespace-ness they keep),
+  /// so the whitespace-aware ops can be checked against the input directly.
+  fn is_fixed_point(&self, value: &str) -> bool {
+    let (mut prev_ws, mut irregular_ws) = (false, false);
+    for &b in value.as_bytes() {
+      if !b.is_ascii() || !self.stable[b as usize] {
+        return false;
+      }
+      let ws = (b as char).is_whitespace();
+      irregular_ws |= ws && (prev_ws || b != b' ');
+      prev_ws = ws;
+    }
+    if !(self.has_trim || self.has_normalize) {
+      return true;
+    }
+    let edge_ws = |b: Option<&u8>| b.is_some_and(|b| (*b as char).is_whitespace());
+    let bytes = value.as_bytes();
+    if edge_ws(bytes.first()) || edge_ws(bytes.last()) {
+      return false;
+    }
+    !(self.has_normalize && irregular_ws)
+  }
+
+  /// Feeds an ASCII byte through the lookup-table segments.
+  #[inline]
+  fn feed_ascii(&self, mut b: u8, states: &mut [OpState; MAX_FUSED], out: &mut String) {
+    for segment in &self.segments {
+      match segment {
+        Segment::Table(table) => {
+          b = table[b as usize];
+          if b == DROP {
+            return;
+          }
+        }
+        Segment::Ws(i) => {
+          match whitespace_step(&self.ops[*i], &mut states[*i], b as char, out.len()) {
+            Step::Drop => return,
+            Step::Pass => {}
+            Step::SpaceThenPass => self.feed_each(*i + 1, [' '].into_iter(), states, out),
+          }
+        }
+      }
+    }
+    out.push(b as char);
+  }
+
+  /// Feeds `c` into op `i`, passing whatever it emits on to the following ops
+  /// (and finally the output). Single-char emissions are handled in the loop;
+  /// only ops emitting several chars recurse.
+  fn feed(&self, mut i: usize, mut c: char, states: &mut [OpState; MAX_FUSED], out: &mut String) {
+    while let Some(op) = self.ops.get(i) {
+      match op {
+        CharOp::Trim | CharOp::NormalizeWhitespace => {
+          match whitespace_step(op, &mut states[i], c, out.len()) {
+            Step::Drop => return,
+            Step::Pass => {}
+            Step::SpaceThenPass => self.feed_each(i + 1, [' '].into_iter(), states, out),
+          }
+        }
+        CharOp::Lowercase if c.is_ascii() => c = c.to_ascii_lowercase(),
+        CharOp::Uppercase if c.is_ascii() => c = c.to_ascii_uppercase(),
+        CharOp::Lowercase => {
+          let mut mapped = c.to_lowercase();
+          if mapped.len() != 1 {
+            self.feed_each(i + 1, mapped, states, out);
+            return;
+          }
+          c = mapped.next().unwrap_or(c);
+        }
+        CharOp::Uppercase => {
+          let mut mapped = c.to_uppercase();
+          if mapped.len() != 1 {
+            self.feed_each(i + 1, mapped, states, out);
+            return;
+          }
+          c = mapped.next().unwrap_or(c);
+        }
+        CharOp::Keep(class) => {
+          if !class.contains(c) {
+            return;
+          }
+        }
+      }
+      i += 1;
+    }
+    out.push(c);
+  }
+
+  /// Feeds several chars emitted by op `i - 1` into op `i`.
+  #[cold]
+  #[inline(never)]
+  fn feed_each(
+    &self,
+    i: usize,
+    chars: impl Iterator<Item = char>,
+    states: &mut [OpState; MAX_FUSED],
+    out: &mut String,
+  ) {
+    for c in chars {
+      self.feed(i, c, states, out);
+    }
+  }
+}
+
+/// Outcome of a whitespace-aware op for one char.
+enum Step {
+  Drop,
+  Pass,
+  /// `NormalizeWhitespace` ends a whitespace run: emit one space, then the char.
+  SpaceThenPass,
+}
+
+/// Runs a whitespace-aware op (`Trim` or `NormalizeWhitespace`) on `c`.
+#[inline]
+fn whitespace_step(op: &CharOp, state: &mut OpState, c: char, out_len: usize) -> Step {
+  let ws = c.is_whitespace();
+  if matches!(op, CharOp::Trim) {
+    if ws {
+      if !state.started {
+        return Step::Drop;
+      }
+      // Whitespace is forwarded provisionally; if the input ends before the
+      // next non-whitespace char, the output is truncated back to `mark`.
+      state.mark.get_or_insert(out_len);
+    } else {
+      state.started = true;
+      state.mark = None;
+    }
+    return Step::Pass;
+  }
+  if ws {
+    state.pending_space |= state.started;
+    return Step::Drop;
+  }
+  state.started = true;
+  if std::mem::take(&mut state.pending_space) {
+    Step::SpaceThenPass
+  } else {
+    Step::Pass
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{MaskMode, RedactDetector, TruncateUnit, WordMask};
+  use std::sync::Arc;
+
+  /// Asserts that the compiled filter agrees with `FilterOp::apply_ref`.
+  fn assert_same(op: &FilterOp<String>, inputs: &[&str]) {
+    let compiled = op.compile();
+    for input in inputs {
+      assert_eq!(
+        compiled.apply_ref(input),
+        op.apply_ref(input),
+        "input {input:?} with {op:?}"
+      );
+      assert_eq!(
+        compiled.apply(input.to_string()),
+        op.apply(input.to_string())
+      );
+    }
+  }
+
+  const INPUTS: &[&str] = &[
+    "",
+    "   ",
+    "hello world",
+    "  Hello,   WORLD!  ",
+    "\tTabs\nand\r\nnewlines \u{a0}",
+    "Ünïcödé ÀÉÎ straße İstanbul",
+    "ΟΔΥΣΣΕΥΣ Σ",
+    "xbς οδυσσευς σ",
+    "a1 b2  c3 !@#",
+    "<b>bold</b> & 'quoted'",
+  ];
+
+  #[test]
+  fn test_fused_ops_match_filter_op() {
+    let ops = [
+      FilterOp::Trim,
+      FilterOp::NormalizeWhitespace,
+      FilterOp::Lowercase,
+      FilterOp::Uppercase,
+      FilterOp::Digits,
+      FilterOp::Alnum {
+        allow_whitespace: true,
+      },
+      FilterOp::Alpha {
+        allow_whitespace: false,
+      },
+      FilterOp::StripNewlines,
+      FilterOp::AllowChars {
+        set: "abc ÀÉ".into(),
+      },
+      FilterOp::DenyChars {
+        set: "<>&ü".into()
+      },
+    ];
+    for op in &ops {
+      assert_same(op, INPUTS);
+    }
+    // Every ordered pair, to exercise interactions between stateful ops.
+    for a in &ops {
+      for b in &ops {
+        assert_same(&FilterOp::Chain(vec![a.clone(), b.clone()]), INPUTS);
+      }
+    }
+  }
+
+  #[test]
+  fn test_final_sigma_produced_mid_pass() {
+    // `Uppercase` turns 'ς' into 'Σ', which `Lowercase` must map back by context.
+    let op = FilterOp::Chain(vec![FilterOp::Uppercase, FilterOp::Lowercase]);
+    assert_eq!(op.compile().apply_ref("xbς "), "xbς ");
+    assert_same(&op, &["xbς ", "σa", "aσ", "ΑΣ σ", "abc"]);
+    assert_same(
+      &FilterOp::Chain(vec![
+        FilterOp::Uppercase,
+        FilterOp::Trim,
+        FilterOp::Lowercase,
+      ]),
+      &[" οδυσσευς "],
+    );
+  }
+
+  #[test]
+  fn test_mixed_pipeline_matches_filter_op() {
+    let op = FilterOp::Chain(vec![
+      FilterOp::Trim,
+      FilterOp::StripTags,
+      FilterOp::Chain(vec![FilterOp::Lowercase, FilterOp::HtmlEntities]),
+      FilterOp::Each(Box::new(FilterOp::NormalizeWhitespace)),
+      FilterOp::Replace {
+        from: "bold".into(),
+        to: "strong".into(),
+      },
+      FilterOp::Truncate {
+        max_length: 12,
+        unit: TruncateUnit::Chars,
+        suffix: String::new(),
+        break_at_word: false,
+      },
+      FilterOp::Mask(
+        WordMask::new(
+          vec!["strong".into(), "Σ".into()],
+          MaskMode::WholeWord,
+          '*',
+          true,
+        )
+        .unwrap(),
+      ),
+      FilterOp::Redact {
+        detectors: RedactDetector::ALL.to_vec(),
+        mask_char: '#',
+      },
+      FilterOp::Trim,
+      FilterOp::Slug {
+        max_length: None,
+        separator: '-',
+        stop_words: Vec::new(),
+      },
+    ]);
+    assert_same(&op, INPUTS);
+  }
+
+  #[test]
+  fn test_long_runs_are_split() {
+    let op = FilterOp::Chain(vec![FilterOp::Trim; MAX_FUSED * 2 + 1]);
+    let compiled = op.compile();
+    assert_eq!(compiled.stages.len(), 3);
+    assert_same(&op, INPUTS);
+  }
+
+  #[test]
+  fn test_scalar_noops_are_dropped() {
+    let op = FilterOp::Chain(vec![
+      FilterOp::Sort,
+      FilterOp::Take(1),
+      FilterOp::Clamp {
+        min: String::new(),
+        max: String::new(),
+      },
+    ]);
+    assert!(op.compile().stages.is_empty());
+    assert!(matches!(op.compile().apply_ref("x"), Cow::Borrowed("x")));
+  }
+
+  #[test]
+  fn test_borrows_unchanged_input() {
+    let compiled = FilterOp::Chain(vec![
+      FilterOp::Trim,
+      FilterOp::Lowercase,
+      FilterOp::NormalizeWhitespace,
+    ])
+    .compile();
+    assert!(matches!(
+      compiled.apply_ref("hello world"),
+      Cow::Borrowed("hello world")
+    ));
+    assert!(matches!(
+      compiled.apply_ref("été à l'eau"),
+      Cow::Borrowed(_)
+    ));
+    assert_eq!(compiled.apply_ref("  hello  "), "hello");
+    assert!(matches!(compiled.apply_ref("Hello"), Cow::Owned(_)));
+  }
+
+  #[test]
+  fn test_owned_input_buffer_is_reused() {
+    let compiled = FilterOp::Chain(vec![
+      FilterOp::Custom(Arc::new(|s: String| s + "  ")),
+      FilterOp::Trim,
+    ])
+    .compile();
+    assert_eq!(compiled.apply_ref("  abc"), "abc");
+  }
+
+  #[test]
+  fn test_filter_trait() {
+    let compiled = CompiledFilter::from(&FilterOp::Uppercase);
+    assert_eq!(compiled.filter("abc".to_string()), "ABC");
+    assert_eq!(compiled.filter(Cow::Borrowed("ABC")), "ABC");
+  }
+}
@@ -0,0 +1,420 @@
+//! Date normalization backing [`TryFilterOp::NormalizeDate`](crate::TryFilterOp::NormalizeDate)
+//! (requires the `chrono` or `jiff` feature).
+//!
+//! Parsing follows the same [`DateFormat`] presets as `walrs_validation`'s date rules, so a
+//! value normalized to `output` is accepted by `Rule::Date`/`Rule::DateRange` configured with
+//! that format. When both features are enabled, `chrono` is used.
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use walrs_validation::DateFormat;
+
+use crate::{FilterError, FilterErrorCode};
+
+const NAME: &str = "NormalizeDate";
+
+/// Format string for ISO 8601 dates: `YYYY-MM-DD`
+const ISO_DATE_FMT: &str = "%Y-%m-%d";
+/// Format string for US-style dates: `MM/DD/YYYY`
+const US_DATE_FMT: &str = "%m/%d/%Y";
+/// Format string for EU-style dates: `DD/MM/YYYY`
+const EU_DATE_FMT: &str = "%d/%m/%Y";
+/// Output format for RFC 2822 dates; the time is midnight UTC.
+const RFC2822_DATE_FMT: &str = "%a, %d %b %Y 00:00:00 +0000";
+
+/// How [`TryFilterOp::NormalizeDate`](crate::TryFilterOp::NormalizeDate) resolves input that
+/// parses to *different* dates under several accepted formats — `02/03/2026` is February 3rd
+/// as a [`DateFormat::UsDate`] and March 2nd as a [`DateFormat::EuDate`].
+///
+/// Input whose parses all agree (`23/02/2026` only parses as an `EuDate`) is never ambiguous.
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_filter::{DateAmbiguity, TryFilterOp};
+/// use walrs_validation::DateFormat;
+///
+/// let op: TryFilterOp<String> = TryFilterOp::NormalizeDate {
+///     accept: vec![DateFormat::Iso8601, DateFormat::UsDate, DateFormat::EuDate],
+///     output: DateFormat::Iso8601,
+///     ambiguity: DateAmbiguity::MonthFirst,
+/// };
+///
+/// assert_eq!(op.try_apply("2/3/2026".to_string()).unwrap(), "2026-02-03");
+/// assert_eq!(op.try_apply("23/02/2026".to_string()).unwrap(), "2026-02-23");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DateAmbiguity {
+  /// Reject ambiguous input with an error (default).
+  #[default]
+  Reject,
+  /// Use the first accepted format that parsed the input.
+  FirstMatch,
+  /// Prefer a format with the month before the day (`UsDate`, or a `Custom` format such as
+  /// `%m.%d.%Y`).
+  MonthFirst,
+  /// Prefer a format with the day before the month (`EuDate`, or a `Custom` format such as
+  /// `%d.%m.%Y`).
+  DayFirst,
+}
+
+/// Relative order of the month and day fields in a [`DateFormat`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FieldOrder {
+  MonthFirst,
+  DayFirst,
+}
+
+/// Returns the month/day order of `format`, or `None` when it has no numeric day and month
+/// or lists the year first.
+fn field_order(format: &DateFormat) -> Option<FieldOrder> {
+  match format {
+    DateFormat::UsDate => Some(FieldOrder::MonthFirst),
+    DateFormat::EuDate => Some(FieldOrder::DayFirst),
+    DateFormat::Iso8601 | DateFormat::Rfc2822 => None,
+    DateFormat::Custom(fmt) => {
+      let mut chars = fmt.chars();
+      while let Some(c) = chars.next() {
+        if c != '%' {
+          continue;
+        }
+        // Skip padding flags (`%-m`, `%_d`, `%0e`).
+        let spec = chars.find(|c| !matches!(c, '-' | '_' | '0'));
+        match spec {
+          Some('m') => return Some(FieldOrder::MonthFirst),
+          Some('d' | 'e') => return Some(FieldOrder::DayFirst),
+          Some('Y' | 'y' | 'G' | 'g') => return None,
+          _ => {}
+        }
+      }
+      None
+    }
+  }
+}
+
+/// Returns the strftime-style format string used to write dates in `format`.
+fn output_format(format: &DateFormat) -> &str {
+  match format {
+    DateFormat::Iso8601 => ISO_DATE_FMT,
+    DateFormat::UsDate => US_DATE_FMT,
+    DateFormat::EuDate => EU_DATE_FMT,
+    DateFormat::Rfc2822 => RFC2822_DATE_FMT,
+    DateFormat::Custom(fmt) => fmt,
+  }
+}
+
+#[cfg(feature = "chrono")]
+mod backend {
+  use chrono::NaiveDate;
+  use std::fmt::Write;
+  use walrs_validation::DateFormat;
+
+  pub(super) type Date = NaiveDate;
+
+  pub(super) fn parse(value: &str, format: &DateFormat) -> Option<Date> {
+    match format {
+      DateFormat::Rfc2822 => chrono::DateTime::parse_from_rfc2822(value)
+        .map(|dt| dt.date_naive())
+        .ok(),
+      format => NaiveDate::parse_from_str(value, super::output_format(format)).ok(),
+    }
+  }
+
+  pub(super) fn format(date: Date, fmt: &str) -> Option<String> {
+    let mut out = String::new();
+    // `DelayedFormat` reports unsupported or time-only specifiers as a `fmt::Error`.
+    write!(out, "{}", date.format(fmt)).ok()?;
+    Some(out)
+  }
+}
+
+#[cfg(all(feature = "jiff", not(feature = "chrono")))]
+mod backend {
+  use jiff::civil;
+  use walrs_validation::DateFormat;
+
+  pub(super) type Date = civil::Date;
+
+  pub(super) fn parse(value: &str, format: &DateFormat) -> Option<Date> {
+    match format {
+      DateFormat::Rfc2822 => jiff::fmt::rfc2822::parse(value)
+        .map(|zoned| zoned.date())
+        .ok(),
+      format => civil::Date::strptime(super::output_format(format), value).ok(),
+    }
+  }
+
+  pub(super) fn format(date: Date, fmt: &str) -> Option<String> {
+    jiff::fmt::strtime::format(fmt, date).ok()
+  }
+}
+
+/// Parses `value` with each format in `accept`, resolving ambiguity per `ambiguity`, and
+/// writes the date in `output`. Surrounding whitespace is ignored.
+///
+/// Returns `Cow::Borrowed` when `value` is already in the output format.
+pub(crate) fn normalize_date<'a>(
+  value: &'a str,
+  accept: &[DateFormat],
+  output: &DateFormat,
+  ambiguity: DateAmbiguity,
+) -> Result<Cow<'a, str>, FilterError> {
+  let trimmed = value.trim();
+  let candidates: Vec<(&DateFormat, backend::Date)> = accept
+    .iter()
+    .filter_map(|format| backend::parse(trimmed, format).map(|date| (format, date)))
+    .collect();
+
+  let Some(&(_, first)) = candidates.first() else {
+    return Err(
+      FilterError::new(format!(
+        "cannot parse {value:?} as a date in any accepted format"
+      ))
+      .with_name(NAME)
+      .with_code(FilterErrorCode::InvalidDate),
+    );
+  };
+
+  let date = if candidates.iter().all(|(_, date)| *date == first) {
+    first
+  } else {
+    let preferred = match ambiguity {
+      DateAmbiguity::Reject => None,
+      DateAmbiguity::FirstMatch => Some(first),
+      DateAmbiguity::MonthFirst | DateAmbiguity::DayFirst => {
+        let order = if ambiguity == DateAmbiguity::MonthFirst {
+          FieldOrder::MonthFirst
+        } else {
+          FieldOrder::DayFirst
+        };
+        candidates
+          .iter()
+          .find(|(format, _)| field_order(format) == Some(order))
+          .map(|&(_, date)| date)
+      }
+    };
+    preferred.ok_or_else(|| {
+      FilterError::new(format!(
+        "{value:?} is ambiguous: it denotes different dates in the accepted formats"
+      ))
+      .with_name(NAME)
+      .with_code(FilterErrorCode::AmbiguousDate)
+    })?
+  };
+
+  let normalized = backend::format(date, output_format(output)).ok_or_else(|| {
+    FilterError::new(format!("cannot format a date with {output:?}"))
+      .with_name(NAME)
+      .with_code(FilterErrorCode::InvalidConfig)
+  })?;
+  if normalized == value {
+    Ok(Cow::Borrowed(value))
+  } else {
+    Ok(Cow::Owned(normalized))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn all_formats() -> Vec<DateFormat> {
+    vec![DateFormat::Iso8601, DateFormat::UsDate, DateFormat::EuDate]
+  }
+
+  #[test]
+  fn test_converts_between_presets() {
+    let iso = DateFormat::Iso8601;
+    assert_eq!(
+      normalize_date(
+        "02/23/2026",
+        &[DateFormat::UsDate],
+        &iso,
+        DateAmbiguity::Reject
+      )
+      .unwrap(),
+      "2026-02-23"
+    );
+    assert_eq!(
+      normalize_date(
+        " 2/3/2026 ",
+        &[DateFormat::UsDate],
+        &iso,
+        DateAmbiguity::Reject
+      )
+      .unwrap(),
+      "2026-02-03"
+    );
+    assert_eq!(
+      normalize_date(
+        "2026-02-23",
+        &[DateFormat::Iso8601],
+        &DateFormat::EuDate,
+        DateAmbiguity::Reject
+      )
+      .unwrap(),
+      "23/02/2026"
+    );
+    assert_eq!(
+      normalize_date(
+        "2026-02-23",
+        &[DateFormat::Iso8601],
+        &DateFormat::Rfc2822,
+        DateAmbiguity::Reject
+      )
+      .unwrap(),
+      "Mon, 23 Feb 2026 00:00:00 +0000"
+    );
+    assert_eq!(
+      normalize_date(
+        "Mon, 23 Feb 2026 18:00:00 +0000",
+        &[DateFormat::Rfc2822],
+        &iso,
+        DateAmbiguity::Reject
+      )
+      .unwrap(),
+      "2026-02-23"
+    );
+    assert_eq!(
+      normalize_date(
+        "23 February 2026",
+        &[DateFormat::Custom("%d %B %Y".into())],
+        &DateFormat::Custom("%B %-d, %Y".into()),
+        DateAmbiguity::Reject
+      )
+      .unwrap(),
+      "February 23, 2026"
+    );
+  }
+
+  #[test]
+  fn test_normalized_input_is_borrowed() {
+    let result = normalize_date(
+      "2026-02-23",
+      &all_formats(),
+      &DateFormat::Iso8601,
+      DateAmbiguity::Reject,
+    )
+    .unwrap();
+    assert!(matches!(result, Cow::Borrowed(_)));
+  }
+
+  #[test]
+  fn test_unambiguous_input_ignores_policy() {
+    for value in ["23/02/2026", "02/23/2026", "2026-02-23"] {
+      assert_eq!(
+        normalize_date(
+          value,
+          &all_formats(),
+          &DateFormat::Iso8601,
+          DateAmbiguity::Reject
+        )
+        .unwrap(),
+        "2026-02-23",
+        "{value}"
+      );
+    }
+    // Day and month are equal, so both readings agree.
+    assert_eq!(
+      normalize_date(
+        "03/03/2026",
+        &all_formats(),
+        &DateFormat::Iso8601,
+        DateAmbiguity::Reject
+      )
+      .unwrap(),
+      "2026-03-03"
+    );
+  }
+
+  #[test]
+  fn test_ambiguity_policies() {
+    let iso = DateFormat::Iso8601;
+    let err =
+      normalize_date("02/03/2026", &all_formats(), &iso, DateAmbiguity::Reject).unwrap_err();
+    assert_eq!(err.filter_name(), Some(NAME));
+    assert!(err.message().contains("ambiguous"));
+
+    assert_eq!(
+      normalize_date(
+        "02/03/2026",
+        &all_formats(),
+        &iso,
+        DateAmbiguity::MonthFirst
+      )
+      .unwrap(),
+      "2026-02-03"
+    );
+    assert_eq!(
+      normalize_date("02/03/2026", &all_formats(), &iso, DateAmbiguity::DayFirst).unwrap(),
+      "2026-03-02"
+    );
+
+    let eu_first = [DateFormat::EuDate, DateFormat::UsDate];
+    assert_eq!(
+      normalize_date("02/03/2026", &eu_first, &iso, DateAmbiguity::FirstMatch).unwrap(),
+      "2026-03-02"
+    );
+
+    // Custom formats are classified by the order of their day and month fields.
+    let dotted = [
+      DateFormat::Custom("%m.%d.%Y".into()),
+      DateFormat::Custom("%-d.%-m.%Y".into()),
+    ];
+    assert_eq!(
+      normalize_date("02.03.2026", &dotted, &iso, DateAmbiguity::DayFirst).unwrap(),
+      "2026-03-02"
+    );
+  }
+
+  #[test]
+  fn test_field_order() {
+    assert_eq!(
+      field_order(&DateFormat::UsDate),
+      Some(FieldOrder::MonthFirst)
+    );
+    assert_eq!(field_order(&DateFormat::EuDate), Some(FieldOrder::DayFirst));
+    assert_eq!(field_order(&DateFormat::Iso8601), None);
+    assert_eq!(
+      field_order(&DateFormat::Custom("%_d/%m/%y".into())),
+      Some(FieldOrder::DayFirst)
+    );
+    assert_eq!(field_order(&DateFormat::Custom("%Y.%d.%m".into())), None);
+  }
+
+  #[test]
+  fn test_invalid_input_is_rejected() {
+    for value in ["", "not a date", "2026-02-30", "13/13/2026"] {
+      let err = normalize_date(
+        value,
+        &all_formats(),
+        &DateFormat::Iso8601,
+        DateAmbiguity::Reject,
+      )
+      .unwrap_err();
+      assert_eq!(err.filter_name(), Some(NAME), "{value:?}");
+    }
+    assert!(
+      normalize_date(
+        "2026-02-23",
+        &[],
+        &DateFormat::Iso8601,
+        DateAmbiguity::Reject
+      )
+      .is_err()
+    );
+  }
+
+  #[test]
+  fn test_unformattable_output_is_rejected() {
+    let err = normalize_date(
+      "2026-02-23",
+      &[DateFormat::Iso8601],
+      &DateFormat::Custom("%H:%M".into()),
+      DateAmbiguity::Reject,
+    )
+    .unwrap_err();
+    assert_eq!(err.filter_name(), Some(NAME));
+  }
+}
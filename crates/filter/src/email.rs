@@ -0,0 +1,226 @@
+//! Email address normalization backing [`TryFilterOp::NormalizeEmail`](crate::TryFilterOp::NormalizeEmail).
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use url::Host;
+
+use crate::{FilterError, FilterErrorCode};
+
+const NAME: &str = "NormalizeEmail";
+
+/// Options for [`TryFilterOp::NormalizeEmail`](crate::TryFilterOp::NormalizeEmail).
+///
+/// Surrounding whitespace is always trimmed. The defaults only apply normalizations
+/// that never change which mailbox an address refers to; the provider-specific rules
+/// (`strip_plus_tag`, `remove_gmail_dots`) are opt-in. Missing fields take their
+/// [`Default`] values when deserializing.
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_filter::{EmailNormalizeOptions, TryFilterOp};
+///
+/// let op: TryFilterOp<String> = TryFilterOp::NormalizeEmail(EmailNormalizeOptions {
+///     strip_plus_tag: true,
+///     remove_gmail_dots: true,
+///     ..Default::default()
+/// });
+///
+/// assert_eq!(
+///     op.try_apply("  John.Doe+news@GMAIL.com ".to_string()).unwrap(),
+///     "johndoe@gmail.com"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmailNormalizeOptions {
+  /// Lowercase the domain. Default: `true`.
+  pub lowercase_domain: bool,
+
+  /// Convert an internationalized domain to its ASCII (punycode) form, rejecting
+  /// domains that are not valid hostnames. IDNA processing also lowercases the
+  /// domain. Default: `true`.
+  pub idn_to_ascii: bool,
+
+  /// Lowercase the local part. Most providers treat it case-insensitively, but
+  /// RFC 5321 does not require them to. Default: `false`.
+  pub lowercase_local_part: bool,
+
+  /// Remove a `+tag` subaddress from the local part (`jane+news` → `jane`).
+  /// Default: `false`.
+  pub strip_plus_tag: bool,
+
+  /// For Gmail addresses (`gmail.com`, `googlemail.com`), remove dots from the local
+  /// part and lowercase it — Gmail ignores both — and use `gmail.com` as the domain.
+  /// Default: `false`.
+  pub remove_gmail_dots: bool,
+}
+
+impl Default for EmailNormalizeOptions {
+  fn default() -> Self {
+    Self {
+      lowercase_domain: true,
+      idn_to_ascii: true,
+      lowercase_local_part: false,
+      strip_plus_tag: false,
+      remove_gmail_dots: false,
+    }
+  }
+}
+
+/// Normalizes the email address `value` according to `options`.
+///
+/// Returns `Cow::Borrowed` when `value` is already normalized.
+pub(crate) fn normalize_email<'a>(
+  value: &'a str,
+  options: &EmailNormalizeOptions,
+) -> Result<Cow<'a, str>, FilterError> {
+  let trimmed = value.trim();
+  let (local, domain) = trimmed
+    .rsplit_once('@')
+    .filter(|(local, domain)| !local.is_empty() && !domain.is_empty())
+    .ok_or_else(|| {
+      FilterError::new(format!("{value:?} is not an email address"))
+        .with_name(NAME)
+        .with_code(FilterErrorCode::InvalidEmail)
+    })?;
+
+  let mut domain = if domain.starts_with('[') && domain.ends_with(']') {
+    // Address literal (`user@[192.0.2.1]`); not a hostname.
+    Cow::Borrowed(domain)
+  } else if options.idn_to_ascii {
+    match Host::parse(domain) {
+      Ok(Host::Domain(ascii)) => Cow::Owned(ascii),
+      Ok(_) | Err(_) => {
+        return Err(
+          FilterError::new(format!("{domain:?} is not a valid email domain"))
+            .with_name(NAME)
+            .with_code(FilterErrorCode::InvalidEmail),
+        );
+      }
+    }
+  } else if options.lowercase_domain {
+    Cow::Owned(domain.to_lowercase())
+  } else {
+    Cow::Borrowed(domain)
+  };
+
+  let mut local = Cow::Borrowed(local);
+  let plus_tag = local
+    .find('+')
+    .filter(|&plus| options.strip_plus_tag && plus > 0);
+  if let Some(plus) = plus_tag {
+    local = Cow::Owned(local[..plus].to_string());
+  }
+  let is_gmail = ["gmail.com", "googlemail.com"]
+    .iter()
+    .any(|gmail| domain.eq_ignore_ascii_case(gmail));
+  if options.remove_gmail_dots && is_gmail {
+    local = Cow::Owned(local.replace('.', "").to_lowercase());
+    domain = Cow::Borrowed("gmail.com");
+  }
+  if options.lowercase_local_part {
+    local = Cow::Owned(local.to_lowercase());
+  }
+
+  let normalized = format!("{local}@{domain}");
+  if normalized == value {
+    Ok(Cow::Borrowed(value))
+  } else {
+    Ok(Cow::Owned(normalized))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn normalize(value: &str, options: &EmailNormalizeOptions) -> String {
+    normalize_email(value, options).unwrap().into_owned()
+  }
+
+  #[test]
+  fn test_defaults_trim_and_lowercase_domain_only() {
+    let options = EmailNormalizeOptions::default();
+    assert_eq!(
+      normalize("  John.Doe+news@GMAIL.com ", &options),
+      "John.Doe+news@gmail.com"
+    );
+    assert!(matches!(
+      normalize_email("jane@example.com", &options).unwrap(),
+      Cow::Borrowed(_)
+    ));
+  }
+
+  #[test]
+  fn test_idn_domain_is_punycoded() {
+    let options = EmailNormalizeOptions::default();
+    assert_eq!(
+      normalize("user@Bücher.DE", &options),
+      "user@xn--bcher-kva.de"
+    );
+
+    let unicode = EmailNormalizeOptions {
+      idn_to_ascii: false,
+      ..Default::default()
+    };
+    assert_eq!(normalize("user@Bücher.DE", &unicode), "user@bücher.de");
+  }
+
+  #[test]
+  fn test_provider_rules() {
+    let options = EmailNormalizeOptions {
+      strip_plus_tag: true,
+      remove_gmail_dots: true,
+      ..Default::default()
+    };
+    assert_eq!(
+      normalize("J.o.h.n+x@googlemail.com", &options),
+      "john@gmail.com"
+    );
+    // Dots are significant outside Gmail; the local part keeps its case.
+    assert_eq!(
+      normalize("John.Doe+news@example.com", &options),
+      "John.Doe@example.com"
+    );
+    // A leading `+` is not a subaddress separator.
+    assert_eq!(
+      normalize("+1234@example.com", &options),
+      "+1234@example.com"
+    );
+  }
+
+  #[test]
+  fn test_lowercase_local_part() {
+    let options = EmailNormalizeOptions {
+      lowercase_local_part: true,
+      ..Default::default()
+    };
+    assert_eq!(
+      normalize("John.Doe@Example.com", &options),
+      "john.doe@example.com"
+    );
+  }
+
+  #[test]
+  fn test_address_literal_is_kept() {
+    let options = EmailNormalizeOptions::default();
+    assert_eq!(normalize("root@[192.0.2.1]", &options), "root@[192.0.2.1]");
+  }
+
+  #[test]
+  fn test_invalid_addresses_are_rejected() {
+    let options = EmailNormalizeOptions::default();
+    for value in [
+      "",
+      "   ",
+      "no-at-sign",
+      "@example.com",
+      "user@",
+      "user@exa mple.com",
+    ] {
+      let err = normalize_email(value, &options).unwrap_err();
+      assert_eq!(err.filter_name(), Some(NAME), "{value:?}");
+    }
+  }
+}
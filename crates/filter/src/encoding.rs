@@ -0,0 +1,370 @@
+//! Encoders/decoders backing the `Base64*`, `Hex*`, `UrlDecode`, `JsonEscape`, and
+//! `JsEscape` variants of [`FilterOp`](crate::FilterOp) and [`TryFilterOp`](crate::TryFilterOp).
+//!
+//! Decoders report failures as [`FilterError`]s carrying the byte offset (into the
+//! decoder's input) at which decoding failed; invalid UTF-8 in the decoded bytes is
+//! reported as the span of input that encodes the offending sequence.
+
+use base64::Engine;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use base64::engine::{DecodePaddingMode, general_purpose};
+use percent_encoding::percent_decode_str;
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::ops::Range;
+
+use crate::{FilterError, FilterErrorCode};
+
+/// Standard alphabet, accepting input with or without `=` padding.
+const STANDARD_LENIENT: GeneralPurpose = GeneralPurpose::new(
+  &base64::alphabet::STANDARD,
+  GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// URL-safe alphabet, accepting input with or without `=` padding.
+const URL_SAFE_LENIENT: GeneralPurpose = GeneralPurpose::new(
+  &base64::alphabet::URL_SAFE,
+  GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+const HEX_LOWER: &[u8; 16] = b"0123456789abcdef";
+const HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Interprets decoded bytes as UTF-8. The first invalid sequence is reported as a
+/// span of the decoder's input, mapped from the decoded byte range by `input_span`.
+fn utf8_from_bytes<F>(
+  bytes: Vec<u8>,
+  filter_name: &str,
+  input_span: F,
+) -> Result<String, FilterError>
+where
+  F: Fn(Range<usize>) -> Range<usize>,
+{
+  String::from_utf8(bytes).map_err(|e| {
+    let decoded_len = e.as_bytes().len();
+    let e = e.utf8_error();
+    let start = e.valid_up_to();
+    let end = e.error_len().map_or(decoded_len, |len| start + len);
+    FilterError::new(format!(
+      "decoded bytes are not valid UTF-8 (invalid sequence at decoded byte {start})"
+    ))
+    .with_name(filter_name)
+    .with_code(FilterErrorCode::InvalidUtf8)
+    .with_span(input_span(start..end))
+  })
+}
+
+/// Maps a range of base64-decoded bytes to the input symbols encoding them: every
+/// 3 bytes come from 4 symbols, and byte `i` of a group spans symbols `i` and `i + 1`.
+fn base64_input_span(decoded: Range<usize>) -> Range<usize> {
+  let symbol = |byte: usize| byte / 3 * 4 + byte % 3;
+  symbol(decoded.start)..symbol(decoded.end - 1) + 2
+}
+
+/// Returns the offset into percent-encoded `value` of decoded byte `decoded`; each
+/// `%XX` escape decodes to one byte and every other byte to itself.
+fn percent_input_offset(value: &str, decoded: usize) -> usize {
+  let bytes = value.as_bytes();
+  let (mut i, mut n) = (0, 0);
+  while n < decoded && i < bytes.len() {
+    let escape = bytes[i] == b'%'
+      && bytes
+        .get(i + 1..i + 3)
+        .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit));
+    i += if escape { 3 } else { 1 };
+    n += 1;
+  }
+  i
+}
+
+/// Percent-decodes `value` and interprets the result as UTF-8.
+pub(crate) fn url_decode(value: &str) -> Result<Cow<'_, str>, FilterError> {
+  percent_decode_str(value).decode_utf8().map_err(|e| {
+    let start = e.valid_up_to();
+    let end = e
+      .error_len()
+      .map_or(value.len(), |len| percent_input_offset(value, start + len));
+    FilterError::new(format!("invalid utf-8 after percent-decode: {e}"))
+      .with_name("UrlDecode")
+      .with_code(FilterErrorCode::InvalidUtf8)
+      .with_span(percent_input_offset(value, start)..end)
+  })
+}
+
+/// Returns the byte offset just past the `char` starting at `offset` in `value`.
+fn char_end(value: &str, offset: usize) -> usize {
+  offset
+    + value
+      .get(offset..)
+      .and_then(|rest| rest.chars().next())
+      .map_or(1, char::len_utf8)
+}
+
+/// Base64-encodes `value` (typically a string's UTF-8 bytes).
+pub(crate) fn base64_encode(value: impl AsRef<[u8]>, url_safe: bool, padding: bool) -> String {
+  let engine = match (url_safe, padding) {
+    (false, true) => &general_purpose::STANDARD,
+    (false, false) => &general_purpose::STANDARD_NO_PAD,
+    (true, true) => &general_purpose::URL_SAFE,
+    (true, false) => &general_purpose::URL_SAFE_NO_PAD,
+  };
+  engine.encode(value)
+}
+
+/// Base64-decodes `value` (padding optional) and interprets the result as UTF-8.
+pub(crate) fn base64_decode(value: &str, url_safe: bool) -> Result<String, FilterError> {
+  const NAME: &str = "Base64Decode";
+  let engine = if url_safe {
+    &URL_SAFE_LENIENT
+  } else {
+    &STANDARD_LENIENT
+  };
+  let bytes = engine
+    .decode(value)
+    .map_err(|e| base64_error(value, e).with_name(NAME))?;
+  utf8_from_bytes(bytes, NAME, base64_input_span)
+}
+
+/// Converts a base64 decode error into a [`FilterError`] located in `value`.
+fn base64_error(value: &str, e: base64::DecodeError) -> FilterError {
+  let err = match e {
+    base64::DecodeError::InvalidByte(offset, byte) => {
+      FilterError::new(format!("invalid base64 byte {:?}", byte as char))
+        .with_span(offset..char_end(value, offset))
+    }
+    base64::DecodeError::InvalidLastSymbol(offset, byte) => {
+      FilterError::new(format!("invalid base64 trailing symbol {:?}", byte as char))
+        .with_span(offset..char_end(value, offset))
+    }
+    base64::DecodeError::InvalidLength(len) => {
+      FilterError::new(format!("invalid base64 length {len}")).with_offset(value.len())
+    }
+    base64::DecodeError::InvalidPadding => {
+      FilterError::new("invalid base64 padding").with_offset(value.find('=').unwrap_or(value.len()))
+    }
+  };
+  err.with_code(FilterErrorCode::InvalidEncoding)
+}
+
+/// Hex-encodes the UTF-8 bytes of `value`.
+pub(crate) fn hex_encode(value: &str, uppercase: bool) -> String {
+  let digits = if uppercase { HEX_UPPER } else { HEX_LOWER };
+  let mut out = String::with_capacity(value.len() * 2);
+  for b in value.bytes() {
+    out.push(digits[(b >> 4) as usize] as char);
+    out.push(digits[(b & 0x0f) as usize] as char);
+  }
+  out
+}
+
+/// Hex-decodes `value` (case-insensitive) and interprets the result as UTF-8.
+pub(crate) fn hex_decode(value: &str) -> Result<String, FilterError> {
+  const NAME: &str = "HexDecode";
+  let bytes = value.as_bytes();
+  if let Some(offset) = bytes.iter().position(|b| !b.is_ascii_hexdigit()) {
+    let ch = value[offset..].chars().next().unwrap_or_default();
+    return Err(
+      FilterError::new(format!("invalid hex digit {ch:?}"))
+        .with_name(NAME)
+        .with_code(FilterErrorCode::InvalidEncoding)
+        .with_span(offset..offset + ch.len_utf8()),
+    );
+  }
+  if bytes.len() & 1 == 1 {
+    return Err(
+      FilterError::new("odd number of hex digits")
+        .with_name(NAME)
+        .with_code(FilterErrorCode::InvalidEncoding)
+        .with_offset(bytes.len()),
+    );
+  }
+  let nibble = |b: u8| match b {
+    b'0'..=b'9' => b - b'0',
+    b'a'..=b'f' => b - b'a' + 10,
+    _ => b - b'A' + 10,
+  };
+  let decoded = bytes
+    .chunks_exact(2)
+    .map(|pair| (nibble(pair[0]) << 4) | nibble(pair[1]))
+    .collect();
+  utf8_from_bytes(decoded, NAME, |decoded| decoded.start * 2..decoded.end * 2)
+}
+
+/// Escapes `value` for embedding inside a JSON string literal (quotes not included).
+///
+/// Escapes `"`, `\`, and control characters (U+0000–U+001F) as required by RFC 8259.
+pub(crate) fn json_escape(value: &str) -> Cow<'_, str> {
+  escape_with(value, |c| match c {
+    '"' => Some(Cow::Borrowed("\\\"")),
+    '\\' => Some(Cow::Borrowed("\\\\")),
+    _ => escape_control(c),
+  })
+}
+
+/// Escapes `value` for embedding inside a JavaScript string literal (single-, double-,
+/// or backtick-quoted), including inline `<script>` blocks.
+///
+/// In addition to the JSON escapes, this escapes `'`, `` ` ``, `<`, `>`, `&`, and the
+/// line terminators U+2028/U+2029 so the output cannot terminate the literal or the
+/// surrounding `<script>` element.
+pub(crate) fn js_escape(value: &str) -> Cow<'_, str> {
+  escape_with(value, |c| match c {
+    '"' => Some(Cow::Borrowed("\\\"")),
+    '\'' => Some(Cow::Borrowed("\\'")),
+    '`' => Some(Cow::Borrowed("\\`")),
+    '\\' => Some(Cow::Borrowed("\\\\")),
+    '<' => Some(Cow::Borrowed("\\u003C")),
+    '>' => Some(Cow::Borrowed("\\u003E")),
+    '&' => Some(Cow::Borrowed("\\u0026")),
+    '\u{2028}' => Some(Cow::Borrowed("\\u2028")),
+    '\u{2029}' => Some(Cow::Borrowed("\\u2029")),
+    _ => escape_control(c),
+  })
+}
+
+fn escape_control(c: char) -> Option<Cow<'static, str>> {
+  match c {
+    '\n' => Some(Cow::Borrowed("\\n")),
+    '\r' => Some(Cow::Borrowed("\\r")),
+    '\t' => Some(Cow::Borrowed("\\t")),
+    '\u{08}' => Some(Cow::Borrowed("\\b")),
+    '\u{0C}' => Some(Cow::Borrowed("\\f")),
+    c if (c as u32) < 0x20 => {
+      let mut s = String::with_capacity(6);
+      let _ = write!(s, "\\u{:04X}", c as u32);
+      Some(Cow::Owned(s))
+    }
+    _ => None,
+  }
+}
+
+/// Replaces every character for which `escape` returns `Some`, returning
+/// `Cow::Borrowed` when nothing needed escaping.
+fn escape_with<F>(value: &str, escape: F) -> Cow<'_, str>
+where
+  F: Fn(char) -> Option<Cow<'static, str>>,
+{
+  let Some(first) = value.char_indices().find(|&(_, c)| escape(c).is_some()) else {
+    return Cow::Borrowed(value);
+  };
+  let mut out = String::with_capacity(value.len() + 8);
+  out.push_str(&value[..first.0]);
+  for c in value[first.0..].chars() {
+    match escape(c) {
+      Some(escaped) => out.push_str(&escaped),
+      None => out.push(c),
+    }
+  }
+  Cow::Owned(out)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_base64_roundtrip() {
+    for (url_safe, padding) in [(false, true), (false, false), (true, true), (true, false)] {
+      let encoded = base64_encode("héllo?>", url_safe, padding);
+      assert_eq!(base64_decode(&encoded, url_safe).unwrap(), "héllo?>");
+    }
+  }
+
+  #[test]
+  fn test_base64_alphabets() {
+    assert_eq!(base64_encode("??>", false, true), "Pz8+");
+    assert_eq!(base64_encode("??>", true, true), "Pz8-");
+    assert_eq!(base64_encode("a", false, true), "YQ==");
+    assert_eq!(base64_encode("a", false, false), "YQ");
+  }
+
+  #[test]
+  fn test_base64_decode_invalid_byte_offset() {
+    let err = base64_decode("YWJj!GVm", false).unwrap_err();
+    assert_eq!(err.filter_name(), Some("Base64Decode"));
+    assert_eq!(err.offset(), Some(4));
+    // `-` is not in the standard alphabet.
+    assert_eq!(base64_decode("Pz8-", false).unwrap_err().offset(), Some(3));
+  }
+
+  #[test]
+  fn test_base64_decode_invalid_utf8() {
+    let err = base64_decode("/w==", false).unwrap_err();
+    assert!(err.message().contains("UTF-8"));
+    assert_eq!(err.code(), FilterErrorCode::InvalidUtf8);
+    assert_eq!(err.span(), Some(0..2));
+    // "abc" then 0xFF: decoded byte 3 is encoded by symbols 4 and 5.
+    let encoded = base64_encode([b'a', b'b', b'c', 0xff, b'd'], false, true);
+    assert_eq!(
+      base64_decode(&encoded, false).unwrap_err().span(),
+      Some(4..6)
+    );
+    // Decoded byte 5 (third of its group) is encoded by symbols 6 and 7.
+    let encoded = base64_encode([b'a', b'b', b'c', b'd', b'e', 0xff], false, true);
+    assert_eq!(
+      base64_decode(&encoded, false).unwrap_err().span(),
+      Some(6..8)
+    );
+  }
+
+  #[test]
+  fn test_base64_decode_invalid_padding_offset() {
+    // The lenient engines accept any padding, so build the error directly.
+    let err = base64_error("YQ=a=", base64::DecodeError::InvalidPadding);
+    assert!(err.message().contains("padding"));
+    assert_eq!(err.offset(), Some(2));
+    let err = base64_error("YQ", base64::DecodeError::InvalidPadding);
+    assert_eq!(err.offset(), Some(2));
+  }
+
+  #[test]
+  fn test_hex_roundtrip_and_case() {
+    assert_eq!(hex_encode("Hi!", false), "486921");
+    assert_eq!(hex_encode("é", true), "C3A9");
+    assert_eq!(hex_decode("C3a9").unwrap(), "é");
+    assert_eq!(hex_decode("").unwrap(), "");
+  }
+
+  #[test]
+  fn test_hex_decode_errors() {
+    let err = hex_decode("48zz").unwrap_err();
+    assert_eq!(err.offset(), Some(2));
+    let err = hex_decode("486").unwrap_err();
+    assert_eq!(err.offset(), Some(3));
+    assert!(err.message().contains("odd"));
+    let err = hex_decode("ff").unwrap_err();
+    assert!(err.message().contains("UTF-8"));
+    assert_eq!(err.span(), Some(0..2));
+    // "hi" then a truncated two-byte sequence.
+    assert_eq!(hex_decode("6869c3").unwrap_err().span(), Some(4..6));
+  }
+
+  #[test]
+  fn test_url_decode_invalid_utf8_span() {
+    let err = url_decode("a%20b%FFc").unwrap_err();
+    assert_eq!(err.code(), FilterErrorCode::InvalidUtf8);
+    assert_eq!(err.span(), Some(5..8));
+    // A truncated sequence at the end spans to the end of the input.
+    assert_eq!(url_decode("x%C3").unwrap_err().span(), Some(1..4));
+    // A `%` without two hex digits is literal.
+    assert_eq!(url_decode("%zz%FF").unwrap_err().span(), Some(3..6));
+  }
+
+  #[test]
+  fn test_json_escape() {
+    assert_eq!(
+      json_escape("say \"hi\"\\\n\t\u{1}"),
+      "say \\\"hi\\\"\\\\\\n\\t\\u0001"
+    );
+    assert!(matches!(json_escape("plain 'text' </>"), Cow::Borrowed(_)));
+  }
+
+  #[test]
+  fn test_js_escape() {
+    assert_eq!(
+      js_escape("</script><b>'`&\u{2028}"),
+      "\\u003C/script\\u003E\\u003Cb\\u003E\\'\\`\\u0026\\u2028"
+    );
+    assert!(matches!(js_escape("plain text"), Cow::Borrowed(_)));
+  }
+}
@@ -0,0 +1,256 @@
+//! File name sanitization backing [`FilterOp::SanitizeFilename`](crate::FilterOp::SanitizeFilename).
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use walrs_validation::{is_forbidden_filename_char, is_windows_reserved_name};
+
+/// Options for [`FilterOp::SanitizeFilename`](crate::FilterOp::SanitizeFilename).
+///
+/// Missing fields take their [`Default`] values when deserializing.
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_filter::{FilenameOptions, FilterOp};
+///
+/// let op = FilterOp::<String>::SanitizeFilename(FilenameOptions {
+///     max_bytes: 16,
+///     ..Default::default()
+/// });
+///
+/// assert_eq!(op.apply_ref("../../etc/passwd"), ".._.._etc_passwd");
+/// assert_eq!(op.apply_ref("aux.txt"), "_aux.txt");
+/// assert_eq!(op.apply_ref("quarterly report final.pdf"), "quarterly re.pdf");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FilenameOptions {
+  /// Character substituted for each forbidden character; `None` removes them instead.
+  /// A replacement that is itself forbidden is treated as `None`. Default: `Some('_')`.
+  pub replacement: Option<char>,
+
+  /// Maximum length in UTF-8 bytes; longer names are truncated at a character
+  /// boundary. Default: `255`.
+  pub max_bytes: usize,
+
+  /// Keep the extension (from the last `.`, if it is not the first character) when
+  /// truncating, shortening the stem instead. Default: `true`.
+  pub preserve_extension: bool,
+}
+
+impl Default for FilenameOptions {
+  fn default() -> Self {
+    Self {
+      replacement: Some('_'),
+      max_bytes: 255,
+      preserve_extension: true,
+    }
+  }
+}
+
+/// Largest char boundary in `value` that is `<= index`.
+fn floor_char_boundary(value: &str, mut index: usize) -> usize {
+  if index >= value.len() {
+    return value.len();
+  }
+  while !value.is_char_boundary(index) {
+    index -= 1;
+  }
+  index
+}
+
+/// Truncates `name` to at most `max_bytes`, optionally shortening only the stem.
+fn truncate(name: &mut String, max_bytes: usize, preserve_extension: bool) {
+  if name.len() <= max_bytes {
+    return;
+  }
+  let extension = name
+    .rfind('.')
+    .filter(|&dot| preserve_extension && dot > 0 && name.len() - dot < max_bytes);
+  match extension {
+    Some(dot) => {
+      let stem_end = floor_char_boundary(name, max_bytes - (name.len() - dot));
+      name.replace_range(stem_end..dot, "");
+    }
+    None => name.truncate(floor_char_boundary(name, max_bytes)),
+  }
+}
+
+fn trim_trailing_dots_and_spaces(name: &mut String) {
+  let len = name.trim_end_matches(['.', ' ']).len();
+  name.truncate(len);
+}
+
+/// Repairs `value` into a file name that is safe on common filesystems and as a
+/// storage key. The result may be empty (for input like `""` or `"..."`).
+///
+/// Returns `Cow::Borrowed` when `value` is already safe.
+pub(crate) fn sanitize_filename<'a>(value: &'a str, options: &FilenameOptions) -> Cow<'a, str> {
+  let replacement = options
+    .replacement
+    .filter(|&c| !is_forbidden_filename_char(c));
+  let mut name: String = value
+    .chars()
+    .filter_map(|c| {
+      if is_forbidden_filename_char(c) {
+        replacement
+      } else {
+        Some(c)
+      }
+    })
+    .collect();
+  truncate(&mut name, options.max_bytes, options.preserve_extension);
+  trim_trailing_dots_and_spaces(&mut name);
+
+  if is_windows_reserved_name(&name) {
+    name.insert(0, replacement.unwrap_or('_'));
+    // The prefix may push the name over the limit; the prefixed stem can no longer
+    // be a reserved name after truncation.
+    truncate(&mut name, options.max_bytes, options.preserve_extension);
+    trim_trailing_dots_and_spaces(&mut name);
+  }
+
+  if name == value {
+    Cow::Borrowed(value)
+  } else {
+    Cow::Owned(name)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sanitize(value: &str) -> String {
+    sanitize_filename(value, &FilenameOptions::default()).into_owned()
+  }
+
+  #[test]
+  fn test_safe_names_are_borrowed() {
+    for name in ["report.pdf", ".gitignore", "naïve café.txt", "console.log"] {
+      assert!(
+        matches!(
+          sanitize_filename(name, &FilenameOptions::default()),
+          Cow::Borrowed(_)
+        ),
+        "{name:?}"
+      );
+    }
+  }
+
+  #[test]
+  fn test_forbidden_and_control_chars_are_replaced() {
+    assert_eq!(sanitize("../../etc/passwd"), ".._.._etc_passwd");
+    assert_eq!(sanitize("C:\\Users\\me\\a.txt"), "C__Users_me_a.txt");
+    assert_eq!(sanitize("a\0b\u{1F}c\u{7F}d.txt"), "a_b_c_d.txt");
+    assert_eq!(sanitize("what?<*>|\".txt"), "what______.txt");
+
+    let remove = FilenameOptions {
+      replacement: None,
+      ..Default::default()
+    };
+    assert_eq!(sanitize_filename("../a/b.txt", &remove), "..ab.txt");
+    // A forbidden replacement falls back to removal.
+    let slash = FilenameOptions {
+      replacement: Some('/'),
+      ..Default::default()
+    };
+    assert_eq!(sanitize_filename("a:b", &slash), "ab");
+  }
+
+  #[test]
+  fn test_trailing_dots_and_spaces_are_trimmed() {
+    assert_eq!(sanitize("name. . "), "name");
+    assert_eq!(sanitize("."), "");
+    assert_eq!(sanitize(".."), "");
+    assert_eq!(sanitize("   "), "");
+  }
+
+  #[test]
+  fn test_windows_reserved_names_are_prefixed() {
+    assert_eq!(sanitize("CON"), "_CON");
+    assert_eq!(sanitize("aux.txt"), "_aux.txt");
+    assert_eq!(sanitize("lpt9.tar.gz"), "_lpt9.tar.gz");
+    assert_eq!(sanitize("nul."), "_nul");
+    assert_eq!(sanitize("console"), "console");
+  }
+
+  #[test]
+  fn test_truncation_respects_utf8_and_extension() {
+    let options = |preserve_extension| FilenameOptions {
+      max_bytes: 10,
+      preserve_extension,
+      ..Default::default()
+    };
+    // "ééééé.txt" is 14 bytes; the stem is cut at a char boundary.
+    assert_eq!(sanitize_filename("ééééé.txt", &options(true)), "ééé.txt");
+    assert_eq!(sanitize_filename("ééééé.txt", &options(false)), "ééééé");
+    // An extension that does not fit is not preserved.
+    assert_eq!(
+      sanitize_filename("a.verylongextension", &options(true)),
+      "a.verylong"
+    );
+    // Truncation never leaves a trailing dot.
+    assert_eq!(
+      sanitize_filename("abcdefghi.j", &options(false)),
+      "abcdefghi"
+    );
+  }
+
+  #[test]
+  fn test_reserved_prefix_respects_max_bytes() {
+    let options = FilenameOptions {
+      max_bytes: 7,
+      ..Default::default()
+    };
+    assert_eq!(sanitize_filename("con.txt", &options), "_co.txt");
+  }
+
+  /// The sanitizer and `walrs_validation::Rule::Filename` agree: sanitized output is
+  /// always accepted (unless empty), and accepted names are left unchanged.
+  #[cfg(feature = "validation")]
+  #[test]
+  fn test_agrees_with_filename_rule() {
+    use walrs_validation::{Rule, ValidateRef};
+
+    let inputs = [
+      "report.pdf",
+      ".gitignore",
+      "../../etc/passwd",
+      "C:\\Windows\\system32",
+      "a\0b\r\n.txt",
+      "name. . ",
+      "...",
+      "CON",
+      "Com1.log",
+      "con.txt",
+      "ééééé.txt",
+      "a.verylongextension",
+      "what?<*>|\".txt",
+    ];
+    for max_bytes in [7, 10, 255] {
+      let rule = Rule::<String>::filename(max_bytes);
+      for replacement in [Some('_'), None] {
+        let options = FilenameOptions {
+          replacement,
+          max_bytes,
+          ..Default::default()
+        };
+        for input in inputs {
+          let sanitized = sanitize_filename(input, &options);
+          if !sanitized.is_empty() {
+            assert!(
+              rule.validate_ref(sanitized.as_ref()).is_ok(),
+              "{input:?} -> {sanitized:?} (max {max_bytes})"
+            );
+          }
+          assert_eq!(
+            rule.validate_ref(input).is_ok(),
+            sanitized == input,
+            "{input:?} (max {max_bytes})"
+          );
+        }
+      }
+    }
+  }
+}
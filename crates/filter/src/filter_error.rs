@@ -1,40 +1,205 @@
 //! Error type for fallible filter operations.
 //!
-//! [`FilterError`] represents a failure during a filter transformation.
-//! With the `validation` feature enabled, it can be converted into a
-//! [`walrs_validation::Violation`] for seamless integration with the validation error pipeline.
+//! [`FilterError`] represents a failure during a filter transformation, classified
+//! by a stable [`FilterErrorCode`]. With the `validation` feature enabled, it can be
+//! converted into a [`walrs_validation::Violation`] for seamless integration with the
+//! validation error pipeline.
 
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::ops::Range;
+
+/// A stable, machine-readable classification of a [`FilterError`].
+///
+/// Unlike the message, codes do not change between releases, so clients can
+/// branch on them (or on [`as_str`](Self::as_str)) to localize or highlight errors.
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_filter::{FilterErrorCode, TryFilterOp};
+///
+/// let err = TryFilterOp::<String>::ToInt.try_apply("  4x2 ".to_string()).unwrap_err();
+/// assert_eq!(err.code(), FilterErrorCode::NotANumber);
+/// assert_eq!(err.code().as_str(), "not_a_number");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterErrorCode {
+  /// A failure without a more specific code, e.g. from a `TryCustom` filter (default).
+  #[default]
+  Custom,
+  /// Decoded bytes, or the input stream, are not valid UTF-8.
+  InvalidUtf8,
+  /// The value is not a recognized boolean literal (`ToBool`).
+  NotABoolean,
+  /// The value is not a number of the requested type (`ToInt`, `ToFloat`).
+  NotANumber,
+  /// The value is not valid base64 or hex (`Base64Decode`, `HexDecode`).
+  InvalidEncoding,
+  /// The value contains an unknown or invalid entity reference (`HtmlEntityDecode`).
+  InvalidEntity,
+  /// The value is not an absolute URL (`CanonicalizeUrl`).
+  InvalidUrl,
+  /// The value is not an email address (`NormalizeEmail`).
+  InvalidEmail,
+  /// The value is not a valid phone number (`NormalizePhone`).
+  InvalidPhone,
+  /// The value is not a date in any accepted format (`NormalizeDate`).
+  InvalidDate,
+  /// The value denotes different dates in different accepted formats (`NormalizeDate`).
+  AmbiguousDate,
+  /// A `Named` filter is not registered or was applied before binding.
+  UnknownFilter,
+  /// The filter's own configuration is invalid, e.g. an unknown phone region.
+  InvalidConfig,
+  /// An async filter (`TryCustomAsync`) was applied synchronously.
+  AsyncOnly,
+}
+
+impl FilterErrorCode {
+  #[cfg(feature = "validation")]
+  const ALL: [Self; 14] = [
+    Self::Custom,
+    Self::InvalidUtf8,
+    Self::NotABoolean,
+    Self::NotANumber,
+    Self::InvalidEncoding,
+    Self::InvalidEntity,
+    Self::InvalidUrl,
+    Self::InvalidEmail,
+    Self::InvalidPhone,
+    Self::InvalidDate,
+    Self::AmbiguousDate,
+    Self::UnknownFilter,
+    Self::InvalidConfig,
+    Self::AsyncOnly,
+  ];
+
+  /// The code as a `snake_case` string, matching its serialized form.
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Custom => "custom",
+      Self::InvalidUtf8 => "invalid_utf8",
+      Self::NotABoolean => "not_a_boolean",
+      Self::NotANumber => "not_a_number",
+      Self::InvalidEncoding => "invalid_encoding",
+      Self::InvalidEntity => "invalid_entity",
+      Self::InvalidUrl => "invalid_url",
+      Self::InvalidEmail => "invalid_email",
+      Self::InvalidPhone => "invalid_phone",
+      Self::InvalidDate => "invalid_date",
+      Self::AmbiguousDate => "ambiguous_date",
+      Self::UnknownFilter => "unknown_filter",
+      Self::InvalidConfig => "invalid_config",
+      Self::AsyncOnly => "async_only",
+    }
+  }
+
+  /// The [`ViolationType`](walrs_validation::ViolationType) a [`FilterError`] with this
+  /// code converts to: `TypeMismatch` for malformed input, `CustomError` otherwise.
+  #[cfg(feature = "validation")]
+  pub fn violation_type(&self) -> walrs_validation::ViolationType {
+    match self {
+      Self::Custom | Self::UnknownFilter | Self::InvalidConfig | Self::AsyncOnly => {
+        walrs_validation::ViolationType::CustomError
+      }
+      _ => walrs_validation::ViolationType::TypeMismatch,
+    }
+  }
+
+  /// The code of the [`FilterError`] a [`Violation`](walrs_validation::Violation) was
+  /// converted from, or `None` for violations raised by validation rules.
+  ///
+  /// Reads the violation's [`code`](walrs_validation::Violation::code), which the
+  /// conversion sets alongside the error's step path and span.
+  ///
+  /// ```rust
+  /// use walrs_filter::{FilterErrorCode, TryFilterOp};
+  /// use walrs_validation::{Violation, ViolationType};
+  ///
+  /// let err = TryFilterOp::<String>::ToInt.try_apply("4x2".to_string()).unwrap_err();
+  /// let violation: Violation = err.into();
+  /// assert_eq!(violation.violation_type(), ViolationType::TypeMismatch);
+  /// assert_eq!(violation.code(), Some("not_a_number"));
+  /// assert_eq!(violation.span(), Some(0..3));
+  /// assert_eq!(
+  ///     FilterErrorCode::from_violation(&violation),
+  ///     Some(FilterErrorCode::NotANumber)
+  /// );
+  /// assert_eq!(FilterErrorCode::from_violation(&Violation::value_missing()), None);
+  /// ```
+  #[cfg(feature = "validation")]
+  pub fn from_violation(violation: &walrs_validation::Violation) -> Option<Self> {
+    let code = violation.code()?;
+    Self::ALL.into_iter().find(|c| c.as_str() == code)
+  }
+}
+
+impl fmt::Display for FilterErrorCode {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
 
 /// An error produced by a fallible filter operation.
 ///
-/// Contains a human-readable message and an optional filter name for
-/// context in error reporting.
+/// Contains a human-readable message, a stable [`FilterErrorCode`], and optional
+/// context for error reporting: the filter name, the byte span of the input where
+/// parsing failed, and the position of the failing step within nested
+/// [`TryFilterOp::Chain`](crate::TryFilterOp::Chain)s.
 ///
 /// # Example
 ///
 /// ```rust
-/// use walrs_filter::FilterError;
+/// use walrs_filter::{FilterError, FilterErrorCode};
 ///
 /// let err = FilterError::new("invalid base64 input")
 ///     .with_name("Base64Decode");
 ///
 /// assert_eq!(err.message(), "invalid base64 input");
 /// assert_eq!(err.filter_name(), Some("Base64Decode"));
+/// assert_eq!(err.code(), FilterErrorCode::Custom);
 /// assert_eq!(err.to_string(), "Filter 'Base64Decode' failed: invalid base64 input");
 /// ```
+///
+/// Errors raised inside a chain record where the failing step sits:
+///
+/// ```rust
+/// use walrs_filter::{FilterErrorCode, FilterOp, TryFilterOp};
+///
+/// let op = TryFilterOp::<String>::Chain(vec![
+///     TryFilterOp::Infallible(FilterOp::Trim),
+///     TryFilterOp::Chain(vec![TryFilterOp::UrlDecode, TryFilterOp::HexDecode]),
+/// ]);
+///
+/// let err = op.try_apply(" 6869z ".to_string()).unwrap_err();
+/// assert_eq!(err.path(), &[1, 1]);
+/// assert_eq!(err.span(), Some(4..5));
+/// assert_eq!(err.code(), FilterErrorCode::InvalidEncoding);
+/// assert_eq!(
+///     err.to_string(),
+///     "Filter 'HexDecode' failed at step [1, 1], byte 4: invalid hex digit 'z'"
+/// );
+/// ```
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct FilterError {
   message: String,
   filter_name: Option<String>,
+  code: FilterErrorCode,
+  span: Option<Range<usize>>,
+  path: Vec<usize>,
 }
 
 impl FilterError {
-  /// Creates a new `FilterError` with the given message.
+  /// Creates a new `FilterError` with the given message and [`FilterErrorCode::Custom`].
   pub fn new(message: impl Into<String>) -> Self {
     Self {
       message: message.into(),
       filter_name: None,
+      code: FilterErrorCode::Custom,
+      span: None,
+      path: Vec::new(),
     }
   }
 
@@ -44,6 +209,34 @@ impl FilterError {
     self
   }
 
+  /// Sets the error's [`FilterErrorCode`].
+  pub fn with_code(mut self, code: FilterErrorCode) -> Self {
+    self.code = code;
+    self
+  }
+
+  /// Attaches the byte offset into the filter's input at which the failure was detected.
+  ///
+  /// Shorthand for an empty [`with_span`](Self::with_span) at `offset`.
+  pub fn with_offset(self, offset: usize) -> Self {
+    self.with_span(offset..offset)
+  }
+
+  /// Attaches the byte range of the filter's input that failed to parse.
+  pub fn with_span(mut self, span: Range<usize>) -> Self {
+    self.span = Some(span);
+    self
+  }
+
+  /// Prepends `index` to the error's step path.
+  ///
+  /// Chains call this with the position of the failing step as the error propagates
+  /// outward, so the path reads from the outermost chain inward.
+  pub fn with_step(mut self, index: usize) -> Self {
+    self.path.insert(0, index);
+    self
+  }
+
   /// Returns a reference to the error message.
   pub fn message(&self) -> &str {
     &self.message
@@ -53,14 +246,74 @@ impl FilterError {
   pub fn filter_name(&self) -> Option<&str> {
     self.filter_name.as_deref()
   }
+
+  /// Returns the error's [`FilterErrorCode`].
+  pub fn code(&self) -> FilterErrorCode {
+    self.code
+  }
+
+  /// Returns the optional byte offset into the input at which the failure was detected
+  /// (the start of [`span`](Self::span)).
+  pub fn offset(&self) -> Option<usize> {
+    self.span.as_ref().map(|span| span.start)
+  }
+
+  /// Returns the optional byte range of the input that failed to parse.
+  pub fn span(&self) -> Option<Range<usize>> {
+    self.span.clone()
+  }
+
+  /// Converts [`span`](Self::span) to a range of `char` indices into `input`, the value
+  /// the failing step received.
+  ///
+  /// Returns `None` when there is no span or it does not fall on `char` boundaries of
+  /// `input`.
+  ///
+  /// ```rust
+  /// use walrs_filter::TryFilterOp;
+  ///
+  /// let input = "é&bogus;";
+  /// let err = TryFilterOp::<String>::HtmlEntityDecode
+  ///     .try_apply(input.to_string())
+  ///     .unwrap_err();
+  /// assert_eq!(err.span(), Some(2..9));
+  /// assert_eq!(err.char_span(input), Some(1..8));
+  /// ```
+  pub fn char_span(&self, input: &str) -> Option<Range<usize>> {
+    let span = self.span.as_ref()?;
+    let prefix = input.get(..span.start)?;
+    let spanned = input.get(span.clone())?;
+    let start = prefix.chars().count();
+    Some(start..start + spanned.chars().count())
+  }
+
+  /// Returns the indices of the failing step within nested chains, outermost first.
+  ///
+  /// Empty when the error did not come from inside a chain.
+  pub fn path(&self) -> &[usize] {
+    &self.path
+  }
 }
 
 impl fmt::Display for FilterError {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match &self.filter_name {
-      Some(name) => write!(f, "Filter '{}' failed: {}", name, self.message),
-      None => write!(f, "Filter failed: {}", self.message),
+      Some(name) => write!(f, "Filter '{}' failed", name)?,
+      None => write!(f, "Filter failed")?,
+    }
+    let mut sep = " at";
+    if !self.path.is_empty() {
+      write!(f, "{sep} step {:?}", self.path)?;
+      sep = ",";
     }
+    match &self.span {
+      Some(span) if span.end > span.start + 1 => {
+        write!(f, "{sep} bytes {}..{}", span.start, span.end)?
+      }
+      Some(span) => write!(f, "{sep} byte {}", span.start)?,
+      None => {}
+    }
+    write!(f, ": {}", self.message)
   }
 }
 
@@ -72,11 +325,17 @@ impl std::error::Error for FilterError {}
 
 #[cfg(feature = "validation")]
 impl From<FilterError> for walrs_validation::Violation {
+  /// Takes the code's [`violation_type`](FilterErrorCode::violation_type) and the
+  /// error's `Display` text as the message, and carries the code, step path, and span
+  /// over as the violation's structured details.
   fn from(err: FilterError) -> Self {
-    walrs_validation::Violation::new(
-      walrs_validation::ViolationType::CustomError,
-      err.to_string(),
-    )
+    let violation = walrs_validation::Violation::new(err.code.violation_type(), err.to_string())
+      .with_code(err.code.as_str())
+      .with_path(err.path);
+    match err.span {
+      Some(span) => violation.with_span(span),
+      None => violation,
+    }
   }
 }
 
@@ -117,6 +376,60 @@ mod tests {
     assert_eq!(err.to_string(), "Filter 'UrlDecode' failed: bad input");
   }
 
+  #[test]
+  fn test_filter_error_with_offset() {
+    let err = FilterError::new("invalid hex digit")
+      .with_name("HexDecode")
+      .with_offset(3);
+    assert_eq!(err.offset(), Some(3));
+    assert_eq!(
+      err.to_string(),
+      "Filter 'HexDecode' failed at byte 3: invalid hex digit"
+    );
+    assert_eq!(FilterError::new("bad").offset(), None);
+  }
+
+  #[test]
+  fn test_filter_error_code_span_and_path() {
+    let err = FilterError::new("invalid hex digit")
+      .with_name("HexDecode")
+      .with_code(FilterErrorCode::InvalidEncoding)
+      .with_span(4..6)
+      .with_step(3)
+      .with_step(1);
+    assert_eq!(err.code(), FilterErrorCode::InvalidEncoding);
+    assert_eq!(err.span(), Some(4..6));
+    assert_eq!(err.offset(), Some(4));
+    assert_eq!(err.path(), &[1, 3]);
+    assert_eq!(
+      err.to_string(),
+      "Filter 'HexDecode' failed at step [1, 3], bytes 4..6: invalid hex digit"
+    );
+    assert_eq!(
+      FilterError::new("bad").with_step(0).to_string(),
+      "Filter failed at step [0]: bad"
+    );
+    assert_eq!(FilterError::new("bad").code(), FilterErrorCode::Custom);
+  }
+
+  #[test]
+  fn test_filter_error_char_span() {
+    let err = FilterError::new("bad").with_span(3..5);
+    assert_eq!(err.char_span("ab😀cd"), None);
+    let err = FilterError::new("bad").with_span(6..8);
+    assert_eq!(err.char_span("ab😀cd"), Some(3..5));
+    assert_eq!(FilterError::new("bad").char_span("abc"), None);
+  }
+
+  #[test]
+  fn test_filter_error_code_serde() {
+    let json = serde_json::to_string(&FilterErrorCode::NotANumber).unwrap();
+    assert_eq!(json, r#""not_a_number""#);
+    assert_eq!(FilterErrorCode::NotANumber.as_str(), "not_a_number");
+    let code: FilterErrorCode = serde_json::from_str(r#""invalid_utf8""#).unwrap();
+    assert_eq!(code, FilterErrorCode::InvalidUtf8);
+  }
+
   #[test]
   fn test_filter_error_eq() {
     let a = FilterError::new("bad").with_name("X");
@@ -144,6 +457,52 @@ mod tests {
     assert!(violation.message().contains("bad input"));
   }
 
+  #[cfg(feature = "validation")]
+  #[test]
+  fn test_filter_error_code_maps_violation_type() {
+    let err = FilterError::new("not a number").with_code(FilterErrorCode::NotANumber);
+    let violation: walrs_validation::Violation = err.into();
+    assert_eq!(
+      violation.violation_type(),
+      walrs_validation::ViolationType::TypeMismatch
+    );
+    let err = FilterError::new("unbound").with_code(FilterErrorCode::UnknownFilter);
+    let violation: walrs_validation::Violation = err.into();
+    assert_eq!(
+      violation.violation_type(),
+      walrs_validation::ViolationType::CustomError
+    );
+  }
+
+  #[cfg(feature = "validation")]
+  #[test]
+  fn test_filter_error_code_round_trips_through_violation() {
+    for code in FilterErrorCode::ALL {
+      let err = FilterError::new("failed (badly)")
+        .with_name("ToInt")
+        .with_span(1..3)
+        .with_step(2)
+        .with_code(code);
+      let message = err.to_string();
+      let violation: walrs_validation::Violation = err.into();
+      assert_eq!(FilterErrorCode::from_violation(&violation), Some(code));
+      assert_eq!(violation.message(), message);
+      assert_eq!(violation.code(), Some(code.as_str()));
+      assert_eq!(violation.path(), &[2]);
+      assert_eq!(violation.span(), Some(1..3));
+    }
+
+    // Only the structured code counts, never text in the message.
+    let violation = walrs_validation::Violation::new(
+      walrs_validation::ViolationType::CustomError,
+      "reserved (not_a_number)",
+    );
+    assert_eq!(FilterErrorCode::from_violation(&violation), None);
+    let violation: walrs_validation::Violation = FilterError::new("bad").into();
+    assert_eq!(violation.path(), &[] as &[usize]);
+    assert_eq!(violation.span(), None);
+  }
+
   #[cfg(feature = "validation")]
   #[test]
   fn test_filter_error_to_violations() {
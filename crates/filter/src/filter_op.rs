@@ -7,10 +7,27 @@
 use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
+use std::collections::HashSet;
 use std::fmt::{self, Debug};
 use std::sync::Arc;
 
-use crate::{Filter, SlugFilter, StripTagsFilter, XmlEntitiesFilter};
+#[cfg(feature = "validation")]
+use crate::FilenameOptions;
+#[cfg(feature = "validation")]
+use walrs_validation::Condition;
+
+use crate::encoding;
+#[cfg(feature = "validation")]
+use crate::filename::sanitize_filename;
+#[cfg(feature = "markdown")]
+use crate::markdown::{MarkdownOptions, markdown_to_html};
+use crate::mask::{default_mask_char, redact};
+use crate::slug::{default_slug_separator, deserialize_slug_separator, pretty_slug};
+use crate::truncate::truncate;
+use crate::{
+  Binding, CaseStyle, Filter, FilterError, NamedFilterFn, RedactDetector, StripTagsFilter,
+  TruncateUnit, WordMask, XmlEntitiesFilter, to_case,
+};
 
 /// RFC 3986 §2.3 "unreserved" character set: `ALPHA / DIGIT / "-" / "." / "_" / "~"`.
 ///
@@ -22,6 +39,10 @@ const RFC_3986_UNRESERVED: AsciiSet = NON_ALPHANUMERIC
   .remove(b'_')
   .remove(b'~');
 
+fn default_redact_detectors() -> Vec<RedactDetector> {
+  RedactDetector::ALL.to_vec()
+}
+
 /// Collapse runs of whitespace to a single ASCII space and trim leading/trailing
 /// whitespace. Returns `Cow::Borrowed` when the input is already normalized.
 ///
@@ -76,7 +97,7 @@ fn normalize_whitespace(value: &str) -> Cow<'_, str> {
 ///
 /// Prevents stack overflow when deeply nested `FilterOp::Chain(vec![FilterOp::Chain(…)])`
 /// values are applied.
-fn flatten_chain<T>(filters: &[FilterOp<T>]) -> Vec<&FilterOp<T>> {
+pub(crate) fn flatten_chain<T>(filters: &[FilterOp<T>]) -> Vec<&FilterOp<T>> {
   let mut flat = Vec::new();
   let mut stack: Vec<&FilterOp<T>> = filters.iter().rev().collect();
   while let Some(op) = stack.pop() {
@@ -89,6 +110,225 @@ fn flatten_chain<T>(filters: &[FilterOp<T>]) -> Vec<&FilterOp<T>> {
   flat
 }
 
+/// Tie-breaking/direction strategy used by [`FilterOp::Round`].
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_filter::{FilterOp, RoundingMode};
+///
+/// let half_even = FilterOp::<f64>::Round { places: 0, mode: RoundingMode::HalfEven };
+/// assert_eq!(half_even.apply(2.5), 2.0);
+///
+/// let half_away = FilterOp::<f64>::Round { places: 0, mode: RoundingMode::HalfAwayFromZero };
+/// assert_eq!(half_away.apply(2.5), 3.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RoundingMode {
+  /// Round to the nearest value; ties round away from zero (`2.5` → `3`, `-2.5` → `-3`).
+  #[default]
+  HalfAwayFromZero,
+
+  /// Round to the nearest value; ties round to the nearest even digit (`2.5` → `2`, `3.5` → `4`).
+  HalfEven,
+
+  /// Truncate toward zero (`2.7` → `2`, `-2.7` → `-2`).
+  TowardZero,
+
+  /// Round away from zero (`2.1` → `3`, `-2.1` → `-3`).
+  AwayFromZero,
+}
+
+/// Implements the per-type helpers backing the numeric variants (`Round`, `SnapToStep`, etc.) for floats.
+macro_rules! impl_float_numeric_ops {
+  ($($t:ty),*) => {
+    $(
+      impl FilterOp<$t> {
+        fn round_value(value: $t, places: u32, mode: RoundingMode) -> $t {
+          if !value.is_finite() {
+            return value;
+          }
+          let factor = (10.0 as $t).powi(i32::try_from(places).unwrap_or(i32::MAX));
+          let scaled = value * factor;
+          if !scaled.is_finite() {
+            return value;
+          }
+          let rounded = match mode {
+            RoundingMode::HalfAwayFromZero => scaled.round(),
+            RoundingMode::HalfEven => scaled.round_ties_even(),
+            RoundingMode::TowardZero => scaled.trunc(),
+            RoundingMode::AwayFromZero => {
+              if scaled < 0.0 {
+                scaled.floor()
+              } else {
+                scaled.ceil()
+              }
+            }
+          };
+          rounded / factor
+        }
+
+        fn snap_value(value: $t, step: $t, origin: $t) -> $t {
+          if !value.is_finite() || !step.is_finite() || !origin.is_finite() || step == 0.0 {
+            return value;
+          }
+          let step = step.abs();
+          // Ties snap upward (toward +∞), matching HTML `<input type="number" step>`.
+          let n = ((value - origin) / step + 0.5).floor();
+          origin + n * step
+        }
+
+        fn abs_value(value: $t) -> $t {
+          value.abs()
+        }
+
+        fn floor_value(value: $t) -> $t {
+          value.floor()
+        }
+
+        fn ceil_value(value: $t) -> $t {
+          value.ceil()
+        }
+
+        fn nan_to_value(value: $t, default: $t) -> $t {
+          if value.is_nan() { default } else { value }
+        }
+
+        fn sort_values(values: &mut [$t]) {
+          values.sort_unstable_by(<$t>::total_cmp);
+        }
+
+        fn dedup_values(values: &mut Vec<$t>) {
+          let mut seen = HashSet::with_capacity(values.len());
+          // `-0.0 == 0.0`, so both map to the same key; `NaN` is never a duplicate.
+          values.retain(|v| v.is_nan() || seen.insert(if *v == 0.0 { 0 } else { v.to_bits() }));
+        }
+      }
+    )*
+  };
+}
+
+impl_float_numeric_ops!(f32, f64);
+
+/// Implements the integer step-snapping helper for signed integer types.
+macro_rules! impl_signed_snap {
+  ($($t:ty),*) => {
+    $(
+      impl FilterOp<$t> {
+        fn snap_value(value: $t, step: $t, origin: $t) -> $t {
+          let step = step.unsigned_abs();
+          if step == 0 {
+            return value;
+          }
+          let above = value >= origin;
+          let dist = value.abs_diff(origin);
+          let rem = dist % step;
+          let lower = dist - rem;
+          // Ties snap upward (toward +∞): away from `origin` when above it, toward it when below.
+          let round_out = if above { rem >= step - rem } else { rem > step - rem };
+          let snap = |d| {
+            if above {
+              origin.checked_add_unsigned(d)
+            } else {
+              origin.checked_sub_unsigned(d)
+            }
+          };
+          if round_out {
+            if let Some(v) = lower.checked_add(step).and_then(snap) {
+              return v;
+            }
+          }
+          snap(lower).unwrap_or(value)
+        }
+
+        fn abs_value(value: $t) -> $t {
+          value.saturating_abs()
+        }
+      }
+    )*
+  };
+}
+
+impl_signed_snap!(i8, i16, i32, i64, i128, isize);
+
+/// Implements the integer step-snapping helper for unsigned integer types.
+macro_rules! impl_unsigned_snap {
+  ($($t:ty),*) => {
+    $(
+      impl FilterOp<$t> {
+        fn snap_value(value: $t, step: $t, origin: $t) -> $t {
+          if step == 0 {
+            return value;
+          }
+          let above = value >= origin;
+          let dist = value.abs_diff(origin);
+          let rem = dist % step;
+          let lower = dist - rem;
+          // Ties snap upward (toward +∞): away from `origin` when above it, toward it when below.
+          let round_out = if above { rem >= step - rem } else { rem > step - rem };
+          let snap = |d| {
+            if above {
+              origin.checked_add(d)
+            } else {
+              origin.checked_sub(d)
+            }
+          };
+          if round_out {
+            if let Some(v) = lower.checked_add(step).and_then(snap) {
+              return v;
+            }
+          }
+          snap(lower).unwrap_or(value)
+        }
+
+        fn abs_value(value: $t) -> $t {
+          value
+        }
+      }
+    )*
+  };
+}
+
+impl_unsigned_snap!(u8, u16, u32, u64, u128, usize);
+
+/// Implements the fractional/`NaN` operations as identities for integer types.
+macro_rules! impl_integer_numeric_ops {
+  ($($t:ty),*) => {
+    $(
+      impl FilterOp<$t> {
+        fn round_value(value: $t, _places: u32, _mode: RoundingMode) -> $t {
+          value
+        }
+
+        fn floor_value(value: $t) -> $t {
+          value
+        }
+
+        fn ceil_value(value: $t) -> $t {
+          value
+        }
+
+        fn nan_to_value(value: $t, _default: $t) -> $t {
+          value
+        }
+
+        fn sort_values(values: &mut [$t]) {
+          values.sort_unstable();
+        }
+
+        fn dedup_values(values: &mut Vec<$t>) {
+          let mut seen = HashSet::with_capacity(values.len());
+          values.retain(|v| seen.insert(*v));
+        }
+      }
+    )*
+  };
+}
+
+impl_integer_numeric_ops!(
+  i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
 /// A composable, serializable value transformer.
 ///
 /// `FilterOp` provides a way to define filter operations that can be serialized
@@ -147,27 +387,67 @@ pub enum FilterOp<T> {
   /// Convert to uppercase.
   Uppercase,
 
+  /// Convert to a case style (`camelCase`, `snake_case`, `kebab-case`, Title Case, …).
+  ///
+  /// See [`CaseStyle`] for word segmentation and acronym handling.
+  Case(CaseStyle),
+
   /// Remove HTML tags using Ammonia sanitizer.
   StripTags,
 
+  /// Render CommonMark as HTML, optionally with pipe tables and bare-URL autolinks
+  /// (see [`MarkdownOptions`]).
+  ///
+  /// The rendered HTML always goes through the same Ammonia sanitizer as
+  /// [`StripTags`](Self::StripTags), so raw HTML in the source, `javascript:` URLs,
+  /// and event-handler attributes never reach the output. Requires the `markdown`
+  /// feature.
+  #[cfg(feature = "markdown")]
+  MarkdownToHtml(MarkdownOptions),
+
   /// Encode special characters as XML/HTML entities. Existing named,
   /// decimal, and hex entity references in the input are preserved
   /// verbatim so repeated application does not double-encode.
   HtmlEntities,
 
-  /// Convert to URL-friendly slug.
+  /// Convert to URL-friendly slug, collapsing separator runs (as [`SlugFilter`](crate::SlugFilter)
+  /// with `allow_duplicate_dashes: false`).
   Slug {
-    /// Maximum length for the slug (None for unlimited).
+    /// Maximum length for the slug. Default (`None`): 200.
     max_length: Option<usize>,
+    /// Character placed between words. Alphanumeric separators are rejected when
+    /// deserializing; set in code, they fall back to `-`. Default: `-`.
+    #[serde(
+      default = "default_slug_separator",
+      deserialize_with = "deserialize_slug_separator"
+    )]
+    separator: char,
+    /// Words dropped from the slug, compared ASCII case-insensitively (kept when
+    /// every word is a stop word). Default: none.
+    #[serde(default)]
+    stop_words: Vec<String>,
   },
 
-  /// Truncate a string to at most `max_length` characters (Unicode scalar values).
+  /// Truncate a string to at most `max_length` units — characters (Unicode scalar
+  /// values) by default, or grapheme clusters or display columns per `unit`.
   ///
   /// Unlike [`Slug`](Self::Slug), `Truncate` does not alter the content of the string —
-  /// it simply clips it at a character boundary. Non-string types are passed through unchanged.
+  /// it simply clips it at a `unit` boundary, then appends `suffix` (e.g. `"…"`), which
+  /// counts toward `max_length`. Non-string types are passed through unchanged.
   Truncate {
-    /// Maximum number of Unicode scalar values to keep.
+    /// Maximum length of the result, suffix included, measured in `unit`s.
     max_length: usize,
+    /// What to count toward `max_length`. Default: [`TruncateUnit::Chars`].
+    #[serde(default)]
+    unit: TruncateUnit,
+    /// Appended when the value is clipped (e.g. `"…"`). Default: empty.
+    #[serde(default)]
+    suffix: String,
+    /// Clip at the start of the word the limit falls in (per Unicode word
+    /// boundaries, so each CJK ideograph is a word) and drop trailing whitespace.
+    /// A first word longer than the limit is still clipped. Default: `false`.
+    #[serde(default)]
+    break_at_word: bool,
   },
 
   /// Replace all occurrences of `from` with `to` in a string.
@@ -228,6 +508,44 @@ pub enum FilterOp<T> {
     set: String,
   },
 
+  /// Repair a user-supplied file name so it is safe on common filesystems and as a
+  /// storage key.
+  ///
+  /// Path separators, the other characters Windows forbids (`< > : " | ? *`), and
+  /// control characters (including NUL) are replaced with
+  /// [`FilenameOptions::replacement`]; the name is truncated to
+  /// [`max_bytes`](FilenameOptions::max_bytes) at a UTF-8 boundary (keeping the
+  /// extension if configured); trailing dots and spaces are removed; and Windows
+  /// device names (`CON`, `aux.txt`) are prefixed with the replacement (or `_`).
+  /// The result may be empty.
+  ///
+  /// To reject such names instead, use `walrs_validation::Rule::Filename`, which shares
+  /// these rules. Requires the `validation` feature.
+  #[cfg(feature = "validation")]
+  SanitizeFilename(FilenameOptions),
+
+  /// Mask deny-listed words (profanity, banned terms) by replacing each of their
+  /// characters with the mask character.
+  ///
+  /// The [`WordMask`] holds the word list compiled into a multi-pattern
+  /// (Aho–Corasick) automaton, built once when it is constructed or deserialized,
+  /// so large lists cost a single pass over the input. A word list too large for
+  /// the automaton is reported by [`WordMask::new`] (or as a deserialization
+  /// error). Overlapping matches are masked together; empty words are ignored.
+  Mask(WordMask),
+
+  /// Mask personally identifiable information found by built-in detectors
+  /// (email addresses, card numbers) by replacing each of its characters with
+  /// `mask_char`.
+  Redact {
+    /// Detectors to run. Default: all of [`RedactDetector::ALL`].
+    #[serde(default = "default_redact_detectors")]
+    detectors: Vec<RedactDetector>,
+    /// Character substituted for each masked character. Default: `*`.
+    #[serde(default = "default_mask_char")]
+    mask_char: char,
+  },
+
   /// Percent-encode the string.
   ///
   /// By default (`encode_unreserved: false`), conforms to RFC 3986 §2.3: ASCII
@@ -246,6 +564,36 @@ pub enum FilterOp<T> {
     encode_unreserved: bool,
   },
 
+  // ---- Encoding Filters ----
+  /// Base64-encode the string's UTF-8 bytes.
+  ///
+  /// Decode with [`TryFilterOp::Base64Decode`](crate::TryFilterOp::Base64Decode).
+  Base64Encode {
+    /// Use the URL- and filename-safe alphabet (`-`, `_`) instead of the standard one (`+`, `/`).
+    url_safe: bool,
+    /// Append `=` padding to a multiple of four characters.
+    padding: bool,
+  },
+
+  /// Hex-encode the string's UTF-8 bytes (two digits per byte).
+  ///
+  /// Decode with [`TryFilterOp::HexDecode`](crate::TryFilterOp::HexDecode).
+  HexEncode {
+    /// Emit `A`–`F` instead of `a`–`f`.
+    uppercase: bool,
+  },
+
+  /// Escape the string for embedding inside a JSON string literal (surrounding quotes
+  /// are not added): `"`, `\`, and control characters are backslash-escaped.
+  JsonEscape,
+
+  /// Escape the string for embedding inside a JavaScript string literal, including one
+  /// inside an inline `<script>` block.
+  ///
+  /// Escapes everything [`JsonEscape`](Self::JsonEscape) does, plus `'`, `` ` ``, `<`, `>`,
+  /// `&`, U+2028, and U+2029.
+  JsEscape,
+
   // ---- Numeric Filters ----
   /// Clamp value to a range (for numeric types).
   ///
@@ -262,11 +610,128 @@ pub enum FilterOp<T> {
     max: T,
   },
 
+  /// Round to `places` decimal places using the given [`RoundingMode`].
+  ///
+  /// Integer types have no fractional digits, so this is a no-op for them.
+  /// Non-finite floats (`NaN`, `±∞`) are passed through unchanged.
+  Round {
+    /// Number of decimal places to keep.
+    places: u32,
+    /// How to resolve the discarded digits.
+    #[serde(default)]
+    mode: RoundingMode,
+  },
+
+  /// Round down to the nearest integer (toward −∞). No-op for integer types.
+  Floor,
+
+  /// Round up to the nearest integer (toward +∞). No-op for integer types.
+  Ceil,
+
+  /// Absolute value.
+  ///
+  /// Signed integers saturate (`i32::MIN` → `i32::MAX`) instead of overflowing;
+  /// unsigned integers are passed through unchanged.
+  Abs,
+
+  /// Snap the value to the nearest `origin + n * step`, matching the values
+  /// accepted by `Rule::Step`. Ties snap upward (toward +∞).
+  ///
+  /// A zero `step` is a no-op. For integer types, when the nearest step lies
+  /// outside the type's range, the value snaps to the nearest in-range step instead.
+  SnapToStep {
+    /// Step size (its sign is ignored).
+    step: T,
+    /// Base value the steps are counted from (usually `0`).
+    origin: T,
+  },
+
+  /// Replace `NaN` with the given default. No-op for integer types and non-`NaN` floats.
+  NanTo(T),
+
+  // ---- Collection Filters ----
+  // Applied to a `Vec<T>` via `apply_all` (or to a raw string via
+  // `FilterOp::<String>::split_apply`). Applied to a single value with
+  // `apply`/`apply_ref` they return it unchanged (except `Each`, which applies its
+  // inner filter).
+  /// Split every string element on `separator`, flattening the pieces into the
+  /// collection (`["a,b", "c"]` → `["a", "b", "c"]`). An empty separator is a no-op.
+  Split {
+    /// Separator to split on.
+    separator: String,
+  },
+
+  /// Join all string elements with `separator` into a single element.
+  /// An empty collection stays empty.
+  Join {
+    /// Separator placed between elements.
+    separator: String,
+  },
+
+  /// Apply the inner filter to each element.
+  Each(Box<FilterOp<T>>),
+
+  /// Remove duplicate elements, keeping the first occurrence of each.
+  ///
+  /// `NaN` is never considered equal to anything, so every `NaN` is kept.
+  Dedup,
+
+  /// Sort elements in ascending order (floats use IEEE 754 total ordering).
+  Sort,
+
+  /// Remove empty strings. No-op for numeric collections.
+  RemoveEmpty,
+
+  /// Keep at most the first `n` elements.
+  Take(usize),
+
   // ---- Composite ----
   /// Apply filters sequentially: f3(f2(f1(value))).
   Chain(Vec<FilterOp<T>>),
 
+  /// Conditional filter: apply `then_filter` when `condition` holds for the
+  /// incoming value, otherwise `else_filter` (or pass the value through unchanged).
+  ///
+  /// Conditions are evaluated the same way as in `Rule::When` — for strings,
+  /// `IsEmpty`/`IsNotEmpty` ignore surrounding whitespace and `Matches` runs
+  /// the regex. Applied to a collection, the condition is evaluated per element.
+  ///
+  /// Requires the `validation` feature.
+  #[cfg(feature = "validation")]
+  When {
+    /// Condition to evaluate against the incoming value.
+    condition: Condition<T>,
+    /// Filter to apply if the condition holds.
+    then_filter: Box<FilterOp<T>>,
+    /// Filter to apply if the condition does not hold (optional).
+    else_filter: Option<Box<FilterOp<T>>>,
+  },
+
   // ---- Custom ----
+  /// A function registered in a [`FilterRegistry`](crate::FilterRegistry), referenced by
+  /// name so the pipeline stays serializable.
+  ///
+  /// Bind a deserialized pipeline with [`FilterRegistry::bind`](crate::FilterRegistry::bind),
+  /// which resolves each `Named` to its registered function and reports unknown names.
+  /// A bound `Named` still serializes as `{ name, args }`. Build one in code with
+  /// [`FilterOp::named`].
+  ///
+  /// The infallible `apply` methods cannot report an unbound step, so they pass the value
+  /// through it. Fallible callers ([`TryFilterOp::Infallible`](crate::TryFilterOp::Infallible),
+  /// `walrs_fieldfilter`'s `Field::sanitize`) check [`ensure_bound`](Self::ensure_bound)
+  /// first and fail with [`UnknownFilter`](crate::FilterErrorCode::UnknownFilter).
+  Named {
+    /// Name the function was registered under.
+    name: String,
+    /// Arguments passed to the function on every call (`null` when omitted).
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    args: serde_json::Value,
+    /// The registered function, set by `FilterRegistry::bind` (never serialized).
+    // An explicit `default` keeps serde from requiring `T: Default`.
+    #[serde(skip, default = "Binding::default")]
+    bound: Binding<NamedFilterFn<T>>,
+  },
+
   /// Custom filter function (not serializable).
   ///
   /// # Serde limitation
@@ -277,27 +742,153 @@ pub enum FilterOp<T> {
   ///   serde will encounter the un-serializable variant.
   /// - Deserialization will never produce a `Custom` variant.
   ///
-  /// If your filter pipeline must survive a serialization round-trip, avoid `Custom`
-  /// or add the custom logic as a post-deserialization step.
+  /// If your filter pipeline must survive a serialization round-trip, register the
+  /// function in a [`FilterRegistry`](crate::FilterRegistry) and reference it with
+  /// [`Named`](Self::Named) instead.
   #[serde(skip)]
   Custom(Arc<dyn Fn(T) -> T + Send + Sync>),
 }
 
+impl<T> FilterOp<T> {
+  /// The variant name, as used for the serialized `type` tag (`"Trim"`, `"Slug"`, …).
+  ///
+  /// ```rust
+  /// use walrs_filter::FilterOp;
+  ///
+  /// assert_eq!(FilterOp::<String>::Trim.name(), "Trim");
+  /// ```
+  pub fn name(&self) -> &'static str {
+    match self {
+      Self::Trim => "Trim",
+      Self::Lowercase => "Lowercase",
+      Self::Uppercase => "Uppercase",
+      Self::Case(_) => "Case",
+      Self::StripTags => "StripTags",
+      #[cfg(feature = "markdown")]
+      Self::MarkdownToHtml(_) => "MarkdownToHtml",
+      Self::HtmlEntities => "HtmlEntities",
+      Self::Slug { .. } => "Slug",
+      Self::Truncate { .. } => "Truncate",
+      Self::Replace { .. } => "Replace",
+      Self::Digits => "Digits",
+      Self::Alnum { .. } => "Alnum",
+      Self::Alpha { .. } => "Alpha",
+      Self::StripNewlines => "StripNewlines",
+      Self::NormalizeWhitespace => "NormalizeWhitespace",
+      Self::AllowChars { .. } => "AllowChars",
+      Self::DenyChars { .. } => "DenyChars",
+      #[cfg(feature = "validation")]
+      Self::SanitizeFilename(_) => "SanitizeFilename",
+      Self::Mask(_) => "Mask",
+      Self::Redact { .. } => "Redact",
+      Self::UrlEncode { .. } => "UrlEncode",
+      Self::Base64Encode { .. } => "Base64Encode",
+      Self::HexEncode { .. } => "HexEncode",
+      Self::JsonEscape => "JsonEscape",
+      Self::JsEscape => "JsEscape",
+      Self::Clamp { .. } => "Clamp",
+      Self::Round { .. } => "Round",
+      Self::Floor => "Floor",
+      Self::Ceil => "Ceil",
+      Self::Abs => "Abs",
+      Self::SnapToStep { .. } => "SnapToStep",
+      Self::NanTo(_) => "NanTo",
+      Self::Split { .. } => "Split",
+      Self::Join { .. } => "Join",
+      Self::Each(_) => "Each",
+      Self::Dedup => "Dedup",
+      Self::Sort => "Sort",
+      Self::RemoveEmpty => "RemoveEmpty",
+      Self::Take(_) => "Take",
+      Self::Chain(_) => "Chain",
+      #[cfg(feature = "validation")]
+      Self::When { .. } => "When",
+      Self::Named { .. } => "Named",
+      Self::Custom(_) => "Custom",
+    }
+  }
+
+  /// An unbound [`Named`](Self::Named) step; bind it with
+  /// [`FilterRegistry::bind`](crate::FilterRegistry::bind).
+  pub fn named(name: impl Into<String>, args: serde_json::Value) -> Self {
+    Self::Named {
+      name: name.into(),
+      args,
+      bound: Binding::default(),
+    }
+  }
+
+  /// Checks that every [`Named`](Self::Named) step (including inside `Chain`, `Each`, and
+  /// `When`) has been bound with [`FilterRegistry::bind`](crate::FilterRegistry::bind).
+  ///
+  /// # Errors
+  ///
+  /// Returns an [`UnknownFilter`](crate::FilterErrorCode::UnknownFilter) error naming the
+  /// first unbound step, with its position in nested chains.
+  ///
+  /// ```rust
+  /// use walrs_filter::{FilterErrorCode, FilterOp};
+  ///
+  /// let op = FilterOp::<String>::named("initials", serde_json::Value::Null);
+  /// assert_eq!(op.ensure_bound().unwrap_err().code(), FilterErrorCode::UnknownFilter);
+  /// assert!(FilterOp::<String>::Trim.ensure_bound().is_ok());
+  /// ```
+  pub fn ensure_bound(&self) -> Result<(), FilterError> {
+    match self {
+      Self::Named { name, bound, .. } if !bound.is_bound() => {
+        Err(crate::registry::unbound_error(name))
+      }
+      Self::Chain(ops) => ops
+        .iter()
+        .enumerate()
+        .try_for_each(|(i, op)| op.ensure_bound().map_err(|e| e.with_step(i))),
+      Self::Each(op) => op.ensure_bound(),
+      #[cfg(feature = "validation")]
+      Self::When {
+        then_filter,
+        else_filter,
+        ..
+      } => {
+        then_filter.ensure_bound()?;
+        else_filter.as_deref().map_or(Ok(()), Self::ensure_bound)
+      }
+      _ => Ok(()),
+    }
+  }
+}
+
 impl<T: Debug> Debug for FilterOp<T> {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
       Self::Trim => write!(f, "Trim"),
       Self::Lowercase => write!(f, "Lowercase"),
       Self::Uppercase => write!(f, "Uppercase"),
+      Self::Case(style) => f.debug_tuple("Case").field(style).finish(),
       Self::StripTags => write!(f, "StripTags"),
+      #[cfg(feature = "markdown")]
+      Self::MarkdownToHtml(options) => f.debug_tuple("MarkdownToHtml").field(options).finish(),
       Self::HtmlEntities => write!(f, "HtmlEntities"),
-      Self::Slug { max_length } => f
+      Self::Slug {
+        max_length,
+        separator,
+        stop_words,
+      } => f
         .debug_struct("Slug")
         .field("max_length", max_length)
+        .field("separator", separator)
+        .field("stop_words", stop_words)
         .finish(),
-      Self::Truncate { max_length } => f
+      Self::Truncate {
+        max_length,
+        unit,
+        suffix,
+        break_at_word,
+      } => f
         .debug_struct("Truncate")
         .field("max_length", max_length)
+        .field("unit", unit)
+        .field("suffix", suffix)
+        .field("break_at_word", break_at_word)
         .finish(),
       Self::Replace { from, to } => f
         .debug_struct("Replace")
@@ -321,12 +912,78 @@ impl<T: Debug> Debug for FilterOp<T> {
         .debug_struct("UrlEncode")
         .field("encode_unreserved", encode_unreserved)
         .finish(),
+      Self::Base64Encode { url_safe, padding } => f
+        .debug_struct("Base64Encode")
+        .field("url_safe", url_safe)
+        .field("padding", padding)
+        .finish(),
+      Self::HexEncode { uppercase } => f
+        .debug_struct("HexEncode")
+        .field("uppercase", uppercase)
+        .finish(),
+      Self::JsonEscape => write!(f, "JsonEscape"),
+      Self::JsEscape => write!(f, "JsEscape"),
+      #[cfg(feature = "validation")]
+      Self::SanitizeFilename(options) => f.debug_tuple("SanitizeFilename").field(options).finish(),
+      Self::Mask(mask) => f.debug_tuple("Mask").field(mask).finish(),
+      Self::Redact {
+        detectors,
+        mask_char,
+      } => f
+        .debug_struct("Redact")
+        .field("detectors", detectors)
+        .field("mask_char", mask_char)
+        .finish(),
       Self::Clamp { min, max } => f
         .debug_struct("Clamp")
         .field("min", min)
         .field("max", max)
         .finish(),
+      Self::Round { places, mode } => f
+        .debug_struct("Round")
+        .field("places", places)
+        .field("mode", mode)
+        .finish(),
+      Self::Floor => write!(f, "Floor"),
+      Self::Ceil => write!(f, "Ceil"),
+      Self::Abs => write!(f, "Abs"),
+      Self::SnapToStep { step, origin } => f
+        .debug_struct("SnapToStep")
+        .field("step", step)
+        .field("origin", origin)
+        .finish(),
+      Self::NanTo(default) => f.debug_tuple("NanTo").field(default).finish(),
+      Self::Split { separator } => f
+        .debug_struct("Split")
+        .field("separator", separator)
+        .finish(),
+      Self::Join { separator } => f
+        .debug_struct("Join")
+        .field("separator", separator)
+        .finish(),
+      Self::Each(filter) => f.debug_tuple("Each").field(filter).finish(),
+      Self::Dedup => write!(f, "Dedup"),
+      Self::Sort => write!(f, "Sort"),
+      Self::RemoveEmpty => write!(f, "RemoveEmpty"),
+      Self::Take(n) => f.debug_tuple("Take").field(n).finish(),
       Self::Chain(filters) => f.debug_tuple("Chain").field(filters).finish(),
+      #[cfg(feature = "validation")]
+      Self::When {
+        condition,
+        then_filter,
+        else_filter,
+      } => f
+        .debug_struct("When")
+        .field("condition", condition)
+        .field("then_filter", then_filter)
+        .field("else_filter", else_filter)
+        .finish(),
+      Self::Named { name, args, bound } => f
+        .debug_struct("Named")
+        .field("name", name)
+        .field("args", args)
+        .field("bound", &bound.is_bound())
+        .finish(),
       Self::Custom(_) => write!(f, "Custom(<fn>)"),
     }
   }
@@ -338,10 +995,37 @@ impl<T: PartialEq> PartialEq for FilterOp<T> {
       (Self::Trim, Self::Trim) => true,
       (Self::Lowercase, Self::Lowercase) => true,
       (Self::Uppercase, Self::Uppercase) => true,
+      (Self::Case(a), Self::Case(b)) => a == b,
       (Self::StripTags, Self::StripTags) => true,
+      #[cfg(feature = "markdown")]
+      (Self::MarkdownToHtml(a), Self::MarkdownToHtml(b)) => a == b,
       (Self::HtmlEntities, Self::HtmlEntities) => true,
-      (Self::Slug { max_length: a }, Self::Slug { max_length: b }) => a == b,
-      (Self::Truncate { max_length: a }, Self::Truncate { max_length: b }) => a == b,
+      (
+        Self::Slug {
+          max_length: ma,
+          separator: sa,
+          stop_words: wa,
+        },
+        Self::Slug {
+          max_length: mb,
+          separator: sb,
+          stop_words: wb,
+        },
+      ) => ma == mb && sa == sb && wa == wb,
+      (
+        Self::Truncate {
+          max_length: la,
+          unit: ua,
+          suffix: sa,
+          break_at_word: ba,
+        },
+        Self::Truncate {
+          max_length: lb,
+          unit: ub,
+          suffix: sb,
+          break_at_word: bb,
+        },
+      ) => la == lb && ua == ub && sa == sb && ba == bb,
       (Self::Replace { from: fa, to: ta }, Self::Replace { from: fb, to: tb }) => {
         fa == fb && ta == tb
       }
@@ -374,9 +1058,91 @@ impl<T: PartialEq> PartialEq for FilterOp<T> {
           encode_unreserved: b,
         },
       ) => a == b,
+      (
+        Self::Base64Encode {
+          url_safe: ua,
+          padding: pa,
+        },
+        Self::Base64Encode {
+          url_safe: ub,
+          padding: pb,
+        },
+      ) => ua == ub && pa == pb,
+      (Self::HexEncode { uppercase: a }, Self::HexEncode { uppercase: b }) => a == b,
+      (Self::JsonEscape, Self::JsonEscape) => true,
+      (Self::JsEscape, Self::JsEscape) => true,
+      #[cfg(feature = "validation")]
+      (Self::SanitizeFilename(a), Self::SanitizeFilename(b)) => a == b,
+      (Self::Mask(a), Self::Mask(b)) => a == b,
+      (
+        Self::Redact {
+          detectors: da,
+          mask_char: ca,
+        },
+        Self::Redact {
+          detectors: db,
+          mask_char: cb,
+        },
+      ) => da == db && ca == cb,
       (Self::Clamp { min: a1, max: a2 }, Self::Clamp { min: b1, max: b2 }) => a1 == b1 && a2 == b2,
+      (
+        Self::Round {
+          places: pa,
+          mode: ma,
+        },
+        Self::Round {
+          places: pb,
+          mode: mb,
+        },
+      ) => pa == pb && ma == mb,
+      (Self::Floor, Self::Floor) => true,
+      (Self::Ceil, Self::Ceil) => true,
+      (Self::Abs, Self::Abs) => true,
+      (
+        Self::SnapToStep {
+          step: sa,
+          origin: oa,
+        },
+        Self::SnapToStep {
+          step: sb,
+          origin: ob,
+        },
+      ) => sa == sb && oa == ob,
+      (Self::NanTo(a), Self::NanTo(b)) => a == b,
+      (Self::Split { separator: a }, Self::Split { separator: b }) => a == b,
+      (Self::Join { separator: a }, Self::Join { separator: b }) => a == b,
+      (Self::Each(a), Self::Each(b)) => a == b,
+      (Self::Dedup, Self::Dedup) => true,
+      (Self::Sort, Self::Sort) => true,
+      (Self::RemoveEmpty, Self::RemoveEmpty) => true,
+      (Self::Take(a), Self::Take(b)) => a == b,
       (Self::Chain(a), Self::Chain(b)) => a == b,
+      #[cfg(feature = "validation")]
+      (
+        Self::When {
+          condition: ca,
+          then_filter: ta,
+          else_filter: ea,
+        },
+        Self::When {
+          condition: cb,
+          then_filter: tb,
+          else_filter: eb,
+        },
+      ) => ca == cb && ta == tb && ea == eb,
       // Custom filters are never equal
+      (
+        Self::Named {
+          name: na,
+          args: aa,
+          bound: ba,
+        },
+        Self::Named {
+          name: nb,
+          args: ab,
+          bound: bb,
+        },
+      ) => na == nb && aa == ab && ba.is_bound() == bb.is_bound(),
       (Self::Custom(_), Self::Custom(_)) => false,
       _ => false,
     }
@@ -395,6 +1161,13 @@ impl FilterOp<String> {
   ///
   /// Prefer this method when you already have a `&str`, avoiding an
   /// unnecessary allocation at the call site.
+  ///
+  /// # Collection variants
+  ///
+  /// `Split`, `Join`, `Dedup`, `Sort`, `RemoveEmpty`, and `Take` need a list and
+  /// **return a single value unchanged** here, so `Split` does not split. Use
+  /// [`split_apply`](Self::split_apply) to turn a raw string into a list, or
+  /// [`apply_all`](Self::apply_all) for values that are already a list.
   pub fn apply_ref<'a>(&self, value: &'a str) -> Cow<'a, str> {
     match self {
       FilterOp::Trim => {
@@ -425,28 +1198,33 @@ impl FilterOp<String> {
           Cow::Owned(value.to_uppercase())
         }
       }
+      FilterOp::Case(style) => to_case(Cow::Borrowed(value), style),
       FilterOp::StripTags => {
         let filter = StripTagsFilter::new();
         filter.filter(Cow::Borrowed(value))
       }
+      #[cfg(feature = "markdown")]
+      FilterOp::MarkdownToHtml(options) => markdown_to_html(value, options),
       FilterOp::HtmlEntities => {
         let filter = XmlEntitiesFilter::new();
         filter.filter(Cow::Borrowed(value))
       }
-      FilterOp::Slug { max_length } => {
-        let filter = SlugFilter::new(max_length.unwrap_or(200), false);
-        filter.filter(Cow::Borrowed(value))
-      }
-      FilterOp::Truncate { max_length } => {
-        let mut char_count = 0;
-        for (idx, _) in value.char_indices() {
-          char_count += 1;
-          if char_count > *max_length {
-            return Cow::Owned(value[..idx].to_string());
-          }
-        }
-        Cow::Borrowed(value)
-      }
+      FilterOp::Slug {
+        max_length,
+        separator,
+        stop_words,
+      } => pretty_slug(
+        Cow::Borrowed(value),
+        max_length.unwrap_or(200),
+        *separator,
+        stop_words,
+      ),
+      FilterOp::Truncate {
+        max_length,
+        unit,
+        suffix,
+        break_at_word,
+      } => truncate(value, *max_length, *unit, suffix, *break_at_word),
       FilterOp::Replace { from, to } => {
         if from.is_empty() || !value.contains(from.as_str()) {
           Cow::Borrowed(value)
@@ -499,6 +1277,13 @@ impl FilterOp<String> {
           Cow::Owned(value.chars().filter(|c| !set.contains(*c)).collect())
         }
       }
+      #[cfg(feature = "validation")]
+      FilterOp::SanitizeFilename(options) => sanitize_filename(value, options),
+      FilterOp::Mask(mask) => mask.mask(value),
+      FilterOp::Redact {
+        detectors,
+        mask_char,
+      } => redact(value, detectors, *mask_char),
       FilterOp::UrlEncode { encode_unreserved } => {
         let set: &AsciiSet = if *encode_unreserved {
           NON_ALPHANUMERIC
@@ -508,7 +1293,38 @@ impl FilterOp<String> {
         // `PercentEncode` materialises as `Cow<str>` — `Borrowed` when nothing needed encoding.
         utf8_percent_encode(value, set).into()
       }
-      FilterOp::Clamp { .. } => Cow::Borrowed(value), // Clamp doesn't apply to strings
+      FilterOp::Base64Encode { url_safe, padding } => {
+        if value.is_empty() {
+          Cow::Borrowed(value)
+        } else {
+          Cow::Owned(encoding::base64_encode(value, *url_safe, *padding))
+        }
+      }
+      FilterOp::HexEncode { uppercase } => {
+        if value.is_empty() {
+          Cow::Borrowed(value)
+        } else {
+          Cow::Owned(encoding::hex_encode(value, *uppercase))
+        }
+      }
+      FilterOp::JsonEscape => encoding::json_escape(value),
+      FilterOp::JsEscape => encoding::js_escape(value),
+      // Numeric filters don't apply to strings
+      FilterOp::Clamp { .. }
+      | FilterOp::Round { .. }
+      | FilterOp::Floor
+      | FilterOp::Ceil
+      | FilterOp::Abs
+      | FilterOp::SnapToStep { .. }
+      | FilterOp::NanTo(_) => Cow::Borrowed(value),
+      FilterOp::Each(filter) => filter.apply_ref(value),
+      // Collection filters don't apply to a single value
+      FilterOp::Split { .. }
+      | FilterOp::Join { .. }
+      | FilterOp::Dedup
+      | FilterOp::Sort
+      | FilterOp::RemoveEmpty
+      | FilterOp::Take(_) => Cow::Borrowed(value),
       FilterOp::Chain(filters) => {
         let flat = flatten_chain(filters);
         if flat.is_empty() {
@@ -527,16 +1343,119 @@ impl FilterOp<String> {
         }
         Cow::Owned(result)
       }
+      #[cfg(feature = "validation")]
+      FilterOp::When {
+        condition,
+        then_filter,
+        else_filter,
+      } => {
+        if condition.evaluate_str(value) {
+          then_filter.apply_ref(value)
+        } else if let Some(else_filter) = else_filter {
+          else_filter.apply_ref(value)
+        } else {
+          Cow::Borrowed(value)
+        }
+      }
+      // Unbound steps pass through here; fallible callers check `ensure_bound` first.
+      FilterOp::Named { args, bound, .. } => match bound.get() {
+        Some(f) => Cow::Owned(f(value.to_string(), args)),
+        None => Cow::Borrowed(value),
+      },
       FilterOp::Custom(f) => Cow::Owned(f(value.to_string())),
     }
   }
 
   /// Apply the filter operation to an owned `String` value.
   ///
-  /// Convenience wrapper that delegates to [`apply_ref`](Self::apply_ref).
+  /// Convenience wrapper that delegates to [`apply_ref`](Self::apply_ref); collection
+  /// variants return the value unchanged (see [`split_apply`](Self::split_apply)).
   pub fn apply(&self, value: String) -> String {
     self.apply_ref(&value).into_owned()
   }
+
+  /// Apply the filter operation to a single raw string, producing a list.
+  ///
+  /// The `String` → `Vec<String>` entry point for pipelines that start with
+  /// [`Split`](FilterOp::Split): the value is treated as a one-element list and run
+  /// through [`apply_all`](Self::apply_all).
+  ///
+  /// ```rust
+  /// use walrs_filter::FilterOp;
+  ///
+  /// let tags = FilterOp::<String>::Chain(vec![
+  ///     FilterOp::Split { separator: ",".into() },
+  ///     FilterOp::Trim,
+  ///     FilterOp::RemoveEmpty,
+  /// ]);
+  /// assert_eq!(tags.split_apply("rust, web,"), ["rust", "web"]);
+  /// ```
+  pub fn split_apply(&self, value: &str) -> Vec<String> {
+    self.apply_all(vec![value.to_string()])
+  }
+
+  /// Apply the filter operation to a collection of values.
+  ///
+  /// Collection variants (`Split`, `Join`, `Dedup`, `Sort`, `RemoveEmpty`, `Take`)
+  /// operate on the collection as a whole; every other variant is applied to
+  /// each element. To split a single raw input, use [`split_apply`](Self::split_apply).
+  ///
+  /// ```rust
+  /// use walrs_filter::FilterOp;
+  ///
+  /// let tags = FilterOp::<String>::Chain(vec![
+  ///     FilterOp::Split { separator: ",".into() },
+  ///     FilterOp::Trim,
+  ///     FilterOp::RemoveEmpty,
+  ///     FilterOp::Dedup,
+  ///     FilterOp::Sort,
+  /// ]);
+  /// assert_eq!(tags.apply_all(vec!["rust, web,  rust,".into()]), ["rust", "web"]);
+  /// ```
+  pub fn apply_all(&self, values: Vec<String>) -> Vec<String> {
+    let mut values = values;
+    match self {
+      FilterOp::Split { separator } => {
+        if !separator.is_empty() {
+          values = values
+            .iter()
+            .flat_map(|v| v.split(separator.as_str()))
+            .map(str::to_string)
+            .collect();
+        }
+      }
+      FilterOp::Join { separator } => {
+        if !values.is_empty() {
+          values = vec![values.join(separator)];
+        }
+      }
+      FilterOp::Dedup => {
+        let mut seen = HashSet::with_capacity(values.len());
+        let keep: Vec<bool> = values.iter().map(|v| seen.insert(v.as_str())).collect();
+        let mut keep = keep.into_iter();
+        values.retain(|_| keep.next().unwrap_or(true));
+      }
+      FilterOp::Sort => values.sort_unstable(),
+      FilterOp::RemoveEmpty => values.retain(|v| !v.is_empty()),
+      FilterOp::Take(n) => values.truncate(*n),
+      FilterOp::Chain(filters) => {
+        let flat = flatten_chain(filters);
+        return flat.iter().fold(values, |vs, f| f.apply_all(vs));
+      }
+      FilterOp::Each(filter) => filter.apply_each(&mut values),
+      _ => self.apply_each(&mut values),
+    }
+    values
+  }
+
+  /// Applies `self` to every element, leaving unchanged elements untouched.
+  fn apply_each(&self, values: &mut [String]) {
+    for v in values {
+      if let Cow::Owned(s) = self.apply_ref(v) {
+        *v = s;
+      }
+    }
+  }
 }
 
 // ============================================================================
@@ -548,20 +1467,69 @@ macro_rules! impl_numeric_filter_op {
         $(
             impl FilterOp<$t> {
                 /// Apply the filter operation to a numeric value.
+                ///
+                /// String and collection variants (`Split`, `Dedup`, `Take`, …) return
+                /// the value unchanged; use [`apply_all`](Self::apply_all) for lists.
                 pub fn apply(&self, value: $t) -> $t {
                     match self {
                         FilterOp::Clamp { min, max } => {
                             value.clamp(*min, *max)
                         }
+                        FilterOp::Round { places, mode } => Self::round_value(value, *places, *mode),
+                        FilterOp::Floor => Self::floor_value(value),
+                        FilterOp::Ceil => Self::ceil_value(value),
+                        FilterOp::Abs => Self::abs_value(value),
+                        FilterOp::SnapToStep { step, origin } => Self::snap_value(value, *step, *origin),
+                        FilterOp::NanTo(default) => Self::nan_to_value(value, *default),
+                        FilterOp::Each(filter) => filter.apply(value),
                         FilterOp::Chain(filters) => {
                             let flat = flatten_chain(filters);
                             flat.iter().fold(value, |v, f| f.apply(v))
                         }
+                        #[cfg(feature = "validation")]
+                        FilterOp::When { condition, then_filter, else_filter } => {
+                            if condition.evaluate(&value) {
+                                then_filter.apply(value)
+                            } else if let Some(else_filter) = else_filter {
+                                else_filter.apply(value)
+                            } else {
+                                value
+                            }
+                        }
+                        FilterOp::Named { args, bound, .. } => match bound.get() {
+                            Some(f) => f(value, args),
+                            None => value,
+                        },
                         FilterOp::Custom(f) => f(value),
                         // String/other filters don't apply to numeric types
                         _ => value,
                     }
                 }
+
+                /// Apply the filter operation to a collection of numeric values.
+                ///
+                /// Collection variants operate on the collection as a whole; every
+                /// other variant is applied to each element. The string-only
+                /// collection variants `Split`, `Join`, and `RemoveEmpty` return the
+                /// list unchanged.
+                pub fn apply_all(&self, values: Vec<$t>) -> Vec<$t> {
+                    let mut values = values;
+                    match self {
+                        FilterOp::Dedup => Self::dedup_values(&mut values),
+                        FilterOp::Sort => Self::sort_values(&mut values),
+                        FilterOp::Take(n) => values.truncate(*n),
+                        FilterOp::Split { .. } | FilterOp::Join { .. } | FilterOp::RemoveEmpty => {}
+                        FilterOp::Chain(filters) => {
+                            let flat = flatten_chain(filters);
+                            return flat.iter().fold(values, |vs, f| f.apply_all(vs));
+                        }
+                        FilterOp::Each(filter) => {
+                            values.iter_mut().for_each(|v| *v = filter.apply(*v));
+                        }
+                        _ => values.iter_mut().for_each(|v| *v = self.apply(*v)),
+                    }
+                    values
+                }
             }
 
             impl crate::Filter<$t> for FilterOp<$t> {
@@ -570,28 +1538,256 @@ macro_rules! impl_numeric_filter_op {
                     self.apply(value)
                 }
             }
+
+            impl crate::Filter<Vec<$t>> for FilterOp<$t> {
+                type Output = Vec<$t>;
+                fn filter(&self, values: Vec<$t>) -> Vec<$t> {
+                    self.apply_all(values)
+                }
+            }
         )*
     };
 }
 
-impl_numeric_filter_op!(i32, i64, f32, f64, u32, u64, usize);
+impl_numeric_filter_op!(
+  i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
 
 // ============================================================================
-// Filter trait implementation for FilterOp<String>
+// Non-String, Non-Numeric FilterOp Implementations
 // ============================================================================
 
-impl crate::Filter<String> for FilterOp<String> {
-  type Output = String;
+/// Implements `apply` for value types that no built-in transform targets (`bool`,
+/// `char`, dates), so `Chain`, `Each`, `When`, `Named`, and `Custom` still compose.
+macro_rules! impl_passthrough_filter_op {
+    ($($t:ty),*) => {
+        $(
+            impl FilterOp<$t> {
+                /// Apply the filter operation to the value.
+                ///
+                /// Only the composite and custom variants act on this type; the string,
+                /// numeric, and collection variants return the value unchanged.
+                pub fn apply(&self, value: $t) -> $t {
+                    match self {
+                        FilterOp::Each(filter) => filter.apply(value),
+                        FilterOp::Chain(filters) => {
+                            let flat = flatten_chain(filters);
+                            flat.iter().fold(value, |v, f| f.apply(v))
+                        }
+                        #[cfg(feature = "validation")]
+                        FilterOp::When { condition, then_filter, else_filter } => {
+                            if condition.evaluate(&value) {
+                                then_filter.apply(value)
+                            } else if let Some(else_filter) = else_filter {
+                                else_filter.apply(value)
+                            } else {
+                                value
+                            }
+                        }
+                        FilterOp::Named { args, bound, .. } => match bound.get() {
+                            Some(f) => f(value, args),
+                            None => value,
+                        },
+                        FilterOp::Custom(f) => f(value),
+                        _ => value,
+                    }
+                }
+            }
 
-  fn filter(&self, value: String) -> String {
-    self.apply(value)
-  }
+            impl crate::Filter<$t> for FilterOp<$t> {
+                type Output = $t;
+                fn filter(&self, value: $t) -> $t {
+                    self.apply(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_passthrough_filter_op!(bool, char);
+
+#[cfg(feature = "chrono")]
+impl_passthrough_filter_op!(chrono::NaiveDate, chrono::NaiveDateTime);
+
+#[cfg(feature = "jiff")]
+impl_passthrough_filter_op!(jiff::civil::Date, jiff::civil::DateTime);
+
+// ============================================================================
+// Vec<String> FilterOp Implementation
+// ============================================================================
+
+impl FilterOp<Vec<String>> {
+  /// Apply the filter operation to a list of strings.
+  ///
+  /// String and collection variants behave as in [`FilterOp::<String>::apply_all`]:
+  /// `Split`, `Join`, `Dedup`, `Sort`, `RemoveEmpty`, and `Take` act on the list,
+  /// and string transforms (`Trim`, `Lowercase`, …) are applied to each element.
+  /// Numeric variants return the list unchanged; `Custom` receives the whole list.
+  ///
+  /// ```rust
+  /// use walrs_filter::FilterOp;
+  ///
+  /// let tags = FilterOp::<Vec<String>>::Chain(vec![
+  ///     FilterOp::Trim,
+  ///     FilterOp::Lowercase,
+  ///     FilterOp::RemoveEmpty,
+  ///     FilterOp::Dedup,
+  /// ]);
+  /// assert_eq!(
+  ///     tags.apply(vec![" Rust".into(), "".into(), "rust ".into(), "Web".into()]),
+  ///     ["rust", "web"]
+  /// );
+  /// ```
+  pub fn apply(&self, values: Vec<String>) -> Vec<String> {
+    match self {
+      FilterOp::Each(filter) => filter.apply(values),
+      FilterOp::Chain(filters) => {
+        let flat = flatten_chain(filters);
+        flat.iter().fold(values, |vs, f| f.apply(vs))
+      }
+      #[cfg(feature = "validation")]
+      FilterOp::When {
+        condition,
+        then_filter,
+        else_filter,
+      } => {
+        if condition.evaluate(&values) {
+          then_filter.apply(values)
+        } else if let Some(else_filter) = else_filter {
+          else_filter.apply(values)
+        } else {
+          values
+        }
+      }
+      FilterOp::Named { args, bound, .. } => match bound.get() {
+        Some(f) => f(values, args),
+        None => values,
+      },
+      FilterOp::Custom(f) => f(values),
+      other => match other.element_op() {
+        Some(op) => op.apply_all(values),
+        None => values,
+      },
+    }
+  }
+
+  /// The equivalent `FilterOp<String>` for variants that do not carry a `Vec<String>`
+  /// payload; `None` for the numeric, composite, and custom variants.
+  fn element_op(&self) -> Option<FilterOp<String>> {
+    Some(match self {
+      FilterOp::Trim => FilterOp::Trim,
+      FilterOp::Lowercase => FilterOp::Lowercase,
+      FilterOp::Uppercase => FilterOp::Uppercase,
+      FilterOp::Case(style) => FilterOp::Case(style.clone()),
+      FilterOp::StripTags => FilterOp::StripTags,
+      #[cfg(feature = "markdown")]
+      FilterOp::MarkdownToHtml(options) => FilterOp::MarkdownToHtml(options.clone()),
+      FilterOp::HtmlEntities => FilterOp::HtmlEntities,
+      FilterOp::Slug {
+        max_length,
+        separator,
+        stop_words,
+      } => FilterOp::Slug {
+        max_length: *max_length,
+        separator: *separator,
+        stop_words: stop_words.clone(),
+      },
+      FilterOp::Truncate {
+        max_length,
+        unit,
+        suffix,
+        break_at_word,
+      } => FilterOp::Truncate {
+        max_length: *max_length,
+        unit: *unit,
+        suffix: suffix.clone(),
+        break_at_word: *break_at_word,
+      },
+      FilterOp::Replace { from, to } => FilterOp::Replace {
+        from: from.clone(),
+        to: to.clone(),
+      },
+      FilterOp::Digits => FilterOp::Digits,
+      FilterOp::Alnum { allow_whitespace } => FilterOp::Alnum {
+        allow_whitespace: *allow_whitespace,
+      },
+      FilterOp::Alpha { allow_whitespace } => FilterOp::Alpha {
+        allow_whitespace: *allow_whitespace,
+      },
+      FilterOp::StripNewlines => FilterOp::StripNewlines,
+      FilterOp::NormalizeWhitespace => FilterOp::NormalizeWhitespace,
+      FilterOp::AllowChars { set } => FilterOp::AllowChars { set: set.clone() },
+      FilterOp::DenyChars { set } => FilterOp::DenyChars { set: set.clone() },
+      #[cfg(feature = "validation")]
+      FilterOp::SanitizeFilename(options) => FilterOp::SanitizeFilename(options.clone()),
+      FilterOp::Mask(mask) => FilterOp::Mask(mask.clone()),
+      FilterOp::Redact {
+        detectors,
+        mask_char,
+      } => FilterOp::Redact {
+        detectors: detectors.clone(),
+        mask_char: *mask_char,
+      },
+      FilterOp::UrlEncode { encode_unreserved } => FilterOp::UrlEncode {
+        encode_unreserved: *encode_unreserved,
+      },
+      FilterOp::Base64Encode { url_safe, padding } => FilterOp::Base64Encode {
+        url_safe: *url_safe,
+        padding: *padding,
+      },
+      FilterOp::HexEncode { uppercase } => FilterOp::HexEncode {
+        uppercase: *uppercase,
+      },
+      FilterOp::JsonEscape => FilterOp::JsonEscape,
+      FilterOp::JsEscape => FilterOp::JsEscape,
+      FilterOp::Split { separator } => FilterOp::Split {
+        separator: separator.clone(),
+      },
+      FilterOp::Join { separator } => FilterOp::Join {
+        separator: separator.clone(),
+      },
+      FilterOp::Dedup => FilterOp::Dedup,
+      FilterOp::Sort => FilterOp::Sort,
+      FilterOp::RemoveEmpty => FilterOp::RemoveEmpty,
+      FilterOp::Take(n) => FilterOp::Take(*n),
+      _ => return None,
+    })
+  }
+}
+
+impl crate::Filter<Vec<String>> for FilterOp<Vec<String>> {
+  type Output = Vec<String>;
+
+  fn filter(&self, values: Vec<String>) -> Vec<String> {
+    self.apply(values)
+  }
+}
+
+// ============================================================================
+// Filter trait implementation for FilterOp<String>
+// ============================================================================
+
+impl crate::Filter<String> for FilterOp<String> {
+  type Output = String;
+
+  fn filter(&self, value: String) -> String {
+    self.apply(value)
+  }
+}
+
+impl crate::Filter<Vec<String>> for FilterOp<String> {
+  type Output = Vec<String>;
+
+  fn filter(&self, values: Vec<String>) -> Vec<String> {
+    self.apply_all(values)
+  }
 }
 
 #[cfg(test)]
 #[allow(deprecated)]
 mod tests {
   use super::*;
+  use crate::MaskMode;
 
   #[test]
   fn test_trim_string() {
@@ -621,7 +1817,11 @@ mod tests {
 
   #[test]
   fn test_slug_string() {
-    let filter = FilterOp::<String>::Slug { max_length: None };
+    let filter = FilterOp::<String>::Slug {
+      max_length: None,
+      separator: '-',
+      stop_words: Vec::new(),
+    };
     assert_eq!(filter.apply("Hello World!".to_string()), "hello-world");
   }
 
@@ -650,6 +1850,8 @@ mod tests {
   fn test_filter_serialization() {
     let filter = FilterOp::<String>::Slug {
       max_length: Some(50),
+      separator: '-',
+      stop_words: Vec::new(),
     };
     let json = serde_json::to_string(&filter).unwrap();
     assert!(json.contains("Slug"));
@@ -712,7 +1914,11 @@ mod tests {
 
   #[test]
   fn test_slug_string_apply_ref() {
-    let filter = FilterOp::<String>::Slug { max_length: None };
+    let filter = FilterOp::<String>::Slug {
+      max_length: None,
+      separator: '-',
+      stop_words: Vec::new(),
+    };
     assert_eq!(filter.apply_ref("Hello World!"), "hello-world");
   }
 
@@ -784,7 +1990,11 @@ mod tests {
 
   #[test]
   fn test_slug_noop_returns_borrowed() {
-    let filter = FilterOp::<String>::Slug { max_length: None };
+    let filter = FilterOp::<String>::Slug {
+      max_length: None,
+      separator: '-',
+      stop_words: Vec::new(),
+    };
     // Already a valid slug
     let result = filter.apply_ref("hello-world");
     assert_eq!(result, "hello-world");
@@ -839,7 +2049,12 @@ mod tests {
 
   #[test]
   fn test_truncate_string_shorter_than_max() {
-    let filter = FilterOp::<String>::Truncate { max_length: 10 };
+    let filter = FilterOp::<String>::Truncate {
+      max_length: 10,
+      unit: TruncateUnit::Chars,
+      suffix: String::new(),
+      break_at_word: false,
+    };
     let result = filter.apply_ref("short");
     assert!(matches!(result, Cow::Borrowed(_)));
     assert_eq!(result, "short");
@@ -847,7 +2062,12 @@ mod tests {
 
   #[test]
   fn test_truncate_string_exceeds_max() {
-    let filter = FilterOp::<String>::Truncate { max_length: 5 };
+    let filter = FilterOp::<String>::Truncate {
+      max_length: 5,
+      unit: TruncateUnit::Chars,
+      suffix: String::new(),
+      break_at_word: false,
+    };
     let result = filter.apply_ref("Hello World");
     assert!(matches!(result, Cow::Owned(_)));
     assert_eq!(result, "Hello");
@@ -856,7 +2076,12 @@ mod tests {
   #[test]
   fn test_truncate_string_unicode() {
     // "café" has 4 Unicode scalar values
-    let filter = FilterOp::<String>::Truncate { max_length: 3 };
+    let filter = FilterOp::<String>::Truncate {
+      max_length: 3,
+      unit: TruncateUnit::Chars,
+      suffix: String::new(),
+      break_at_word: false,
+    };
     let result = filter.apply("café".to_string());
     assert_eq!(result, "caf");
   }
@@ -973,6 +2198,8 @@ mod tests {
     // "hello-world-this-is-a-long-title" is 32 chars
     let filter = FilterOp::<String>::Slug {
       max_length: Some(11),
+      separator: '-',
+      stop_words: Vec::new(),
     };
     let result = filter.apply("Hello World This Is A Long Title".to_string());
     assert!(
@@ -994,10 +2221,33 @@ mod tests {
     assert_eq!(op, deserialized);
   }
 
+  #[test]
+  fn test_slug_separator_and_stop_words() {
+    let op: FilterOp<String> = serde_json::from_str(
+      r#"{"type":"Slug","config":{"max_length":null,"separator":"_","stop_words":["the"]}}"#,
+    )
+    .unwrap();
+    assert_eq!(op.apply_ref("The Art of War"), "art_of_war");
+    assert_eq!(op.compile().apply_ref("The Art of War"), "art_of_war");
+
+    // Older configs without the new fields keep the defaults.
+    let op: FilterOp<String> =
+      serde_json::from_str(r#"{"type":"Slug","config":{"max_length":20}}"#).unwrap();
+    assert_eq!(op.apply_ref("The Art"), "the-art");
+
+    let err = serde_json::from_str::<FilterOp<String>>(
+      r#"{"type":"Slug","config":{"max_length":null,"separator":"x"}}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("invalid slug separator"));
+  }
+
   #[test]
   fn test_serde_roundtrip_slug() {
     let op = FilterOp::<String>::Slug {
       max_length: Some(50),
+      separator: '-',
+      stop_words: Vec::new(),
     };
     let json = serde_json::to_string(&op).unwrap();
     let deserialized: FilterOp<String> = serde_json::from_str(&json).unwrap();
@@ -1014,12 +2264,41 @@ mod tests {
 
   #[test]
   fn test_serde_roundtrip_truncate() {
-    let op = FilterOp::<String>::Truncate { max_length: 20 };
+    let op = FilterOp::<String>::Truncate {
+      max_length: 20,
+      unit: TruncateUnit::Chars,
+      suffix: String::new(),
+      break_at_word: false,
+    };
     let json = serde_json::to_string(&op).unwrap();
     let deserialized: FilterOp<String> = serde_json::from_str(&json).unwrap();
     assert_eq!(op, deserialized);
   }
 
+  #[test]
+  fn test_serde_truncate_options() {
+    // Configs written before the options existed still deserialize.
+    let op: FilterOp<String> =
+      serde_json::from_str(r#"{"type":"Truncate","config":{"max_length":20}}"#).unwrap();
+    assert_eq!(
+      op,
+      FilterOp::Truncate {
+        max_length: 20,
+        unit: TruncateUnit::Chars,
+        suffix: String::new(),
+        break_at_word: false,
+      }
+    );
+
+    let op: FilterOp<String> = serde_json::from_str(
+      r#"{"type":"Truncate","config":{"max_length":12,"unit":"Graphemes","suffix":"…","break_at_word":true}}"#,
+    )
+    .unwrap();
+    assert_eq!(op.apply_ref("Grapheme-aware 👩‍💻 truncation"), "Grapheme-…");
+    let json = serde_json::to_string(&op).unwrap();
+    assert_eq!(serde_json::from_str::<FilterOp<String>>(&json).unwrap(), op);
+  }
+
   #[test]
   fn test_serde_roundtrip_replace() {
     let op = FilterOp::<String>::Replace {
@@ -1469,6 +2748,112 @@ mod tests {
     assert_eq!(filter.apply("a&b=c".to_string()), "a%26b%3Dc");
   }
 
+  #[cfg(feature = "validation")]
+  #[test]
+  fn test_sanitize_filename() {
+    let filter = FilterOp::<String>::SanitizeFilename(FilenameOptions::default());
+    assert_eq!(
+      filter.apply("../secret:plan?.txt".to_string()),
+      ".._secret_plan_.txt"
+    );
+    assert!(matches!(filter.apply_ref("notes.md"), Cow::Borrowed(_)));
+  }
+
+  #[cfg(feature = "validation")]
+  #[test]
+  fn test_serde_sanitize_filename() {
+    let op: FilterOp<String> =
+      serde_json::from_str(r#"{"type":"SanitizeFilename","config":{"max_bytes":8}}"#).unwrap();
+    assert_eq!(
+      op,
+      FilterOp::SanitizeFilename(FilenameOptions {
+        max_bytes: 8,
+        ..Default::default()
+      })
+    );
+    let json = serde_json::to_string(&op).unwrap();
+    let deserialized: FilterOp<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(op, deserialized);
+  }
+
+  #[cfg(feature = "markdown")]
+  #[test]
+  fn test_markdown_to_html() {
+    let op: FilterOp<String> =
+      serde_json::from_str(r#"{"type":"MarkdownToHtml","config":{"tables":true}}"#).unwrap();
+    assert_eq!(
+      op,
+      FilterOp::MarkdownToHtml(MarkdownOptions {
+        tables: true,
+        autolinks: false,
+      })
+    );
+    assert_eq!(op.name(), "MarkdownToHtml");
+    assert_eq!(
+      op.apply("*hi* <script>x</script>".to_string()),
+      "<p><em>hi</em> </p>\n"
+    );
+    let json = serde_json::to_string(&op).unwrap();
+    let deserialized: FilterOp<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(op, deserialized);
+  }
+
+  #[test]
+  fn test_mask() {
+    let filter = FilterOp::<String>::Mask(
+      WordMask::new(
+        vec!["darn".into(), "heck".into()],
+        MaskMode::WholeWord,
+        '*',
+        true,
+      )
+      .unwrap(),
+    );
+    assert_eq!(
+      filter.apply("Darn it, what the HECK; darned!".to_string()),
+      "**** it, what the ****; darned!"
+    );
+    assert!(matches!(filter.apply_ref("all good"), Cow::Borrowed(_)));
+  }
+
+  #[test]
+  fn test_redact() {
+    let filter = FilterOp::<String>::Redact {
+      detectors: vec![RedactDetector::Email],
+      mask_char: 'x',
+    };
+    assert_eq!(
+      filter.apply("ping me@ex.io, 4111111111111111".to_string()),
+      "ping xxxxxxxx, 4111111111111111"
+    );
+  }
+
+  #[test]
+  fn test_serde_mask_and_redact_defaults() {
+    let op: FilterOp<String> =
+      serde_json::from_str(r#"{"type":"Mask","config":{"words":["darn"]}}"#).unwrap();
+    assert_eq!(
+      op,
+      FilterOp::Mask(WordMask::new(vec!["darn".into()], MaskMode::WholeWord, '*', false).unwrap())
+    );
+    let json = serde_json::to_string(&op).unwrap();
+    assert_eq!(serde_json::from_str::<FilterOp<String>>(&json).unwrap(), op);
+
+    let op: FilterOp<String> = serde_json::from_str(r#"{"type":"Redact","config":{}}"#).unwrap();
+    assert_eq!(
+      op,
+      FilterOp::Redact {
+        detectors: RedactDetector::ALL.to_vec(),
+        mask_char: '*',
+      }
+    );
+    let op: FilterOp<String> = serde_json::from_str(
+      r##"{"type":"Mask","config":{"words":["a"],"mode":"Substring","mask_char":"#","case_insensitive":true}}"##,
+    )
+    .unwrap();
+    assert_eq!(op.apply_ref("bAnana"), "b#n#n#");
+  }
+
   #[test]
   fn test_serde_roundtrip_url_encode() {
     let op = FilterOp::<String>::UrlEncode {
@@ -1537,4 +2922,700 @@ mod tests {
     assert!(url.contains("UrlEncode"));
     assert!(url.contains("encode_unreserved"));
   }
+
+  // ====================================================================
+  // Numeric filter tests — Round, Floor, Ceil, Abs, SnapToStep, NanTo
+  // ====================================================================
+
+  #[test]
+  fn test_clamp_small_and_wide_integer_types() {
+    assert_eq!(FilterOp::<i8>::Clamp { min: -10, max: 10 }.apply(100), 10);
+    assert_eq!(FilterOp::<u8>::Clamp { min: 1, max: 9 }.apply(0), 1);
+    assert_eq!(FilterOp::<i16>::Clamp { min: 0, max: 5 }.apply(-3), 0);
+    assert_eq!(FilterOp::<u16>::Clamp { min: 0, max: 5 }.apply(7), 5);
+    assert_eq!(FilterOp::<i128>::Clamp { min: 0, max: 5 }.apply(7), 5);
+    assert_eq!(FilterOp::<u128>::Clamp { min: 2, max: 5 }.apply(1), 2);
+    assert_eq!(FilterOp::<isize>::Clamp { min: -1, max: 1 }.apply(-5), -1);
+  }
+
+  #[test]
+  fn test_round_f64_places() {
+    let op = FilterOp::<f64>::Round {
+      places: 2,
+      mode: RoundingMode::HalfAwayFromZero,
+    };
+    assert_eq!(op.apply(19.996), 20.0);
+    assert_eq!(op.apply(1.234_9), 1.23);
+    assert_eq!(op.apply(-2.675_1), -2.68);
+    assert_eq!(op.apply(1.0), 1.0);
+  }
+
+  #[test]
+  fn test_round_modes() {
+    let round = |mode, v: f64| FilterOp::<f64>::Round { places: 0, mode }.apply(v);
+    assert_eq!(round(RoundingMode::HalfAwayFromZero, 2.5), 3.0);
+    assert_eq!(round(RoundingMode::HalfAwayFromZero, -2.5), -3.0);
+    assert_eq!(round(RoundingMode::HalfEven, 2.5), 2.0);
+    assert_eq!(round(RoundingMode::HalfEven, 3.5), 4.0);
+    assert_eq!(round(RoundingMode::TowardZero, 2.7), 2.0);
+    assert_eq!(round(RoundingMode::TowardZero, -2.7), -2.0);
+    assert_eq!(round(RoundingMode::AwayFromZero, 2.1), 3.0);
+    assert_eq!(round(RoundingMode::AwayFromZero, -2.1), -3.0);
+  }
+
+  #[test]
+  fn test_round_f32_and_non_finite_passthrough() {
+    let op = FilterOp::<f32>::Round {
+      places: 1,
+      mode: RoundingMode::HalfAwayFromZero,
+    };
+    assert_eq!(op.apply(1.26_f32), 1.3_f32);
+    assert!(op.apply(f32::NAN).is_nan());
+    assert_eq!(op.apply(f32::INFINITY), f32::INFINITY);
+  }
+
+  #[test]
+  fn test_round_large_places_does_not_overflow() {
+    let op = FilterOp::<f64>::Round {
+      places: 400,
+      mode: RoundingMode::HalfEven,
+    };
+    assert_eq!(op.apply(1.5), 1.5);
+  }
+
+  #[test]
+  fn test_round_is_noop_on_integers() {
+    let op = FilterOp::<i64>::Round {
+      places: 2,
+      mode: RoundingMode::HalfEven,
+    };
+    assert_eq!(op.apply(42), 42);
+  }
+
+  #[test]
+  fn test_floor_and_ceil() {
+    assert_eq!(FilterOp::<f64>::Floor.apply(2.7), 2.0);
+    assert_eq!(FilterOp::<f64>::Floor.apply(-2.1), -3.0);
+    assert_eq!(FilterOp::<f32>::Ceil.apply(2.1), 3.0);
+    assert_eq!(FilterOp::<f32>::Ceil.apply(-2.7), -2.0);
+    assert_eq!(FilterOp::<u8>::Floor.apply(7), 7);
+    assert_eq!(FilterOp::<i16>::Ceil.apply(-7), -7);
+  }
+
+  #[test]
+  fn test_abs() {
+    assert_eq!(FilterOp::<f64>::Abs.apply(-1.5), 1.5);
+    assert_eq!(FilterOp::<i32>::Abs.apply(-5), 5);
+    assert_eq!(FilterOp::<u32>::Abs.apply(5), 5);
+  }
+
+  #[test]
+  fn test_abs_signed_min_saturates() {
+    assert_eq!(FilterOp::<i8>::Abs.apply(i8::MIN), i8::MAX);
+    assert_eq!(FilterOp::<i128>::Abs.apply(i128::MIN), i128::MAX);
+  }
+
+  #[test]
+  fn test_snap_to_step_integers() {
+    let op = FilterOp::<i32>::SnapToStep { step: 5, origin: 0 };
+    assert_eq!(op.apply(12), 10);
+    assert_eq!(op.apply(13), 15);
+    assert_eq!(op.apply(-12), -10);
+    assert_eq!(op.apply(-13), -15);
+    assert_eq!(op.apply(15), 15);
+  }
+
+  #[test]
+  fn test_snap_to_step_ties_snap_upward() {
+    let op = FilterOp::<i32>::SnapToStep {
+      step: 10,
+      origin: 0,
+    };
+    assert_eq!(op.apply(5), 10);
+    assert_eq!(op.apply(-5), 0);
+    let op = FilterOp::<f64>::SnapToStep {
+      step: 1.0,
+      origin: 0.0,
+    };
+    assert_eq!(op.apply(0.5), 1.0);
+    assert_eq!(op.apply(-0.5), 0.0);
+  }
+
+  #[test]
+  fn test_snap_to_step_with_origin() {
+    let op = FilterOp::<u32>::SnapToStep {
+      step: 10,
+      origin: 3,
+    };
+    assert_eq!(op.apply(14), 13);
+    assert_eq!(op.apply(19), 23);
+    assert_eq!(op.apply(0), 3);
+    let op = FilterOp::<f64>::SnapToStep {
+      step: 0.25,
+      origin: 0.1,
+    };
+    assert!((op.apply(0.4) - 0.35).abs() < 1e-12);
+  }
+
+  #[test]
+  fn test_snap_to_step_negative_step_uses_magnitude() {
+    let op = FilterOp::<i64>::SnapToStep {
+      step: -5,
+      origin: 0,
+    };
+    assert_eq!(op.apply(12), 10);
+  }
+
+  #[test]
+  fn test_snap_to_step_zero_step_is_noop() {
+    assert_eq!(
+      FilterOp::<i32>::SnapToStep { step: 0, origin: 0 }.apply(7),
+      7
+    );
+    assert_eq!(
+      FilterOp::<f64>::SnapToStep {
+        step: 0.0,
+        origin: 0.0
+      }
+      .apply(7.3),
+      7.3
+    );
+  }
+
+  #[test]
+  fn test_snap_to_step_stays_in_range() {
+    // Nearest step (260) exceeds u8::MAX — snap to the in-range neighbour instead.
+    let op = FilterOp::<u8>::SnapToStep {
+      step: 20,
+      origin: 0,
+    };
+    assert_eq!(op.apply(255), 240);
+    let op = FilterOp::<i8>::SnapToStep {
+      step: 100,
+      origin: 0,
+    };
+    assert_eq!(op.apply(i8::MIN), -100);
+    assert_eq!(op.apply(i8::MAX), 100);
+    // Distances wider than the signed type itself.
+    let op = FilterOp::<i8>::SnapToStep {
+      step: 50,
+      origin: -100,
+    };
+    assert_eq!(op.apply(120), 100);
+  }
+
+  #[test]
+  fn test_nan_to() {
+    let op = FilterOp::<f64>::NanTo(0.0);
+    assert_eq!(op.apply(f64::NAN), 0.0);
+    assert_eq!(op.apply(1.5), 1.5);
+    assert_eq!(FilterOp::<f32>::NanTo(-1.0).apply(f32::NAN), -1.0);
+    assert_eq!(FilterOp::<i32>::NanTo(0).apply(5), 5);
+  }
+
+  #[test]
+  fn test_numeric_variants_noop_on_string() {
+    for op in [
+      FilterOp::<String>::Round {
+        places: 2,
+        mode: RoundingMode::HalfEven,
+      },
+      FilterOp::Floor,
+      FilterOp::Ceil,
+      FilterOp::Abs,
+      FilterOp::NanTo("x".to_string()),
+    ] {
+      let result = op.apply_ref("-1.55");
+      assert!(matches!(result, Cow::Borrowed(_)));
+      assert_eq!(result, "-1.55");
+    }
+  }
+
+  #[test]
+  fn test_chain_numeric_normalization() {
+    let op: FilterOp<f64> = FilterOp::Chain(vec![
+      FilterOp::NanTo(0.0),
+      FilterOp::Abs,
+      FilterOp::SnapToStep {
+        step: 0.5,
+        origin: 0.0,
+      },
+      FilterOp::Clamp {
+        min: 0.0,
+        max: 10.0,
+      },
+    ]);
+    assert_eq!(op.apply(f64::NAN), 0.0);
+    assert_eq!(op.apply(-3.3), 3.5);
+    assert_eq!(op.apply(42.0), 10.0);
+  }
+
+  #[test]
+  fn test_serde_roundtrip_numeric_variants() {
+    let ops: Vec<FilterOp<f64>> = vec![
+      FilterOp::Round {
+        places: 2,
+        mode: RoundingMode::HalfEven,
+      },
+      FilterOp::Floor,
+      FilterOp::Ceil,
+      FilterOp::Abs,
+      FilterOp::SnapToStep {
+        step: 0.5,
+        origin: 0.0,
+      },
+      FilterOp::NanTo(1.0),
+    ];
+    for op in ops {
+      let json = serde_json::to_string(&op).unwrap();
+      let deserialized: FilterOp<f64> = serde_json::from_str(&json).unwrap();
+      assert_eq!(op, deserialized);
+    }
+  }
+
+  #[test]
+  fn test_round_mode_defaults_when_omitted() {
+    let op: FilterOp<f64> =
+      serde_json::from_str(r#"{"type":"Round","config":{"places":1}}"#).unwrap();
+    assert_eq!(
+      op,
+      FilterOp::Round {
+        places: 1,
+        mode: RoundingMode::HalfAwayFromZero
+      }
+    );
+  }
+
+  #[test]
+  fn test_debug_format_numeric_variants() {
+    let round = format!(
+      "{:?}",
+      FilterOp::<f64>::Round {
+        places: 2,
+        mode: RoundingMode::HalfEven
+      }
+    );
+    assert!(round.contains("Round"));
+    assert!(round.contains("HalfEven"));
+    assert_eq!(format!("{:?}", FilterOp::<f64>::Floor), "Floor");
+    assert_eq!(format!("{:?}", FilterOp::<f64>::NanTo(0.0)), "NanTo(0.0)");
+    let snap = format!("{:?}", FilterOp::<i32>::SnapToStep { step: 5, origin: 0 });
+    assert!(snap.contains("SnapToStep"));
+    assert!(snap.contains("origin"));
+  }
+
+  // ====================================================================
+  // Case tests
+  // ====================================================================
+
+  #[test]
+  fn test_case_string() {
+    let filter = FilterOp::<String>::Case(CaseStyle::Snake);
+    assert_eq!(
+      filter.apply("  userAccountID ".to_string()),
+      "user_account_id"
+    );
+    let result = filter.apply_ref("already_snake");
+    assert!(matches!(result, Cow::Borrowed(_)));
+  }
+
+  #[test]
+  fn test_case_noop_on_numeric() {
+    assert_eq!(FilterOp::<i32>::Case(CaseStyle::Kebab).apply(7), 7);
+  }
+
+  #[test]
+  fn test_serde_roundtrip_case() {
+    let op = FilterOp::<String>::Case(CaseStyle::Title {
+      locale: Some("en".to_string()),
+    });
+    let json = serde_json::to_string(&op).unwrap();
+    assert_eq!(
+      json,
+      r#"{"type":"Case","config":{"Title":{"locale":"en"}}}"#
+    );
+    let deserialized: FilterOp<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(op, deserialized);
+
+    let op: FilterOp<String> = serde_json::from_str(r#"{"type":"Case","config":"Kebab"}"#).unwrap();
+    assert_eq!(op, FilterOp::Case(CaseStyle::Kebab));
+  }
+
+  // ====================================================================
+  // Encoding filter tests
+  // ====================================================================
+
+  #[test]
+  fn test_base64_encode_string() {
+    let op = FilterOp::<String>::Base64Encode {
+      url_safe: false,
+      padding: true,
+    };
+    assert_eq!(op.apply("hello".to_string()), "aGVsbG8=");
+    let op = FilterOp::<String>::Base64Encode {
+      url_safe: true,
+      padding: false,
+    };
+    assert_eq!(op.apply("??>".to_string()), "Pz8-");
+    assert!(matches!(op.apply_ref(""), Cow::Borrowed(_)));
+  }
+
+  #[test]
+  fn test_hex_encode_string() {
+    let op = FilterOp::<String>::HexEncode { uppercase: true };
+    assert_eq!(op.apply("é!".to_string()), "C3A921");
+  }
+
+  #[test]
+  fn test_json_and_js_escape_string() {
+    assert_eq!(
+      FilterOp::<String>::JsonEscape.apply("a\"b\n".to_string()),
+      "a\\\"b\\n"
+    );
+    assert_eq!(
+      FilterOp::<String>::JsEscape.apply("</script>".to_string()),
+      "\\u003C/script\\u003E"
+    );
+    assert!(matches!(
+      FilterOp::<String>::JsonEscape.apply_ref("clean"),
+      Cow::Borrowed(_)
+    ));
+  }
+
+  #[test]
+  fn test_serde_roundtrip_encoding_variants() {
+    for op in [
+      FilterOp::<String>::Base64Encode {
+        url_safe: true,
+        padding: false,
+      },
+      FilterOp::HexEncode { uppercase: false },
+      FilterOp::JsonEscape,
+      FilterOp::JsEscape,
+    ] {
+      let json = serde_json::to_string(&op).unwrap();
+      let deserialized: FilterOp<String> = serde_json::from_str(&json).unwrap();
+      assert_eq!(op, deserialized);
+    }
+  }
+
+  // ---- Collection filters ----
+
+  fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+  }
+
+  #[test]
+  fn test_split_and_clean_tags() {
+    let op = FilterOp::<String>::Chain(vec![
+      FilterOp::Split {
+        separator: ",".into(),
+      },
+      FilterOp::Trim,
+      FilterOp::RemoveEmpty,
+      FilterOp::Dedup,
+      FilterOp::Sort,
+    ]);
+    assert_eq!(
+      op.apply_all(strings(&["web, rust,  , rust", "async"])),
+      ["async", "rust", "web"]
+    );
+  }
+
+  #[test]
+  fn test_split_empty_separator_is_noop() {
+    let op = FilterOp::<String>::Split {
+      separator: String::new(),
+    };
+    assert_eq!(op.apply_all(strings(&["a,b"])), ["a,b"]);
+  }
+
+  #[test]
+  fn test_join() {
+    let op = FilterOp::<String>::Join {
+      separator: ", ".into(),
+    };
+    assert_eq!(op.apply_all(strings(&["a", "b", "c"])), ["a, b, c"]);
+    assert!(op.apply_all(Vec::new()).is_empty());
+  }
+
+  #[test]
+  fn test_dedup_keeps_first_occurrence() {
+    let op = FilterOp::<String>::Dedup;
+    assert_eq!(
+      op.apply_all(strings(&["b", "a", "b", "c", "a"])),
+      ["b", "a", "c"]
+    );
+  }
+
+  #[test]
+  fn test_take() {
+    let op = FilterOp::<String>::Take(2);
+    assert_eq!(op.apply_all(strings(&["a", "b", "c"])), ["a", "b"]);
+    assert_eq!(op.apply_all(strings(&["a"])), ["a"]);
+  }
+
+  #[test]
+  fn test_split_apply_and_scalar_collection_ops() {
+    let op = FilterOp::<String>::Chain(vec![
+      FilterOp::Split {
+        separator: ";".into(),
+      },
+      FilterOp::Trim,
+      FilterOp::Take(2),
+    ]);
+    assert_eq!(op.split_apply(" a ; b ; c"), ["a", "b"]);
+    assert_eq!(op.split_apply(""), [""]);
+    // Applied to a single value, collection variants leave it unchanged.
+    let split = FilterOp::<String>::Split {
+      separator: ";".into(),
+    };
+    assert_eq!(split.apply("a;b".into()), "a;b");
+  }
+
+  #[test]
+  fn test_each_and_scalar_ops_map_over_elements() {
+    let each = FilterOp::<String>::Each(Box::new(FilterOp::Uppercase));
+    assert_eq!(each.apply_all(strings(&["a", "b"])), ["A", "B"]);
+    assert_eq!(
+      FilterOp::<String>::Trim.apply_all(strings(&[" a ", "b"])),
+      ["a", "b"]
+    );
+    // `Each` applied to a single value applies its inner filter.
+    assert_eq!(each.apply("x".to_string()), "X");
+  }
+
+  #[test]
+  fn test_collection_ops_are_noops_on_single_string() {
+    for op in [
+      FilterOp::<String>::Split {
+        separator: ",".into(),
+      },
+      FilterOp::Join {
+        separator: ",".into(),
+      },
+      FilterOp::Dedup,
+      FilterOp::Sort,
+      FilterOp::RemoveEmpty,
+      FilterOp::Take(0),
+    ] {
+      assert!(matches!(op.apply_ref("b,a"), Cow::Borrowed("b,a")));
+    }
+  }
+
+  #[test]
+  fn test_numeric_collection_ops() {
+    let op = FilterOp::<i32>::Chain(vec![
+      FilterOp::Clamp { min: 0, max: 10 },
+      FilterOp::Dedup,
+      FilterOp::Sort,
+      FilterOp::Take(3),
+    ]);
+    assert_eq!(op.apply_all(vec![12, 3, -1, 3, 7, 0, 5]), [0, 3, 5]);
+    assert_eq!(
+      FilterOp::<u8>::Each(Box::new(FilterOp::Clamp { min: 1, max: 2 })).apply_all(vec![0, 9]),
+      [1, 2]
+    );
+  }
+
+  #[test]
+  fn test_passthrough_types() {
+    let op = FilterOp::<bool>::Chain(vec![
+      FilterOp::Trim,
+      FilterOp::Custom(Arc::new(|b: bool| !b)),
+    ]);
+    assert!(op.apply(false));
+    assert_eq!(FilterOp::<char>::Uppercase.apply('a'), 'a');
+    assert_eq!(
+      FilterOp::<char>::Custom(Arc::new(|c: char| c.to_ascii_uppercase())).apply('a'),
+      'A'
+    );
+  }
+
+  #[test]
+  fn test_vec_string_ops() {
+    let op = FilterOp::<Vec<String>>::Chain(vec![
+      FilterOp::Split {
+        separator: ",".into(),
+      },
+      FilterOp::Trim,
+      FilterOp::RemoveEmpty,
+      FilterOp::Clamp {
+        min: vec![],
+        max: vec![],
+      },
+      FilterOp::Custom(Arc::new(|mut vs: Vec<String>| {
+        vs.reverse();
+        vs
+      })),
+      FilterOp::Take(2),
+    ]);
+    assert_eq!(op.apply(strings(&["a, b,", " c"])), ["c", "b"]);
+    assert_eq!(
+      FilterOp::<Vec<String>>::Join {
+        separator: "-".into()
+      }
+      .apply(strings(&["a", "b"])),
+      ["a-b"]
+    );
+  }
+
+  #[test]
+  fn test_float_collection_ops() {
+    let sorted = FilterOp::<f64>::Sort.apply_all(vec![2.5, -1.0, f64::NAN, 0.0]);
+    assert_eq!(&sorted[..3], [-1.0, 0.0, 2.5]);
+    assert!(sorted[3].is_nan());
+
+    let deduped = FilterOp::<f32>::Dedup.apply_all(vec![0.0, -0.0, 1.5, f32::NAN, 1.5, f32::NAN]);
+    assert_eq!(deduped.len(), 4);
+    assert_eq!(&deduped[..2], [0.0, 1.5]);
+    assert!(deduped[2..].iter().all(|v| v.is_nan()));
+  }
+
+  #[test]
+  fn test_filter_trait_for_vec() {
+    let op = FilterOp::<String>::Sort;
+    assert_eq!(
+      Filter::<Vec<String>>::filter(&op, strings(&["b", "a"])),
+      ["a", "b"]
+    );
+    assert_eq!(
+      Filter::<Vec<i64>>::filter(&FilterOp::Take(1), vec![4, 5]),
+      [4]
+    );
+  }
+
+  #[test]
+  fn test_serde_roundtrip_collection_variants() {
+    let op = FilterOp::<String>::Chain(vec![
+      FilterOp::Split {
+        separator: ",".into(),
+      },
+      FilterOp::Each(Box::new(FilterOp::Trim)),
+      FilterOp::RemoveEmpty,
+      FilterOp::Dedup,
+      FilterOp::Sort,
+      FilterOp::Take(10),
+      FilterOp::Join {
+        separator: ",".into(),
+      },
+    ]);
+    let json = serde_json::to_string(&op).unwrap();
+    let deserialized: FilterOp<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(op, deserialized);
+
+    let parsed: FilterOp<String> =
+      serde_json::from_str(r#"{"type":"Each","config":{"type":"Lowercase"}}"#).unwrap();
+    assert_eq!(parsed, FilterOp::Each(Box::new(FilterOp::Lowercase)));
+  }
+
+  // ---- Conditional filters ----
+
+  #[cfg(feature = "validation")]
+  fn pattern(p: &str) -> walrs_validation::CompiledPattern {
+    walrs_validation::CompiledPattern::try_from(p).unwrap()
+  }
+
+  #[cfg(feature = "validation")]
+  #[test]
+  fn test_when_then_else_string() {
+    let op = FilterOp::<String>::Chain(vec![
+      FilterOp::Trim,
+      FilterOp::When {
+        condition: Condition::Matches(pattern(r"^\+")),
+        then_filter: Box::new(FilterOp::AllowChars {
+          set: "+0123456789".into(),
+        }),
+        else_filter: Some(Box::new(FilterOp::Digits)),
+      },
+    ]);
+    assert_eq!(op.apply(" +1 (555) 010-9999 ".to_string()), "+15550109999");
+    assert_eq!(op.apply("(555) 010-9999".to_string()), "5550109999");
+  }
+
+  #[cfg(feature = "validation")]
+  #[test]
+  fn test_when_without_else_passes_through() {
+    let op = FilterOp::<String>::When {
+      condition: Condition::Matches(pattern("^.{6,}$")),
+      then_filter: Box::new(FilterOp::Truncate {
+        max_length: 5,
+        unit: TruncateUnit::Chars,
+        suffix: String::new(),
+        break_at_word: false,
+      }),
+      else_filter: None,
+    };
+    assert_eq!(op.apply("abcdefgh".to_string()), "abcde");
+    assert!(matches!(op.apply_ref("abc"), Cow::Borrowed("abc")));
+
+    // `IsEmpty` follows `Rule::When` semantics: whitespace-only counts as empty.
+    let fallback = FilterOp::<String>::When {
+      condition: Condition::IsEmpty,
+      then_filter: Box::new(FilterOp::Replace {
+        from: " ".into(),
+        to: "-".into(),
+      }),
+      else_filter: None,
+    };
+    assert_eq!(fallback.apply("  ".to_string()), "--");
+    assert_eq!(fallback.apply("a b".to_string()), "a b");
+  }
+
+  #[cfg(feature = "validation")]
+  #[test]
+  fn test_when_numeric() {
+    let op = FilterOp::<i32>::When {
+      condition: Condition::GreaterThan(100),
+      then_filter: Box::new(FilterOp::SnapToStep {
+        step: 50,
+        origin: 0,
+      }),
+      else_filter: Some(Box::new(FilterOp::Abs)),
+    };
+    assert_eq!(op.apply(124), 100);
+    assert_eq!(op.apply(-7), 7);
+    assert_eq!(op.apply_all(vec![126, -3]), [150, 3]);
+  }
+
+  #[cfg(feature = "validation")]
+  #[test]
+  fn test_when_per_element_in_collection() {
+    let op = FilterOp::<String>::When {
+      condition: Condition::Equals("n/a".into()),
+      then_filter: Box::new(FilterOp::Replace {
+        from: "n/a".into(),
+        to: String::new(),
+      }),
+      else_filter: None,
+    };
+    let values = vec!["a".to_string(), "n/a".to_string()];
+    assert_eq!(op.apply_all(values), ["a", ""]);
+  }
+
+  #[cfg(feature = "validation")]
+  #[test]
+  fn test_serde_roundtrip_when() {
+    let op = FilterOp::<String>::When {
+      condition: Condition::Matches(pattern(r"^\+")),
+      then_filter: Box::new(FilterOp::Digits),
+      else_filter: Some(Box::new(FilterOp::Chain(vec![FilterOp::Trim]))),
+    };
+    let json = serde_json::to_string(&op).unwrap();
+    let deserialized: FilterOp<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(op, deserialized);
+
+    let parsed: FilterOp<String> = serde_json::from_str(
+      r#"{"type":"When","config":{"condition":{"type":"isnotempty"},"then_filter":{"type":"Trim"}}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+      parsed,
+      FilterOp::When {
+        condition: Condition::IsNotEmpty,
+        then_filter: Box::new(FilterOp::Trim),
+        else_filter: None,
+      }
+    );
+  }
 }
@@ -8,7 +8,9 @@
 //!
 //! ## Available Filters
 //!
-//! - [`SlugFilter`] - Converts strings to URL-friendly slugs
+//! - [`CaseFilter`] - Converts strings between case styles (`camelCase`, `snake_case`, Title Case, …)
+//! - [`SlugFilter`] - Converts strings to URL-friendly slugs (custom separators, stop words)
+//! - [`SlugGenerator`] - Generates unique slugs (`-2`, `-3`, …) via an existence check
 //! - [`StripTagsFilter`] - Removes/sanitizes HTML tags using Ammonia
 //! - [`XmlEntitiesFilter`] - Encodes special characters as XML entities
 //!
@@ -16,10 +18,25 @@
 //!
 //! The [`FilterOp`] enum provides a composable, serializable way to define
 //! filter operations for config-driven form processing. In addition to
-//! string/numeric transforms like `Trim`, `Lowercase`, and `Clamp`, it
+//! string transforms like `Trim`, `Lowercase`, and `Case`, and numeric transforms like
+//! `Clamp`, `Round`, `SnapToStep`, and `NanTo` (for every primitive numeric type), it
 //! exposes a suite of sanitize variants: `Digits`, `Alnum`, `Alpha`,
-//! `StripNewlines`, `NormalizeWhitespace`, `AllowChars`, `DenyChars`, and
-//! `UrlEncode`.
+//! `StripNewlines`, `NormalizeWhitespace`, `AllowChars`, `DenyChars`,
+//! `SanitizeFilename` (with the `validation` feature), and `UrlEncode`. With the
+//! `markdown` feature, `MarkdownToHtml`
+//! renders CommonMark to sanitized HTML. `Mask` and `Redact` mask deny-listed words and
+//! detected PII (emails, card numbers). Collection variants (`Split`, `Join`,
+//! `Each`, `Dedup`, `Sort`, `RemoveEmpty`, `Take`) clean multi-value inputs via `FilterOp::apply_all`, and
+//! `When` (with the `validation` feature) applies filters conditionally.
+//! `Named` steps refer to functions in a [`FilterRegistry`] so custom logic stays
+//! serializable; [`FilterRegistry::bind`] resolves them after loading.
+//!
+//! [`FilterOp::compile`] precompiles a string pipeline into a [`CompiledFilter`],
+//! fusing adjacent char-level variants into a single pass over the input.
+//! [`FilterOp::apply_stream`] and [`FilterWriter`] apply a string pipeline
+//! chunk-by-chunk over `io::Read`/`io::Write` for inputs too large to buffer.
+//! [`FilterOp::apply_traced`] returns a serializable [`FilterTrace`] recording
+//! which step changed the value and how, for auditing.
 //!
 //! ## TryFilterOp Enum
 //!
@@ -29,7 +46,12 @@
 //! as [`FilterError`], which can be converted to
 //! [`Violation`](walrs_validation::Violation) for integration with the
 //! validation error pipeline. Built-in fallible variants include `ToBool`,
-//! `ToInt`, `ToFloat`, and `UrlDecode`.
+//! `ToInt`, `ToFloat`, `UrlDecode`, `HtmlEntityDecode`, `Base64Decode`,
+//! `HexDecode`, `CanonicalizeUrl`, `NormalizeEmail`, and (with the `phone`
+//! feature) `NormalizePhone`. With the `chrono` or `jiff` feature, `NormalizeDate`
+//! converts dates between [`DateFormat`](walrs_validation::DateFormat)s. With the
+//! `async` feature, `TryCustomAsync` runs async fallible filters through
+//! [`TryFilterOp::try_apply_async`].
 //!
 //! ## Example
 //!
@@ -74,18 +96,52 @@
 #[macro_use]
 extern crate derive_builder;
 
+pub mod case;
+pub mod compiled;
+#[cfg(any(feature = "chrono", feature = "jiff"))]
+pub mod date;
+pub mod email;
+pub(crate) mod encoding;
+#[cfg(feature = "validation")]
+pub mod filename;
 pub mod filter_error;
 pub mod filter_op;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+pub mod mask;
+#[cfg(feature = "phone")]
+pub(crate) mod phone;
+pub mod registry;
 pub mod slug;
+pub mod stream;
 pub mod strip_tags;
+pub mod trace;
 pub mod traits;
+pub mod truncate;
 pub mod try_filter_op;
+pub mod url_canonical;
+pub(crate) mod words;
 pub mod xml_entities;
 
+pub use case::*;
+pub use compiled::*;
+#[cfg(any(feature = "chrono", feature = "jiff"))]
+pub use date::*;
+pub use email::*;
+#[cfg(feature = "validation")]
+pub use filename::*;
 pub use filter_error::*;
 pub use filter_op::*;
+#[cfg(feature = "markdown")]
+pub use markdown::*;
+pub use mask::*;
+pub use registry::*;
 pub use slug::*;
+pub use stream::*;
 pub use strip_tags::*;
+pub use trace::*;
 pub use traits::*;
+pub use truncate::*;
 pub use try_filter_op::*;
+pub use url_canonical::*;
 pub use xml_entities::*;
@@ -0,0 +1,332 @@
+//! Markdown rendering backing [`FilterOp::MarkdownToHtml`](crate::FilterOp::MarkdownToHtml).
+
+use pulldown_cmark::{
+  CowStr, Event, LinkType, Options, Parser, Tag, TagEnd, TextMergeStream, html,
+};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use crate::{Filter, StripTagsFilter};
+
+/// Characters dropped from the end of a bare URL, so `see https://example.com.`
+/// does not link the full stop.
+const TRAILING_PUNCTUATION: &[char] = &['.', ',', ':', ';', '!', '?', '"', '\'', '*', '_', '~'];
+
+/// Options for [`FilterOp::MarkdownToHtml`](crate::FilterOp::MarkdownToHtml).
+///
+/// Missing fields take their [`Default`] values when deserializing; the default is
+/// plain CommonMark.
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_filter::{FilterOp, MarkdownOptions};
+///
+/// let op = FilterOp::<String>::MarkdownToHtml(MarkdownOptions {
+///     autolinks: true,
+///     ..Default::default()
+/// });
+///
+/// assert_eq!(
+///     op.apply_ref("Read **https://example.com**"),
+///     "<p>Read <strong><a href=\"https://example.com\" rel=\"noopener noreferrer\">https://example.com</a></strong></p>\n"
+/// );
+/// // Raw HTML is sanitized, never passed through.
+/// assert_eq!(op.apply_ref("<script>alert(1)</script>"), "");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MarkdownOptions {
+  /// Render GitHub-style pipe tables. Default: `false`.
+  pub tables: bool,
+
+  /// Turn bare `http://`, `https://`, and `www.` URLs in text into links. Default: `false`.
+  pub autolinks: bool,
+}
+
+/// Renders `value` as CommonMark and sanitizes the resulting HTML with the default
+/// [`StripTagsFilter`] (Ammonia) policy.
+///
+/// Sanitizing always runs, so raw HTML in the source, `javascript:` links, and event
+/// handler attributes are removed regardless of `options`.
+pub(crate) fn markdown_to_html<'a>(value: &str, options: &MarkdownOptions) -> Cow<'a, str> {
+  let mut parser_options = Options::empty();
+  if options.tables {
+    parser_options.insert(Options::ENABLE_TABLES);
+  }
+  let events = TextMergeStream::new(Parser::new_ext(value, parser_options));
+
+  let mut rendered = String::with_capacity(value.len() + value.len() / 2);
+  if options.autolinks {
+    html::push_html(&mut rendered, Autolinks::new(events));
+  } else {
+    html::push_html(&mut rendered, events);
+  }
+
+  StripTagsFilter::new().filter(Cow::Owned(rendered))
+}
+
+/// Event adapter that wraps bare URLs found in text events in autolinks.
+///
+/// Text inside links, images, and code blocks is passed through untouched.
+struct Autolinks<'a, I> {
+  inner: I,
+  pending: std::collections::VecDeque<Event<'a>>,
+  /// Nesting depth of links, images, and code blocks.
+  suppress: usize,
+}
+
+impl<'a, I: Iterator<Item = Event<'a>>> Autolinks<'a, I> {
+  fn new(inner: I) -> Self {
+    Self {
+      inner,
+      pending: std::collections::VecDeque::new(),
+      suppress: 0,
+    }
+  }
+
+  fn split_text(&mut self, text: CowStr<'a>) -> Event<'a> {
+    let mut last = 0;
+    for (start, end) in find_urls(&text) {
+      if start > last {
+        self
+          .pending
+          .push_back(Event::Text(text[last..start].to_string().into()));
+      }
+      let url = &text[start..end];
+      let dest_url = if url.len() >= 4 && url[..4].eq_ignore_ascii_case("www.") {
+        format!("http://{}", url)
+      } else {
+        url.to_string()
+      };
+      self.pending.push_back(Event::Start(Tag::Link {
+        link_type: LinkType::Autolink,
+        dest_url: dest_url.into(),
+        title: CowStr::Borrowed(""),
+        id: CowStr::Borrowed(""),
+      }));
+      self.pending.push_back(Event::Text(url.to_string().into()));
+      self.pending.push_back(Event::End(TagEnd::Link));
+      last = end;
+    }
+    if last == 0 {
+      return Event::Text(text);
+    }
+    if last < text.len() {
+      self
+        .pending
+        .push_back(Event::Text(text[last..].to_string().into()));
+    }
+    self
+      .pending
+      .pop_front()
+      .expect("at least one url was found")
+  }
+}
+
+impl<'a, I: Iterator<Item = Event<'a>>> Iterator for Autolinks<'a, I> {
+  type Item = Event<'a>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if let Some(event) = self.pending.pop_front() {
+      return Some(event);
+    }
+    let event = self.inner.next()?;
+    match &event {
+      Event::Start(Tag::Link { .. } | Tag::Image { .. } | Tag::CodeBlock(_)) => {
+        self.suppress += 1;
+      }
+      Event::End(TagEnd::Link | TagEnd::Image | TagEnd::CodeBlock) => {
+        self.suppress = self.suppress.saturating_sub(1);
+      }
+      _ => {}
+    }
+    match event {
+      Event::Text(text) if self.suppress == 0 => Some(self.split_text(text)),
+      other => Some(other),
+    }
+  }
+}
+
+/// Returns the byte ranges of bare URLs in `text`.
+///
+/// A URL starts with `http://`, `https://`, or `www.` at the start of the text or after
+/// whitespace or an opening bracket/quote, and runs to the next whitespace or `<`.
+/// Trailing punctuation and unbalanced closing parentheses are excluded.
+fn find_urls(text: &str) -> Vec<(usize, usize)> {
+  let mut urls = Vec::new();
+  let mut prev: Option<char> = None;
+  let mut skip_to = 0;
+
+  for (start, c) in text.char_indices() {
+    let at_boundary =
+      matches!(prev, None | Some('(' | '[' | '"' | '\'')) || prev.is_some_and(char::is_whitespace);
+    prev = Some(c);
+    if start < skip_to || !at_boundary || !matches!(c, 'h' | 'H' | 'w' | 'W') {
+      continue;
+    }
+    let rest = &text[start..];
+    let Some(prefix_len) = ["https://", "http://", "www."]
+      .iter()
+      // Compare bytes: the prefix length need not fall on a char boundary of `rest`.
+      .find(|prefix| {
+        rest
+          .as_bytes()
+          .get(..prefix.len())
+          .is_some_and(|b| b.eq_ignore_ascii_case(prefix.as_bytes()))
+      })
+      .map(|prefix| prefix.len())
+    else {
+      continue;
+    };
+
+    let mut end = rest
+      .find(|c: char| c.is_whitespace() || c == '<')
+      .unwrap_or(rest.len());
+    loop {
+      let url = &rest[..end];
+      let unbalanced_paren =
+        url.ends_with(')') && url.matches(')').count() > url.matches('(').count();
+      if url.ends_with(TRAILING_PUNCTUATION) || unbalanced_paren {
+        end -= 1;
+      } else {
+        break;
+      }
+    }
+    if end > prefix_len {
+      urls.push((start, start + end));
+      skip_to = start + end;
+    }
+  }
+  urls
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn render(value: &str, options: &MarkdownOptions) -> String {
+    markdown_to_html(value, options).into_owned()
+  }
+
+  #[test]
+  fn test_commonmark() {
+    let options = MarkdownOptions::default();
+    assert_eq!(render("", &options), "");
+    assert_eq!(
+      render("# Title\n\nSome *emphasis* and `code`.", &options),
+      "<h1>Title</h1>\n<p>Some <em>emphasis</em> and <code>code</code>.</p>\n"
+    );
+    assert_eq!(
+      render("[docs](https://example.com)", &options),
+      "<p><a href=\"https://example.com\" rel=\"noopener noreferrer\">docs</a></p>\n"
+    );
+  }
+
+  #[test]
+  fn test_sanitizes_output() {
+    let options = MarkdownOptions {
+      tables: true,
+      autolinks: true,
+    };
+    assert_eq!(render("<script>alert(1)</script>", &options), "");
+    assert_eq!(
+      render("hi <img src=x onerror=alert(1)>", &options),
+      "<p>hi <img src=\"x\"></p>\n"
+    );
+    assert_eq!(
+      render("[click](javascript:alert(1))", &options),
+      "<p><a rel=\"noopener noreferrer\">click</a></p>\n"
+    );
+  }
+
+  #[test]
+  fn test_tables() {
+    let source = "| a | b |\n|---|---|\n| 1 | 2 |";
+    assert!(!render(source, &MarkdownOptions::default()).contains("<table>"));
+
+    let html = render(
+      source,
+      &MarkdownOptions {
+        tables: true,
+        ..Default::default()
+      },
+    );
+    assert!(html.starts_with("<table><thead><tr><th>a</th><th>b</th></tr></thead>"));
+    assert!(html.contains("<td>1</td><td>2</td>"));
+  }
+
+  #[test]
+  fn test_autolinks() {
+    let options = MarkdownOptions {
+      autolinks: true,
+      ..Default::default()
+    };
+    assert_eq!(
+      render(
+        "See https://example.com/a_(b). Or www.example.org!",
+        &options
+      ),
+      "<p>See <a href=\"https://example.com/a_(b)\" rel=\"noopener noreferrer\">https://example.com/a_(b)</a>. \
+       Or <a href=\"http://www.example.org\" rel=\"noopener noreferrer\">www.example.org</a>!</p>\n"
+    );
+    // Existing links, code, and non-boundary matches are left alone.
+    assert_eq!(
+      render(
+        "[https://a.example](https://b.example) `https://c.example` xhttps://d.example",
+        &options
+      ),
+      "<p><a href=\"https://b.example\" rel=\"noopener noreferrer\">https://a.example</a> \
+       <code>https://c.example</code> xhttps://d.example</p>\n"
+    );
+    // Bare scheme is not a URL.
+    assert_eq!(render("https://", &options), "<p>https://</p>\n");
+    // Off by default.
+    assert_eq!(
+      render("https://example.com", &MarkdownOptions::default()),
+      "<p>https://example.com</p>\n"
+    );
+  }
+
+  #[test]
+  fn test_find_urls() {
+    let text = "(https://example.com) and https://example.com/path?q=1, www.x.io.";
+    let urls: Vec<&str> = find_urls(text)
+      .into_iter()
+      .map(|(s, e)| &text[s..e])
+      .collect();
+    assert_eq!(
+      urls,
+      vec![
+        "https://example.com",
+        "https://example.com/path?q=1",
+        "www.x.io"
+      ]
+    );
+  }
+
+  #[test]
+  fn test_multibyte_text_after_h_or_w() {
+    let options = MarkdownOptions {
+      autolinks: true,
+      ..Default::default()
+    };
+    for text in [
+      "wé€ x",
+      "hé€€ x",
+      "w漢字テキスト",
+      "h日本語のテキスト",
+      "ww€",
+      "Wé",
+    ] {
+      assert!(find_urls(text).is_empty(), "{text:?}");
+      assert_eq!(render(text, &options), format!("<p>{text}</p>\n"));
+    }
+    let text = "wé https://例え.jp/パス";
+    let urls: Vec<&str> = find_urls(text)
+      .into_iter()
+      .map(|(s, e)| &text[s..e])
+      .collect();
+    assert_eq!(urls, vec!["https://例え.jp/パス"]);
+  }
+}
@@ -0,0 +1,394 @@
+//! Word-list masking and PII redaction backing [`FilterOp::Mask`](crate::FilterOp::Mask)
+//! and [`FilterOp::Redact`](crate::FilterOp::Redact).
+
+use aho_corasick::AhoCorasick;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt::{self, Debug};
+use std::ops::Range;
+use std::sync::OnceLock;
+
+use crate::{FilterError, FilterErrorCode};
+
+static EMAIL_REGEX: OnceLock<Regex> = OnceLock::new();
+static CARD_NUMBER_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// How [`FilterOp::Mask`](crate::FilterOp::Mask) matches its word list.
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_filter::{FilterOp, MaskMode, WordMask};
+///
+/// let op = FilterOp::<String>::Mask(
+///     WordMask::new(vec!["heck".into()], MaskMode::WholeWord, '*', true).unwrap(),
+/// );
+///
+/// assert_eq!(op.apply_ref("Heck, that's heckin' great"), "****, that's heckin' great");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MaskMode {
+  /// Only mask occurrences not adjoined by another letter, digit, or `_`
+  /// (`"ass"` masks `"ass!"` but not `"class"`).
+  #[default]
+  WholeWord,
+
+  /// Mask every occurrence, including inside longer words.
+  Substring,
+}
+
+/// A built-in PII detector for [`FilterOp::Redact`](crate::FilterOp::Redact).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RedactDetector {
+  /// Email addresses (`local@example.com`).
+  Email,
+
+  /// Runs of 13–19 digits, optionally grouped with single spaces or dashes, that
+  /// pass the Luhn checksum (`4111 1111 1111 1111`).
+  CardNumber,
+}
+
+impl RedactDetector {
+  /// Every built-in detector; the default for `FilterOp::Redact`.
+  pub const ALL: [RedactDetector; 2] = [RedactDetector::Email, RedactDetector::CardNumber];
+
+  fn find_spans(&self, value: &str, spans: &mut Vec<Range<usize>>) {
+    match self {
+      RedactDetector::Email => {
+        let regex = EMAIL_REGEX.get_or_init(|| {
+          Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}").unwrap()
+        });
+        spans.extend(regex.find_iter(value).map(|m| m.range()));
+      }
+      RedactDetector::CardNumber => {
+        let regex =
+          CARD_NUMBER_REGEX.get_or_init(|| Regex::new(r"\b[0-9](?:[ -]?[0-9]){12,18}\b").unwrap());
+        spans.extend(
+          regex
+            .find_iter(value)
+            .filter(|m| luhn_valid(m.as_str()))
+            .map(|m| m.range()),
+        );
+      }
+    }
+  }
+}
+
+/// Luhn checksum over the ASCII digits of `value`.
+fn luhn_valid(value: &str) -> bool {
+  let checksum = value
+    .bytes()
+    .filter(u8::is_ascii_digit)
+    .rev()
+    .enumerate()
+    .map(|(i, b)| {
+      let digit = u32::from(b - b'0');
+      if i % 2 == 1 {
+        let doubled = digit * 2;
+        if doubled > 9 { doubled - 9 } else { doubled }
+      } else {
+        digit
+      }
+    })
+    .fold(0, |acc, digit| (acc + digit) % 10);
+  checksum == 0
+}
+
+fn is_word_char(c: char) -> bool {
+  c.is_alphanumeric() || c == '_'
+}
+
+pub(crate) fn default_mask_char() -> char {
+  '*'
+}
+
+/// Serialized form of [`WordMask`].
+#[derive(Clone, Serialize, Deserialize)]
+struct WordMaskConfig {
+  words: Vec<String>,
+  #[serde(default)]
+  mode: MaskMode,
+  #[serde(default = "default_mask_char")]
+  mask_char: char,
+  #[serde(default)]
+  case_insensitive: bool,
+}
+
+/// The word list of [`FilterOp::Mask`](crate::FilterOp::Mask), compiled into a
+/// multi-pattern (Aho–Corasick) automaton when it is constructed or deserialized,
+/// so masking costs one pass over the input regardless of how many words are
+/// listed. Clones share the automaton.
+///
+/// Serializes as its settings (`words`, `mode`, `mask_char`, `case_insensitive`);
+/// only `words` is required when deserializing.
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_filter::{MaskMode, WordMask};
+///
+/// let mask = WordMask::new(vec!["darn".into()], MaskMode::WholeWord, '*', true).unwrap();
+/// assert_eq!(mask.mask("Darn it, darned!"), "**** it, darned!");
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "WordMaskConfig", into = "WordMaskConfig")]
+pub struct WordMask {
+  config: WordMaskConfig,
+  automaton: Option<AhoCorasick>,
+}
+
+impl WordMask {
+  /// Builds the automaton for `words`; empty words are ignored.
+  ///
+  /// Fails with [`FilterErrorCode::InvalidConfig`] if the word list exceeds the
+  /// automaton's size limits.
+  pub fn new(
+    words: Vec<String>,
+    mode: MaskMode,
+    mask_char: char,
+    case_insensitive: bool,
+  ) -> Result<Self, FilterError> {
+    WordMaskConfig {
+      words,
+      mode,
+      mask_char,
+      case_insensitive,
+    }
+    .try_into()
+  }
+
+  /// Words to mask.
+  pub fn words(&self) -> &[String] {
+    &self.config.words
+  }
+
+  /// Whole-word or substring matching.
+  pub fn mode(&self) -> MaskMode {
+    self.config.mode
+  }
+
+  /// Character substituted for each masked character.
+  pub fn mask_char(&self) -> char {
+    self.config.mask_char
+  }
+
+  /// Whether ASCII letters match case-insensitively (non-ASCII letters must match
+  /// exactly).
+  pub fn case_insensitive(&self) -> bool {
+    self.config.case_insensitive
+  }
+
+  /// Replaces every character of each listed word found in `value` with the mask
+  /// character. Returns `Cow::Borrowed` when nothing matched.
+  pub fn mask<'a>(&self, value: &'a str) -> Cow<'a, str> {
+    let Some(automaton) = &self.automaton else {
+      return Cow::Borrowed(value);
+    };
+    // Overlapping search, so a rejected match (e.g. not a whole word) cannot hide
+    // an acceptable one that starts inside it.
+    let spans = automaton
+      .find_overlapping_iter(value)
+      .map(|m| m.range())
+      .filter(|span| {
+        self.config.mode == MaskMode::Substring
+          || !(value[..span.start]
+            .chars()
+            .next_back()
+            .is_some_and(is_word_char)
+            || value[span.end..].chars().next().is_some_and(is_word_char))
+      })
+      .collect();
+    mask_spans(value, spans, self.config.mask_char)
+  }
+}
+
+impl TryFrom<WordMaskConfig> for WordMask {
+  type Error = FilterError;
+
+  fn try_from(config: WordMaskConfig) -> Result<Self, FilterError> {
+    let words: Vec<&str> = config
+      .words
+      .iter()
+      .map(String::as_str)
+      .filter(|word| !word.is_empty())
+      .collect();
+    let automaton = if words.is_empty() {
+      None
+    } else {
+      let automaton = AhoCorasick::builder()
+        .ascii_case_insensitive(config.case_insensitive)
+        .build(words)
+        .map_err(|e| {
+          FilterError::new(format!("cannot build the mask word list: {e}"))
+            .with_name("Mask")
+            .with_code(FilterErrorCode::InvalidConfig)
+        })?;
+      Some(automaton)
+    };
+    Ok(Self { config, automaton })
+  }
+}
+
+impl From<WordMask> for WordMaskConfig {
+  fn from(mask: WordMask) -> Self {
+    mask.config
+  }
+}
+
+impl Debug for WordMask {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("WordMask")
+      .field("words", &self.config.words)
+      .field("mode", &self.config.mode)
+      .field("mask_char", &self.config.mask_char)
+      .field("case_insensitive", &self.config.case_insensitive)
+      .finish()
+  }
+}
+
+impl PartialEq for WordMask {
+  fn eq(&self, other: &Self) -> bool {
+    let (a, b) = (&self.config, &other.config);
+    a.words == b.words
+      && a.mode == b.mode
+      && a.mask_char == b.mask_char
+      && a.case_insensitive == b.case_insensitive
+  }
+}
+
+/// Masks the spans of `value` found by `detectors`. Returns `Cow::Borrowed` when
+/// nothing was detected.
+pub(crate) fn redact<'a>(
+  value: &'a str,
+  detectors: &[RedactDetector],
+  mask_char: char,
+) -> Cow<'a, str> {
+  let mut spans = Vec::new();
+  for detector in detectors {
+    detector.find_spans(value, &mut spans);
+  }
+  mask_spans(value, spans, mask_char)
+}
+
+/// Replaces each character inside the (possibly overlapping, unsorted) byte `spans`
+/// of `value` with `mask_char`.
+fn mask_spans(value: &str, mut spans: Vec<Range<usize>>, mask_char: char) -> Cow<'_, str> {
+  if spans.is_empty() {
+    return Cow::Borrowed(value);
+  }
+  spans.sort_unstable_by_key(|span| span.start);
+  let mut result = String::with_capacity(value.len());
+  let mut last = 0;
+  for span in spans {
+    if span.end <= last {
+      continue;
+    }
+    let start = span.start.max(last);
+    result.push_str(&value[last..start]);
+    result.extend(std::iter::repeat_n(
+      mask_char,
+      value[start..span.end].chars().count(),
+    ));
+    last = span.end;
+  }
+  result.push_str(&value[last..]);
+  Cow::Owned(result)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn word_mask(list: &[&str], mode: MaskMode, mask_char: char, case_insensitive: bool) -> WordMask {
+    let words = list.iter().map(|word| word.to_string()).collect();
+    WordMask::new(words, mode, mask_char, case_insensitive).unwrap()
+  }
+
+  #[test]
+  fn test_whole_word_mode_respects_boundaries() {
+    let masker = word_mask(&["ass", "darn"], MaskMode::WholeWord, '*', false);
+    assert_eq!(
+      masker.mask("Darn, a class ass! darn_it darn."),
+      "Darn, a class ***! darn_it ****."
+    );
+    assert!(matches!(masker.mask("classic"), Cow::Borrowed(_)));
+  }
+
+  #[test]
+  fn test_substring_mode_masks_inside_words() {
+    let masker = word_mask(&["ass"], MaskMode::Substring, '#', false);
+    assert_eq!(masker.mask("class assessment"), "cl### ###essment");
+  }
+
+  #[test]
+  fn test_overlapping_words_are_merged() {
+    let masker = word_mask(&["abc", "bcd", "cdef"], MaskMode::Substring, '*', false);
+    assert_eq!(masker.mask("xabcdefx"), "x******x");
+
+    // The shorter word is rejected as a whole word, the longer one is not.
+    let masker = word_mask(&["foo", "foobar"], MaskMode::WholeWord, '*', false);
+    assert_eq!(masker.mask("foobar foo"), "****** ***");
+  }
+
+  #[test]
+  fn test_case_insensitive_and_unicode() {
+    let masker = word_mask(&["heck", "müll"], MaskMode::WholeWord, '•', true);
+    assert_eq!(masker.mask("HECK, Müll!"), "••••, ••••!");
+    let sensitive = word_mask(&["heck"], MaskMode::WholeWord, '*', false);
+    assert_eq!(sensitive.mask("HECK heck"), "HECK ****");
+  }
+
+  #[test]
+  fn test_empty_word_list_is_a_no_op() {
+    let masker = word_mask(&["", ""], MaskMode::Substring, '*', false);
+    assert!(matches!(masker.mask("anything"), Cow::Borrowed(_)));
+  }
+
+  #[test]
+  fn test_redact_emails_and_cards() {
+    assert_eq!(
+      redact(
+        "mail jane.doe+x@mail.example.com or pay 4111 1111 1111 1111.",
+        &RedactDetector::ALL,
+        '*'
+      ),
+      "mail *************************** or pay *******************."
+    );
+    assert_eq!(
+      redact(
+        "card 5500-0000-0000-0004",
+        &[RedactDetector::CardNumber],
+        'X'
+      ),
+      "card XXXXXXXXXXXXXXXXXXX"
+    );
+  }
+
+  #[test]
+  fn test_redact_ignores_non_pii() {
+    // 16 digits failing the Luhn check, too-short runs, and a bare `@`.
+    for value in [
+      "order 4111 1111 1111 1112",
+      "call 555-0132",
+      "@handle at home",
+    ] {
+      assert!(
+        matches!(redact(value, &RedactDetector::ALL, '*'), Cow::Borrowed(_)),
+        "{value}"
+      );
+    }
+    // Only the requested detectors run.
+    assert_eq!(
+      redact("a@b.io", &[RedactDetector::CardNumber], '*'),
+      "a@b.io"
+    );
+  }
+
+  #[test]
+  fn test_luhn() {
+    assert!(luhn_valid("4111111111111111"));
+    assert!(luhn_valid("3782 822463 10005"));
+    assert!(!luhn_valid("4111111111111112"));
+  }
+}
@@ -0,0 +1,110 @@
+//! Phone number normalization backing `TryFilterOp::NormalizePhone` (requires the
+//! `phone` feature).
+
+use phonenumber::Mode;
+use phonenumber::country::Id;
+use std::borrow::Cow;
+
+use crate::{FilterError, FilterErrorCode};
+
+const NAME: &str = "NormalizePhone";
+
+/// Parses `value` as a phone number and formats it as E.164 (`+14155550132`).
+///
+/// Numbers without a leading `+` or international dialing prefix are interpreted
+/// in `default_region` (an ISO 3166-1 alpha-2 code such as `"US"`). Extensions are
+/// not representable in E.164 and are dropped.
+///
+/// Returns `Cow::Borrowed` when `value` is already in E.164 form.
+pub(crate) fn normalize_phone<'a>(
+  value: &'a str,
+  default_region: Option<&str>,
+) -> Result<Cow<'a, str>, FilterError> {
+  let region = default_region
+    .map(|region| {
+      region
+        .trim()
+        .to_ascii_uppercase()
+        .parse::<Id>()
+        .map_err(|_| {
+          FilterError::new(format!("unknown default region {region:?}"))
+            .with_name(NAME)
+            .with_code(FilterErrorCode::InvalidConfig)
+        })
+    })
+    .transpose()?;
+
+  let number = phonenumber::parse(region, value).map_err(|e| {
+    FilterError::new(format!("cannot parse {value:?} as a phone number: {e}"))
+      .with_name(NAME)
+      .with_code(FilterErrorCode::InvalidPhone)
+  })?;
+  if !number.is_valid() {
+    return Err(
+      FilterError::new(format!("{value:?} is not a valid phone number"))
+        .with_name(NAME)
+        .with_code(FilterErrorCode::InvalidPhone),
+    );
+  }
+
+  let e164 = number.format().mode(Mode::E164).to_string();
+  if e164 == value {
+    Ok(Cow::Borrowed(value))
+  } else {
+    Ok(Cow::Owned(e164))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_national_numbers_use_default_region() {
+    assert_eq!(
+      normalize_phone("(415) 555-0132", Some("US")).unwrap(),
+      "+14155550132"
+    );
+    assert_eq!(
+      normalize_phone("020 7946 0018", Some("gb")).unwrap(),
+      "+442079460018"
+    );
+  }
+
+  #[test]
+  fn test_international_numbers_ignore_default_region() {
+    for value in ["+44 20 7946 0018", "0044 20 7946 0018"] {
+      assert_eq!(
+        normalize_phone(value, Some("GB")).unwrap(),
+        "+442079460018",
+        "{value}"
+      );
+    }
+    assert_eq!(
+      normalize_phone("+1 415-555-0132", None).unwrap(),
+      "+14155550132"
+    );
+  }
+
+  #[test]
+  fn test_e164_input_is_borrowed() {
+    assert!(matches!(
+      normalize_phone("+14155550132", None).unwrap(),
+      Cow::Borrowed(_)
+    ));
+  }
+
+  #[test]
+  fn test_invalid_numbers_are_rejected() {
+    for (value, region) in [
+      ("415 555 0132", None),
+      ("12", Some("US")),
+      ("not a number", Some("US")),
+      ("+1 000 000 0000", None),
+      ("(415) 555-0132", Some("ZZ")),
+    ] {
+      let err = normalize_phone(value, region).unwrap_err();
+      assert_eq!(err.filter_name(), Some(NAME), "{value:?}");
+    }
+  }
+}
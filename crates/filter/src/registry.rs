@@ -0,0 +1,413 @@
+//! Named filter functions for [`FilterOp::Named`] and [`TryFilterOp::Named`].
+//!
+//! `Custom` and `TryCustom` hold closures and cannot be serialized. A pipeline kept in
+//! config refers to its custom steps by name instead, and [`FilterRegistry::bind`]
+//! resolves each name to the registered function once the pipeline is loaded. The bound
+//! pipeline keeps its `Named` steps, so it still serializes and traces by name.
+
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::{FilterError, FilterErrorCode, FilterOp, TryFilterOp};
+
+/// Type alias for an infallible function registered in a [`FilterRegistry`]; receives
+/// the value and the op's `args`.
+pub type NamedFilterFn<T> = Arc<dyn Fn(T, &Value) -> T + Send + Sync>;
+
+/// Type alias for a fallible function registered in a [`FilterRegistry`]; receives
+/// the value and the op's `args`.
+pub type NamedTryFilterFn<T> = Arc<dyn Fn(T, &Value) -> Result<T, FilterError> + Send + Sync>;
+
+/// The registered function a [`FilterOp::Named`] or [`TryFilterOp::Named`] step is bound
+/// to. Only [`FilterRegistry::bind`] and [`FilterRegistry::bind_try`] set it, and it never
+/// hands the function back out; [`FilterOp::named`] and [`TryFilterOp::named`] build
+/// unbound steps.
+pub struct Binding<F>(Option<F>);
+
+impl<F> Binding<F> {
+  /// Whether a registry has bound the step.
+  pub fn is_bound(&self) -> bool {
+    self.0.is_some()
+  }
+
+  pub(crate) fn get(&self) -> Option<&F> {
+    self.0.as_ref()
+  }
+}
+
+impl<F> Default for Binding<F> {
+  fn default() -> Self {
+    Self(None)
+  }
+}
+
+impl<F: Clone> Clone for Binding<F> {
+  fn clone(&self) -> Self {
+    Self(self.0.clone())
+  }
+}
+
+impl<F> fmt::Debug for Binding<F> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(if self.is_bound() { "bound" } else { "unbound" })
+  }
+}
+
+/// Functions that [`FilterOp::Named`] and [`TryFilterOp::Named`] steps refer to by name.
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_filter::{FilterOp, FilterRegistry};
+///
+/// let mut registry = FilterRegistry::<String>::new();
+/// registry.register("repeat", |value, args| {
+///     value.repeat(args.as_u64().unwrap_or(1) as usize)
+/// });
+///
+/// let config = r#"{"type":"Chain","config":[
+///     {"type":"Trim"},
+///     {"type":"Named","config":{"name":"repeat","args":2}}
+/// ]}"#;
+/// let op: FilterOp<String> = serde_json::from_str(config).unwrap();
+/// let op = registry.bind(op).unwrap();
+/// assert_eq!(op.apply(" ab ".to_string()), "abab");
+///
+/// // Unknown names are reported when binding, and unbound steps fail when checked.
+/// let typo: FilterOp<String> =
+///     serde_json::from_str(r#"{"type":"Named","config":{"name":"repaet"}}"#).unwrap();
+/// assert!(typo.ensure_bound().is_err());
+/// assert!(registry.bind(typo).is_err());
+/// ```
+pub struct FilterRegistry<T> {
+  filters: HashMap<String, NamedFilterFn<T>>,
+  try_filters: HashMap<String, NamedTryFilterFn<T>>,
+}
+
+impl<T> Default for FilterRegistry<T> {
+  fn default() -> Self {
+    Self {
+      filters: HashMap::new(),
+      try_filters: HashMap::new(),
+    }
+  }
+}
+
+impl<T> Clone for FilterRegistry<T> {
+  fn clone(&self) -> Self {
+    Self {
+      filters: self.filters.clone(),
+      try_filters: self.try_filters.clone(),
+    }
+  }
+}
+
+impl<T> fmt::Debug for FilterRegistry<T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut filters: Vec<&String> = self.filters.keys().collect();
+    let mut try_filters: Vec<&String> = self.try_filters.keys().collect();
+    filters.sort_unstable();
+    try_filters.sort_unstable();
+    f.debug_struct("FilterRegistry")
+      .field("filters", &filters)
+      .field("try_filters", &try_filters)
+      .finish()
+  }
+}
+
+impl<T: 'static> FilterRegistry<T> {
+  /// An empty registry.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Registers an infallible function under `name`, replacing any previous one.
+  pub fn register<F>(&mut self, name: impl Into<String>, f: F) -> &mut Self
+  where
+    F: Fn(T, &Value) -> T + Send + Sync + 'static,
+  {
+    self.filters.insert(name.into(), Arc::new(f));
+    self
+  }
+
+  /// Registers a fallible function under `name`, replacing any previous one.
+  ///
+  /// Fallible functions are only available to [`TryFilterOp::Named`].
+  pub fn register_try<F>(&mut self, name: impl Into<String>, f: F) -> &mut Self
+  where
+    F: Fn(T, &Value) -> Result<T, FilterError> + Send + Sync + 'static,
+  {
+    self.try_filters.insert(name.into(), Arc::new(f));
+    self
+  }
+
+  /// Whether a function (fallible or not) is registered under `name`.
+  pub fn contains(&self, name: &str) -> bool {
+    self.filters.contains_key(name) || self.try_filters.contains_key(name)
+  }
+
+  /// Binds every [`FilterOp::Named`] in `op` (including inside `Chain`, `Each`, and
+  /// `When`) to the registered function. The steps stay `Named`, so the bound pipeline
+  /// serializes back to the same config.
+  ///
+  /// # Errors
+  ///
+  /// Returns a [`FilterError`] naming the first step whose name is not registered as an
+  /// infallible function.
+  pub fn bind(&self, op: FilterOp<T>) -> Result<FilterOp<T>, FilterError> {
+    Ok(match op {
+      FilterOp::Named { name, args, .. } => {
+        let Some(f) = self.filters.get(&name) else {
+          return Err(self.unknown(&name, false));
+        };
+        let bound = Binding(Some(Arc::clone(f)));
+        FilterOp::Named { name, args, bound }
+      }
+      FilterOp::Chain(ops) => FilterOp::Chain(
+        ops
+          .into_iter()
+          .map(|op| self.bind(op))
+          .collect::<Result<_, _>>()?,
+      ),
+      FilterOp::Each(op) => FilterOp::Each(Box::new(self.bind(*op)?)),
+      #[cfg(feature = "validation")]
+      FilterOp::When {
+        condition,
+        then_filter,
+        else_filter,
+      } => FilterOp::When {
+        condition,
+        then_filter: Box::new(self.bind(*then_filter)?),
+        else_filter: else_filter
+          .map(|op| self.bind(*op).map(Box::new))
+          .transpose()?,
+      },
+      op => op,
+    })
+  }
+
+  /// Binds every [`TryFilterOp::Named`] in `op` to the registered function — fallible
+  /// functions first, then infallible ones — and binds the [`FilterOp`]s wrapped in
+  /// `Infallible`. The steps stay `Named`, so the bound pipeline serializes back to the
+  /// same config.
+  ///
+  /// # Errors
+  ///
+  /// Returns a [`FilterError`] naming the first step whose name is not registered.
+  pub fn bind_try(&self, op: TryFilterOp<T>) -> Result<TryFilterOp<T>, FilterError> {
+    Ok(match op {
+      TryFilterOp::Named { name, args, .. } => {
+        let bound: NamedTryFilterFn<T> = if let Some(f) = self.try_filters.get(&name) {
+          Arc::clone(f)
+        } else if let Some(f) = self.filters.get(&name) {
+          let f = Arc::clone(f);
+          Arc::new(move |value, args| Ok(f(value, args)))
+        } else {
+          return Err(self.unknown(&name, true));
+        };
+        TryFilterOp::Named {
+          name,
+          args,
+          bound: Binding(Some(bound)),
+        }
+      }
+      TryFilterOp::Infallible(op) => TryFilterOp::Infallible(self.bind(op)?),
+      TryFilterOp::Chain(ops) => TryFilterOp::Chain(
+        ops
+          .into_iter()
+          .map(|op| self.bind_try(op))
+          .collect::<Result<_, _>>()?,
+      ),
+      op => op,
+    })
+  }
+
+  fn unknown(&self, name: &str, fallible: bool) -> FilterError {
+    let message = if !fallible && self.try_filters.contains_key(name) {
+      format!("filter {name:?} is fallible; reference it from a TryFilterOp::Named")
+    } else {
+      format!("no filter named {name:?} is registered")
+    };
+    FilterError::new(message)
+      .with_name("Named")
+      .with_code(FilterErrorCode::UnknownFilter)
+  }
+}
+
+/// The error for a `Named` step applied without binding.
+pub(crate) fn unbound_error(name: &str) -> FilterError {
+  FilterError::new(format!(
+    "filter {name:?} was applied before binding it to a FilterRegistry"
+  ))
+  .with_name("Named")
+  .with_code(FilterErrorCode::UnknownFilter)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  fn registry() -> FilterRegistry<String> {
+    let mut registry = FilterRegistry::new();
+    registry
+      .register("wrap", |value: String, args: &Value| {
+        let with = args["with"].as_str().unwrap_or("\"");
+        format!("{with}{value}{with}")
+      })
+      .register_try("non_empty", |value: String, _: &Value| {
+        if value.is_empty() {
+          Err(FilterError::new("empty"))
+        } else {
+          Ok(value)
+        }
+      });
+    registry
+  }
+
+  #[test]
+  fn test_bind_nested_ops() {
+    let op = FilterOp::<String>::Chain(vec![
+      FilterOp::Trim,
+      FilterOp::Chain(vec![FilterOp::named("wrap", json!({ "with": "*" }))]),
+    ]);
+    let bound = registry().bind(op).unwrap();
+    assert_eq!(bound.apply(" a ".to_string()), "*a*");
+
+    let each = FilterOp::<String>::Each(Box::new(FilterOp::named("wrap", Value::Null)));
+    let bound = registry().bind(each).unwrap();
+    assert_eq!(bound.apply_all(vec!["a".into()]), ["\"a\""]);
+  }
+
+  #[cfg(feature = "validation")]
+  #[test]
+  fn test_bind_when_branches() {
+    let op = FilterOp::<String>::When {
+      condition: walrs_validation::Condition::IsEmpty,
+      then_filter: Box::new(FilterOp::Trim),
+      else_filter: Some(Box::new(FilterOp::named("wrap", Value::Null))),
+    };
+    assert_eq!(registry().bind(op).unwrap().apply("a".into()), "\"a\"");
+
+    let op = FilterOp::<String>::When {
+      condition: walrs_validation::Condition::IsEmpty,
+      then_filter: Box::new(FilterOp::named("nope", Value::Null)),
+      else_filter: None,
+    };
+    assert!(registry().bind(op).is_err());
+  }
+
+  #[test]
+  fn test_unknown_and_fallible_names_error() {
+    let named = |name: &str| FilterOp::<String>::named(name, Value::Null);
+    let err = registry()
+      .bind(FilterOp::Chain(vec![FilterOp::Trim, named("nope")]))
+      .unwrap_err();
+    assert_eq!(err.filter_name(), Some("Named"));
+    assert!(err.message().contains("\"nope\""));
+
+    let err = registry().bind(named("non_empty")).unwrap_err();
+    assert!(err.message().contains("fallible"));
+  }
+
+  #[test]
+  fn test_bind_try() {
+    let named = |name: &str| TryFilterOp::<String>::named(name, Value::Null);
+    let op = TryFilterOp::Chain(vec![
+      TryFilterOp::Infallible(FilterOp::Trim),
+      named("non_empty"),
+      named("wrap"),
+    ]);
+    let bound = registry().bind_try(op).unwrap();
+    assert_eq!(bound.try_apply(" a ".into()).unwrap(), "\"a\"");
+    assert!(bound.try_apply("  ".into()).is_err());
+
+    let op = TryFilterOp::Infallible(FilterOp::named("nope", Value::Null));
+    assert!(registry().bind_try(op).is_err());
+    assert!(registry().bind_try(named("nope")).is_err());
+  }
+
+  #[test]
+  fn test_unbound_named_fails() {
+    let op = FilterOp::<String>::Chain(vec![FilterOp::Trim, FilterOp::named("wrap", Value::Null)]);
+    let err = op.ensure_bound().unwrap_err();
+    assert_eq!(err.code(), FilterErrorCode::UnknownFilter);
+    assert_eq!(err.path(), [1]);
+    let err = TryFilterOp::Infallible(op)
+      .try_apply("a".into())
+      .unwrap_err();
+    assert_eq!(err.code(), FilterErrorCode::UnknownFilter);
+
+    let op = TryFilterOp::Infallible(FilterOp::<i32>::Each(Box::new(FilterOp::named(
+      "double",
+      Value::Null,
+    ))));
+    let err = op.try_apply(2).unwrap_err();
+    assert_eq!(err.code(), FilterErrorCode::UnknownFilter);
+    assert!(err.message().contains("\"double\""));
+    assert!(
+      registry()
+        .bind(FilterOp::named("wrap", Value::Null))
+        .unwrap()
+        .ensure_bound()
+        .is_ok()
+    );
+  }
+
+  #[test]
+  fn test_unbound_try_named_errors() {
+    let op = TryFilterOp::<i32>::named("double", Value::Null);
+    assert_eq!(op.try_apply(1).unwrap_err().filter_name(), Some("Named"));
+  }
+
+  #[test]
+  fn test_numeric_registry() {
+    let mut registry = FilterRegistry::<i64>::new();
+    registry.register("scale", |value, args| value * args.as_i64().unwrap_or(1));
+    let op: FilterOp<i64> =
+      serde_json::from_str(r#"{"type":"Named","config":{"name":"scale","args":3}}"#).unwrap();
+    assert_eq!(registry.bind(op).unwrap().apply(-2), -6);
+    assert!(registry.contains("scale"));
+    assert!(!registry.contains("wrap"));
+  }
+
+  #[test]
+  fn test_named_serde_round_trip() {
+    let op = FilterOp::<String>::Chain(vec![
+      FilterOp::named("wrap", json!({ "with": "'" })),
+      FilterOp::named("plain", Value::Null),
+    ]);
+    let json = serde_json::to_string(&op).unwrap();
+    assert_eq!(
+      json,
+      r#"{"type":"Chain","config":[{"type":"Named","config":{"name":"wrap","args":{"with":"'"}}},{"type":"Named","config":{"name":"plain"}}]}"#
+    );
+    let back: FilterOp<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, op);
+
+    let try_op: TryFilterOp<String> =
+      serde_json::from_str(r#"{"type":"Named","config":{"name":"non_empty"}}"#).unwrap();
+    assert_eq!(try_op.name(), "Named");
+  }
+
+  #[test]
+  fn test_bound_pipeline_keeps_names() {
+    let config =
+      r#"{"type":"Chain","config":[{"type":"Trim"},{"type":"Named","config":{"name":"wrap"}}]}"#;
+    let op: FilterOp<String> = serde_json::from_str(config).unwrap();
+    let bound = registry().bind(op.clone()).unwrap();
+    assert_eq!(serde_json::to_string(&bound).unwrap(), config);
+    assert_ne!(bound, op);
+    let (value, trace) = bound.apply_traced(" a ".into(), crate::TraceValues::Omitted);
+    assert_eq!(value, "\"a\"");
+    assert_eq!(trace.steps[1].op, "Named");
+
+    let try_config = r#"{"type":"Chain","config":[{"type":"Named","config":{"name":"non_empty"}},{"type":"Named","config":{"name":"wrap"}}]}"#;
+    let op: TryFilterOp<String> = serde_json::from_str(try_config).unwrap();
+    let bound = registry().bind_try(op).unwrap();
+    assert_eq!(serde_json::to_string(&bound).unwrap(), try_config);
+    assert_eq!(bound.try_apply("a".into()).unwrap(), "\"a\"");
+  }
+}
@@ -1,21 +1,57 @@
 use crate::Filter;
-use regex::Regex;
+use crate::words::split_runs;
 use std::borrow::Cow;
-use std::sync::OnceLock;
 
-static SLUG_FILTER_REGEX: OnceLock<Regex> = OnceLock::new();
-static SLUG_FILTER_REGEX_STR: &str = r"(?i)[^a-zA-Z0-9_\-]";
-static DASH_FILTER_REGEX: OnceLock<Regex> = OnceLock::new();
-static DASH_FILTER_REGEX_STR: &str = r"(?i)\-{2,}";
+/// Returns `true` for characters kept verbatim (modulo case) in a slug; every
+/// other character is a word separator.
+fn is_slug_word_char(c: char) -> bool {
+  c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Returns `true` if `c` can separate slug words. Alphanumerics can't: they would be
+/// indistinguishable from the words themselves.
+pub fn is_valid_slug_separator(c: char) -> bool {
+  !c.is_alphanumeric()
+}
 
-/// Returns the static regex used for filtering a string to slug.
-pub(crate) fn get_slug_filter_regex() -> &'static Regex {
-  SLUG_FILTER_REGEX.get_or_init(|| Regex::new(SLUG_FILTER_REGEX_STR).unwrap())
+/// `separator`, or `-` if it is not a valid separator.
+fn effective_separator(separator: char) -> char {
+  if is_valid_slug_separator(separator) {
+    separator
+  } else {
+    '-'
+  }
 }
 
-/// Returns the static regex used for filtering out multiple dashes for one dash.
-pub(crate) fn get_dash_filter_regex() -> &'static Regex {
-  DASH_FILTER_REGEX.get_or_init(|| Regex::new(DASH_FILTER_REGEX_STR).unwrap())
+/// Serde default for slug separators.
+pub(crate) fn default_slug_separator() -> char {
+  '-'
+}
+
+/// Deserializes a slug separator, rejecting alphanumeric characters.
+pub(crate) fn deserialize_slug_separator<'de, D>(deserializer: D) -> Result<char, D::Error>
+where
+  D: serde::Deserializer<'de>,
+{
+  let separator = <char as serde::Deserialize>::deserialize(deserializer)?;
+  if is_valid_slug_separator(separator) {
+    Ok(separator)
+  } else {
+    Err(serde::de::Error::custom(format!(
+      "invalid slug separator {separator:?}: separators cannot be alphanumeric"
+    )))
+  }
+}
+
+/// Pretty-slugs `xs` as [`FilterOp::Slug`](crate::FilterOp::Slug) does, without
+/// building a [`SlugFilter`].
+pub(crate) fn pretty_slug<'a>(
+  xs: Cow<'a, str>,
+  max_length: usize,
+  separator: char,
+  stop_words: &[String],
+) -> Cow<'a, str> {
+  _to_pretty_slug(max_length, effective_separator(separator), stop_words, xs)
 }
 
 /// Normalizes given string into a slug — a lowercase, ASCII-only string
@@ -28,11 +64,16 @@ pub(crate) fn get_dash_filter_regex() -> &'static Regex {
 /// assert_eq!(to_slug(Cow::Borrowed("Hello World")), "hello-world");
 /// ```
 pub fn to_slug<'a>(xs: Cow<'a, str>) -> Cow<'a, str> {
-  _to_slug(get_slug_filter_regex(), 200, xs)
+  _to_slug(200, '-', &[], xs)
 }
 
 /// Same as [`to_slug`] but collapses consecutive dashes into a single dash.
 ///
+/// Runs of non-slug characters are treated as word separators — the same run
+/// splitting that [`CaseFilter`](crate::CaseFilter) builds its words on — and collapse
+/// into one dash before truncating to the max length, so the result never ends in a
+/// dash, even when truncated.
+///
 /// ```rust
 /// use std::borrow::Cow;
 /// use walrs_filter::slug::to_pretty_slug;
@@ -40,20 +81,50 @@ pub fn to_slug<'a>(xs: Cow<'a, str>) -> Cow<'a, str> {
 /// assert_eq!(to_pretty_slug(Cow::Borrowed("%$Hello@#$@#!(World$$")), "hello-world");
 /// ```
 pub fn to_pretty_slug<'a>(xs: Cow<'a, str>) -> Cow<'a, str> {
-  _to_pretty_slug(get_slug_filter_regex(), 200, xs)
+  _to_pretty_slug(200, '-', &[], xs)
+}
+
+fn is_stop_word(word: &str, stop_words: &[String]) -> bool {
+  stop_words
+    .iter()
+    .any(|stop_word| stop_word.eq_ignore_ascii_case(word))
+}
+
+/// Returns `true` when `words` contains stop words that should be dropped — that is,
+/// at least one stop word and at least one word that is not a stop word. A value
+/// made up entirely of stop words keeps them, so it still yields a slug.
+fn drops_stop_words<'w>(words: impl Iterator<Item = &'w str>, stop_words: &[String]) -> bool {
+  if stop_words.is_empty() {
+    return false;
+  }
+  let (mut stop, mut other) = (false, false);
+  for word in words {
+    if is_stop_word(word, stop_words) {
+      stop = true;
+    } else {
+      other = true;
+    }
+  }
+  stop && other
 }
 
 /// Returns `true` if the input is already a valid slug for the given parameters.
-fn is_valid_slug(s: &str, max_length: usize, allow_duplicate_dashes: bool) -> bool {
-  if s.is_empty() || s.len() > max_length {
+fn is_valid_slug(
+  s: &str,
+  max_length: usize,
+  allow_duplicate_dashes: bool,
+  separator: char,
+  stop_words: &[String],
+) -> bool {
+  if s.is_empty() || s.chars().take(max_length + 1).count() > max_length {
     return false;
   }
-  if s.starts_with('-') || s.ends_with('-') {
+  if s.starts_with(separator) || s.ends_with(separator) {
     return false;
   }
   let mut prev_dash = false;
   for c in s.chars() {
-    if c == '-' {
+    if c == separator {
       if !allow_duplicate_dashes && prev_dash {
         return false;
       }
@@ -65,20 +136,41 @@ fn is_valid_slug(s: &str, max_length: usize, allow_duplicate_dashes: bool) -> bo
       }
     }
   }
-  true
+  !drops_stop_words(s.split(separator), stop_words)
 }
 
-fn _to_slug<'a>(pattern: &Regex, max_length: usize, xs: Cow<'a, str>) -> Cow<'a, str> {
+fn _to_slug<'a>(
+  max_length: usize,
+  separator: char,
+  stop_words: &[String],
+  xs: Cow<'a, str>,
+) -> Cow<'a, str> {
   // Fast path: if already a valid slug, return as-is (zero-copy)
-  if is_valid_slug(&xs, max_length, true) {
+  if is_valid_slug(&xs, max_length, true, separator, stop_words) {
     return xs;
   }
 
-  let rslt = pattern
-    .replace_all(xs.as_ref(), "-")
-    .to_lowercase()
-    .trim_matches('-')
-    .to_string();
+  // Every non-slug character becomes one separator; dropped stop words leave
+  // their surrounding separators behind.
+  let drop_stop_words = drops_stop_words(split_runs(&xs, is_slug_word_char), stop_words);
+  let mut rslt = String::with_capacity(xs.len());
+  let mut word_start = None;
+  for (i, c) in xs.char_indices().chain([(xs.len(), '\0')]) {
+    if i < xs.len() && is_slug_word_char(c) {
+      word_start.get_or_insert(i);
+      continue;
+    }
+    if let Some(start) = word_start.take() {
+      let word = &xs[start..i];
+      if !(drop_stop_words && is_stop_word(word, stop_words)) {
+        rslt.extend(word.chars().map(|c| c.to_ascii_lowercase()));
+      }
+    }
+    if i < xs.len() {
+      rslt.push(separator);
+    }
+  }
+  let rslt = rslt.trim_matches(separator);
 
   if rslt.chars().take(max_length + 1).count() > max_length {
     let byte_idx = rslt
@@ -88,42 +180,131 @@ fn _to_slug<'a>(pattern: &Regex, max_length: usize, xs: Cow<'a, str>) -> Cow<'a,
       .unwrap_or(rslt.len());
     Cow::Owned(rslt[..byte_idx].to_string())
   } else {
-    Cow::Owned(rslt)
+    Cow::Owned(rslt.to_string())
   }
 }
 
-fn _to_pretty_slug<'a>(pattern: &Regex, max_length: usize, xs: Cow<'a, str>) -> Cow<'a, str> {
+fn _to_pretty_slug<'a>(
+  max_length: usize,
+  separator: char,
+  stop_words: &[String],
+  xs: Cow<'a, str>,
+) -> Cow<'a, str> {
   if xs.is_empty() {
     return xs;
   }
 
   // Fast path: if already a valid pretty slug, return as-is (zero-copy)
-  if is_valid_slug(&xs, max_length, false) {
+  if is_valid_slug(&xs, max_length, false, separator, stop_words) {
     return xs;
   }
 
-  get_dash_filter_regex()
-    .replace_all(&_to_slug(pattern, max_length, xs), "-")
-    .to_string()
-    .into()
+  let drop_stop_words = drops_stop_words(split_runs(&xs, is_slug_word_char), stop_words);
+  let mut out = String::with_capacity(xs.len().min(max_length));
+  // Length in chars; only the separator may be non-ASCII.
+  let mut length = 0;
+  for word in split_runs(&xs, is_slug_word_char) {
+    if drop_stop_words && is_stop_word(word, stop_words) {
+      continue;
+    }
+    if length >= max_length {
+      break;
+    }
+    if !out.is_empty() {
+      out.push(separator);
+      length += 1;
+    }
+    out.extend(word.chars().map(|c| c.to_ascii_lowercase()));
+    length += word.len();
+  }
+  if length > max_length {
+    let byte_idx = out
+      .char_indices()
+      .nth(max_length)
+      .map_or(out.len(), |(i, _)| i);
+    out.truncate(byte_idx);
+  }
+  while out.ends_with(separator) {
+    out.pop();
+  }
+  Cow::Owned(out)
 }
 
 /// Configurable slug filter — produces a lowercase, ASCII-only slug with a settable max length.
+///
+/// Words are joined with [`separator`](Self::separator) (`-` by default), and
+/// [`stop_words`](Self::stop_words) (`a`, `the`, …) can be dropped.
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use walrs_filter::{Filter, SlugFilterBuilder};
+///
+/// let filter = SlugFilterBuilder::default()
+///     .allow_duplicate_dashes(false)
+///     .separator('_')
+///     .stop_words(vec!["a".to_string(), "the".to_string(), "of".to_string()])
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(filter.filter(Cow::Borrowed("The Art of War")), "art_war");
+/// // A title made only of stop words keeps them.
+/// assert_eq!(filter.filter(Cow::Borrowed("The A")), "the_a");
+/// ```
 #[must_use]
-#[derive(Clone, Debug, Default, Builder)]
+#[derive(Clone, Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct SlugFilter {
+  /// Maximum length of the slug, in characters. Default: `200`.
   #[builder(setter(into), default = "200")]
   pub max_length: usize,
 
+  /// Keep one separator per non-slug character instead of collapsing runs into one.
   #[builder(setter(into), default = "true")]
   pub allow_duplicate_dashes: bool,
+
+  /// Character placed between words (and substituted for non-slug characters).
+  /// Must not be alphanumeric (see [`is_valid_slug_separator`]):
+  /// [`SlugFilterBuilder::build`] rejects such separators, and the filter falls back
+  /// to `-` if one is set directly. Default: `-`.
+  #[builder(setter(into), default = "'-'")]
+  pub separator: char,
+
+  /// Words dropped from the slug, compared ASCII case-insensitively. Ignored when
+  /// every word of the input is a stop word. Default: none.
+  #[builder(setter(into), default)]
+  pub stop_words: Vec<String>,
+}
+
+impl SlugFilterBuilder {
+  fn validate(&self) -> Result<(), String> {
+    match self.separator {
+      Some(separator) if !is_valid_slug_separator(separator) => Err(format!(
+        "invalid slug separator {separator:?}: separators cannot be alphanumeric"
+      )),
+      _ => Ok(()),
+    }
+  }
 }
 
 impl SlugFilter {
+  /// Creates a filter with the given max length and dash handling, the default `-`
+  /// separator, and no stop words.
   pub fn new(max_length: usize, allow_duplicate_dashes: bool) -> Self {
     SlugFilter {
       max_length,
       allow_duplicate_dashes,
+      ..Default::default()
+    }
+  }
+}
+
+impl Default for SlugFilter {
+  fn default() -> Self {
+    SlugFilter {
+      max_length: 200,
+      allow_duplicate_dashes: true,
+      separator: '-',
+      stop_words: Vec::new(),
     }
   }
 }
@@ -132,10 +313,95 @@ impl<'a> Filter<Cow<'a, str>> for SlugFilter {
   type Output = Cow<'a, str>;
 
   fn filter(&self, xs: Cow<'a, str>) -> Self::Output {
+    let separator = effective_separator(self.separator);
     if self.allow_duplicate_dashes {
-      _to_slug(get_slug_filter_regex(), self.max_length, xs)
+      _to_slug(self.max_length, separator, &self.stop_words, xs)
+    } else {
+      _to_pretty_slug(self.max_length, separator, &self.stop_words, xs)
+    }
+  }
+}
+
+/// Generates slugs that are unique according to a caller-supplied existence check,
+/// appending `-2`, `-3`, … (using the filter's separator) until a free slug is found.
+///
+/// The base slug is shortened as needed so that suffixed slugs still respect the
+/// filter's `max_length`. The existence check is called once per candidate, so it
+/// must eventually return `false`.
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use walrs_filter::{SlugFilter, SlugGenerator};
+///
+/// let generator = SlugGenerator::new(SlugFilter::new(11, false));
+/// let taken: HashSet<&str> = ["hello-world", "hello-wor-2"].into();
+///
+/// assert_eq!(generator.generate("Fresh page", |slug| taken.contains(slug)), "fresh-page");
+/// assert_eq!(generator.generate("Hello, World!", |slug| taken.contains(slug)), "hello-wor-3");
+/// ```
+#[must_use]
+#[derive(Clone, Debug, Default)]
+pub struct SlugGenerator {
+  /// Filter producing the base slug; its `separator` and `max_length` also apply
+  /// to the suffixed candidates.
+  pub filter: SlugFilter,
+}
+
+impl SlugGenerator {
+  /// Creates a generator that slugs values with `filter`.
+  pub fn new(filter: SlugFilter) -> Self {
+    SlugGenerator { filter }
+  }
+
+  /// Returns the first slug for `value` for which `exists` returns `false`.
+  ///
+  /// A `value` with no slug characters yields an empty base slug, which is
+  /// checked (and suffixed, as `2`, `3`, …) like any other.
+  pub fn generate<F>(&self, value: &str, mut exists: F) -> String
+  where
+    F: FnMut(&str) -> bool,
+  {
+    let base = self.filter.filter(Cow::Borrowed(value));
+    let mut candidate = base.to_string();
+    let mut n = 1;
+    while exists(&candidate) {
+      n += 1;
+      candidate = self.candidate(&base, n);
+    }
+    candidate
+  }
+
+  /// Async variant of [`generate`](Self::generate), for existence checks that
+  /// query a database or remote service.
+  ///
+  /// Requires the `async` feature.
+  #[cfg(feature = "async")]
+  pub async fn generate_async<F, Fut>(&self, value: &str, mut exists: F) -> String
+  where
+    F: FnMut(&str) -> Fut,
+    Fut: std::future::Future<Output = bool>,
+  {
+    let base = self.filter.filter(Cow::Borrowed(value));
+    let mut candidate = base.to_string();
+    let mut n = 1;
+    while exists(&candidate).await {
+      n += 1;
+      candidate = self.candidate(&base, n);
+    }
+    candidate
+  }
+
+  /// `base` with the numeric suffix `n`, shortening `base` to fit `max_length`.
+  fn candidate(&self, base: &str, n: u64) -> String {
+    let separator = effective_separator(self.filter.separator);
+    let suffix = n.to_string();
+    let room = self.filter.max_length.saturating_sub(suffix.len() + 1);
+    let end = base.char_indices().nth(room).map_or(base.len(), |(i, _)| i);
+    let stem = base[..end].trim_end_matches(separator);
+    if stem.is_empty() {
+      suffix
     } else {
-      _to_pretty_slug(get_slug_filter_regex(), self.max_length, xs)
+      format!("{stem}{separator}{suffix}")
     }
   }
 }
@@ -212,6 +478,7 @@ mod test {
     let slug_filter = SlugFilter {
       max_length: 200,
       allow_duplicate_dashes: true,
+      ..Default::default()
     };
 
     assert_eq!(slug_filter(Cow::Borrowed("Hello World")), "hello-world");
@@ -328,6 +595,139 @@ mod test {
     );
   }
 
+  #[test]
+  fn test_pretty_slug_truncation_never_ends_with_dash() {
+    let filter = SlugFilter::new(6, false);
+    assert_eq!(filter.filter(Cow::Borrowed("Hello World")), "hello");
+    // Separator runs collapse before truncating, so more content fits.
+    let filter = SlugFilter::new(7, false);
+    assert_eq!(filter.filter(Cow::Borrowed("a -- b -- c -- d")), "a-b-c-d");
+  }
+
+  #[test]
+  fn test_custom_separator() {
+    let filter = |allow_duplicate_dashes, separator| SlugFilter {
+      allow_duplicate_dashes,
+      separator,
+      ..Default::default()
+    };
+    assert_eq!(
+      filter(false, '_').filter(Cow::Borrowed("Hello -- World")),
+      "hello_world"
+    );
+    assert_eq!(
+      filter(true, '.').filter(Cow::Borrowed("-Hello--World-")),
+      "hello..world"
+    );
+    assert_eq!(
+      filter(false, '·').filter(Cow::Borrowed("Hello World")),
+      "hello·world"
+    );
+    // Already a slug for this separator.
+    assert!(matches!(
+      filter(false, '.').filter(Cow::Borrowed("hello.world")),
+      Cow::Borrowed(_)
+    ));
+  }
+
+  #[test]
+  fn test_alphanumeric_separator_is_rejected() {
+    let err = SlugFilterBuilder::default()
+      .separator('x')
+      .build()
+      .unwrap_err();
+    assert!(err.to_string().contains("separator"));
+    assert!(SlugFilterBuilder::default().separator('_').build().is_ok());
+
+    // Set directly, an invalid separator falls back to `-`.
+    let filter = SlugFilter {
+      separator: 'é',
+      ..SlugFilter::new(200, false)
+    };
+    assert_eq!(filter.filter(Cow::Borrowed("Hello World")), "hello-world");
+    let generator = SlugGenerator::new(filter);
+    assert_eq!(generator.generate("Hello", |s| s == "hello"), "hello-2");
+  }
+
+  #[test]
+  fn test_stop_words() {
+    let filter = |allow_duplicate_dashes| SlugFilter {
+      allow_duplicate_dashes,
+      stop_words: vec!["the".into(), "in".into(), "a".into()],
+      ..Default::default()
+    };
+    assert_eq!(
+      filter(false).filter(Cow::Borrowed("The Cat in the Hat")),
+      "cat-hat"
+    );
+    assert_eq!(
+      filter(true).filter(Cow::Borrowed("The Cat in the Hat")),
+      "cat---hat"
+    );
+    // Stop words only match whole words.
+    assert_eq!(
+      filter(false).filter(Cow::Borrowed("Theater in Athens")),
+      "theater-athens"
+    );
+    // A slug that still contains a stop word is rebuilt.
+    assert_eq!(filter(false).filter(Cow::Borrowed("the-hat")), "hat");
+    // Input made only of stop words keeps them.
+    assert_eq!(filter(false).filter(Cow::Borrowed("In the")), "in-the");
+  }
+
+  #[test]
+  fn test_slug_max_length_with_multibyte_separator() {
+    let filter = SlugFilter {
+      max_length: 7,
+      allow_duplicate_dashes: false,
+      separator: '·',
+      ..Default::default()
+    };
+    assert_eq!(filter.filter(Cow::Borrowed("ab cd ef gh")), "ab·cd·e");
+  }
+
+  #[test]
+  fn test_slug_generator() {
+    let taken = ["post", "post-2", "a-long-title", "a-long-tit", "a-long-t-2"];
+    let exists = |slug: &str| taken.contains(&slug);
+
+    let generator = SlugGenerator::default();
+    assert_eq!(generator.generate("New", exists), "new");
+    assert_eq!(generator.generate("Post!", exists), "post-3");
+
+    // Suffixes fit within `max_length`, shortening the base slug.
+    let generator = SlugGenerator::new(SlugFilter::new(12, false));
+    assert_eq!(generator.generate("A long title", exists), "a-long-tit-2");
+    let generator = SlugGenerator::new(SlugFilter::new(10, false));
+    assert_eq!(generator.generate("A long title", exists), "a-long-t-3");
+    // The shortened base never ends in a separator.
+    let generator = SlugGenerator::new(SlugFilter::new(7, false));
+    assert_eq!(
+      generator.generate("post office", |s| s == "post-of"),
+      "post-2"
+    );
+
+    let generator = SlugGenerator::new(SlugFilter {
+      separator: '_',
+      ..Default::default()
+    });
+    assert_eq!(generator.generate("post", |s| s == "post"), "post_2");
+  }
+
+  #[cfg(feature = "async")]
+  #[tokio::test]
+  async fn test_slug_generator_async() {
+    let taken = ["post", "post-2"];
+    let generator = SlugGenerator::default();
+    let slug = generator
+      .generate_async("Post", |slug: &str| {
+        let exists = taken.contains(&slug);
+        async move { exists }
+      })
+      .await;
+    assert_eq!(slug, "post-3");
+  }
+
   #[cfg(feature = "fn_traits")]
   #[test]
   fn test_struct_in_threaded_contexts() {
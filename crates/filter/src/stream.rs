@@ -0,0 +1,658 @@
+//! Streaming application of [`FilterOp<String>`] pipelines over `io::Read`/`io::Write`.
+//!
+//! [`FilterWriter`] wraps a writer and filters UTF-8 text chunk-by-chunk as it is
+//! written, so large documents never have to be held in memory as a single `&str`.
+//! [`FilterOp::apply_stream`] drives a `FilterWriter` from a reader.
+//!
+//! Chunk boundaries are invisible in the output: the result is identical to calling
+//! [`FilterOp::apply_ref`] on the whole input. To guarantee that, each op keeps only
+//! the state it needs across chunks:
+//!
+//! - incomplete UTF-8 sequences at the end of a chunk are held until the next write;
+//! - `Trim` holds trailing whitespace until more text (or the end of input) arrives;
+//! - `NormalizeWhitespace` carries a pending-space flag;
+//! - `HtmlEntities` holds back a trailing `&…` that may still become an entity reference;
+//! - `Replace` holds back a tail shorter than `from` that may still begin a match;
+//! - `Lowercase` holds back the last whitespace-delimited word (final-sigma rule);
+//! - `Base64Encode` holds back up to two bytes of an incomplete 3-byte group;
+//! - `Truncate` counts characters across chunks (grapheme and display-width units,
+//!   suffixes, and word breaks need the whole value and are buffered).
+//!
+//! Char-local ops (`Uppercase`, `Digits`, `Alnum`, `Alpha`, `StripNewlines`,
+//! `AllowChars`, `DenyChars`, `UrlEncode`, `HexEncode`, `JsonEscape`, `JsEscape`)
+//! need no state. Ops that depend on the whole value (`StripTags`, `MarkdownToHtml`,
+//! `Slug`, `Case`, `SanitizeFilename`, `Mask`, `Redact`, `When`, `Custom`) buffer their input and run
+//! once the stream is finished; later ops in the chain still stream.
+
+use std::borrow::Cow;
+use std::io::{self, Read, Write};
+
+use crate::xml_entities::is_entity_prefix;
+use crate::{
+  Filter, FilterError, FilterErrorCode, FilterOp, TruncateUnit, XmlEntitiesFilter, encoding,
+};
+
+/// An [`io::Write`] adapter that applies a [`FilterOp<String>`] to the UTF-8 text
+/// written through it and forwards the result to an inner writer.
+///
+/// Call [`finish`](Self::finish) once all input has been written: it flushes text
+/// held back across chunk boundaries and reports truncated UTF-8 input. Dropping
+/// an unfinished `FilterWriter` finishes it on a best-effort basis, ignoring errors.
+///
+/// Input that is not valid UTF-8 fails with [`io::ErrorKind::InvalidData`], wrapping
+/// a [`FilterError`] whose offset is the position of the invalid byte in the stream.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::Write;
+/// use walrs_filter::{FilterOp, FilterWriter};
+///
+/// let op = FilterOp::<String>::Chain(vec![FilterOp::NormalizeWhitespace, FilterOp::HtmlEntities]);
+/// let mut writer = FilterWriter::new(&op, Vec::new());
+///
+/// // Chunks may split whitespace runs, entity references, and UTF-8 sequences.
+/// writer.write_all(b"  caf\xC3").unwrap();
+/// writer.write_all(b"\xA9   <b>&am").unwrap();
+/// writer.write_all(b"p;  ").unwrap();
+///
+/// let out = writer.finish().unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "café &lt;b&gt;&amp;");
+/// ```
+#[derive(Debug)]
+pub struct FilterWriter<W: Write> {
+  inner: Option<W>,
+  stages: Vec<StreamStage>,
+  /// Incomplete UTF-8 sequence left at the end of the last write.
+  utf8_tail: Vec<u8>,
+  /// Total number of bytes accepted so far, for error offsets.
+  bytes_in: usize,
+  scratch: [String; 2],
+}
+
+impl<W: Write> FilterWriter<W> {
+  /// Creates a writer that filters text through `op` before writing it to `inner`.
+  pub fn new(op: &FilterOp<String>, inner: W) -> Self {
+    let mut stages = Vec::new();
+    let mut stack = vec![op];
+    while let Some(op) = stack.pop() {
+      match op {
+        FilterOp::Chain(inner) => stack.extend(inner.iter().rev()),
+        FilterOp::Each(inner) => stack.push(inner),
+        other => stages.extend(StreamStage::new(other)),
+      }
+    }
+    Self {
+      inner: Some(inner),
+      stages,
+      utf8_tail: Vec::new(),
+      bytes_in: 0,
+      scratch: [String::new(), String::new()],
+    }
+  }
+
+  /// Returns a reference to the inner writer.
+  pub fn get_ref(&self) -> &W {
+    self
+      .inner
+      .as_ref()
+      .expect("inner writer is present until finished")
+  }
+
+  /// Flushes all held-back text through the pipeline and returns the inner writer.
+  ///
+  /// # Errors
+  ///
+  /// Returns an [`io::ErrorKind::InvalidData`] error if the input ended inside a
+  /// UTF-8 sequence, or any error from the inner writer.
+  pub fn finish(mut self) -> io::Result<W> {
+    self.finish_stages()?;
+    Ok(
+      self
+        .inner
+        .take()
+        .expect("inner writer is present until finished"),
+    )
+  }
+
+  fn finish_stages(&mut self) -> io::Result<()> {
+    if !self.utf8_tail.is_empty() {
+      let offset = self.bytes_in - self.utf8_tail.len();
+      self.utf8_tail.clear();
+      return Err(invalid_utf8(offset, "stream ended inside a UTF-8 sequence"));
+    }
+    self.process("", true)?;
+    self.inner_mut().flush()
+  }
+
+  fn inner_mut(&mut self) -> &mut W {
+    self
+      .inner
+      .as_mut()
+      .expect("inner writer is present until finished")
+  }
+
+  /// Runs `chunk` through every stage and writes the output to the inner writer.
+  fn process(&mut self, chunk: &str, finish: bool) -> io::Result<()> {
+    if self.stages.is_empty() {
+      return self.inner_mut().write_all(chunk.as_bytes());
+    }
+    let [mut input, mut output] = std::mem::take(&mut self.scratch);
+    input.clear();
+    input.push_str(chunk);
+    for stage in &mut self.stages {
+      output.clear();
+      stage.push(&input, &mut output);
+      if finish {
+        stage.finish(&mut output);
+      }
+      std::mem::swap(&mut input, &mut output);
+    }
+    let result = self.inner_mut().write_all(input.as_bytes());
+    self.scratch = [input, output];
+    result
+  }
+}
+
+impl<W: Write> Write for FilterWriter<W> {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    let start = self.bytes_in - self.utf8_tail.len();
+    let mut tail = std::mem::take(&mut self.utf8_tail);
+    let data: Cow<'_, [u8]> = if tail.is_empty() {
+      Cow::Borrowed(buf)
+    } else {
+      tail.extend_from_slice(buf);
+      Cow::Owned(tail)
+    };
+    let (text, valid) = match std::str::from_utf8(&data) {
+      Ok(text) => (text, data.len()),
+      Err(err) if err.error_len().is_some() => {
+        return Err(invalid_utf8(
+          start + err.valid_up_to(),
+          "invalid UTF-8 sequence",
+        ));
+      }
+      // The chunk ends inside a multi-byte sequence; keep it for the next write.
+      Err(err) => {
+        let valid = err.valid_up_to();
+        (
+          std::str::from_utf8(&data[..valid]).expect("valid up to here"),
+          valid,
+        )
+      }
+    };
+    self.bytes_in += buf.len();
+    self.process(text, false)?;
+    self.utf8_tail.extend_from_slice(&data[valid..]);
+    Ok(buf.len())
+  }
+
+  /// Flushes the inner writer. Text held back across chunk boundaries is only
+  /// emitted by [`finish`](FilterWriter::finish).
+  fn flush(&mut self) -> io::Result<()> {
+    self.inner_mut().flush()
+  }
+}
+
+impl<W: Write> Drop for FilterWriter<W> {
+  fn drop(&mut self) {
+    if self.inner.is_some() && !std::thread::panicking() {
+      let _ = self.finish_stages();
+    }
+  }
+}
+
+impl FilterOp<String> {
+  /// Streams UTF-8 text from `reader` through this filter into `writer`, chunk by chunk.
+  ///
+  /// The output is identical to [`apply_ref`](Self::apply_ref) on the whole input;
+  /// see [`FilterWriter`] for how state is carried across chunk boundaries.
+  /// Returns the number of bytes read.
+  ///
+  /// # Errors
+  ///
+  /// Returns an [`io::ErrorKind::InvalidData`] error if the input is not valid UTF-8,
+  /// or any error from `reader` or `writer`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use walrs_filter::FilterOp;
+  ///
+  /// let op = FilterOp::<String>::Chain(vec![FilterOp::StripNewlines, FilterOp::Uppercase]);
+  /// let mut out = Vec::new();
+  /// let read = op.apply_stream("one\ntwo\r\n".as_bytes(), &mut out).unwrap();
+  ///
+  /// assert_eq!(read, 9);
+  /// assert_eq!(out, b"ONETWO");
+  /// ```
+  pub fn apply_stream<R, W>(&self, mut reader: R, writer: &mut W) -> io::Result<u64>
+  where
+    R: Read,
+    W: Write + ?Sized,
+  {
+    let mut filtered = FilterWriter::new(self, writer);
+    let read = io::copy(&mut reader, &mut filtered)?;
+    filtered.finish()?;
+    Ok(read)
+  }
+}
+
+fn invalid_utf8(offset: usize, message: &str) -> io::Error {
+  io::Error::new(
+    io::ErrorKind::InvalidData,
+    FilterError::new(message)
+      .with_name("FilterWriter")
+      .with_code(FilterErrorCode::InvalidUtf8)
+      .with_offset(offset),
+  )
+}
+
+/// One op of a streaming pipeline, with the state it carries between chunks.
+#[derive(Debug)]
+enum StreamStage {
+  /// Char-local op applied to each chunk independently.
+  Map(FilterOp<String>),
+  Trim {
+    started: bool,
+    held: String,
+  },
+  NormalizeWhitespace {
+    started: bool,
+    pending_space: bool,
+  },
+  Lowercase {
+    held: String,
+  },
+  HtmlEntities {
+    filter: XmlEntitiesFilter<'static>,
+    held: String,
+  },
+  Replace {
+    from: String,
+    to: String,
+    held: String,
+  },
+  Truncate {
+    remaining: usize,
+  },
+  Base64Encode {
+    url_safe: bool,
+    padding: bool,
+    held: Vec<u8>,
+  },
+  /// Op that needs the whole value; applied when the stream finishes.
+  Buffered {
+    op: FilterOp<String>,
+    buf: String,
+  },
+}
+
+impl StreamStage {
+  /// Returns `None` for ops that are no-ops on a single string value.
+  fn new(op: &FilterOp<String>) -> Option<Self> {
+    let stage = match op {
+      FilterOp::Clamp { .. }
+      | FilterOp::Round { .. }
+      | FilterOp::Floor
+      | FilterOp::Ceil
+      | FilterOp::Abs
+      | FilterOp::SnapToStep { .. }
+      | FilterOp::NanTo(_)
+      | FilterOp::Split { .. }
+      | FilterOp::Join { .. }
+      | FilterOp::Dedup
+      | FilterOp::Sort
+      | FilterOp::RemoveEmpty
+      | FilterOp::Take(_) => return None,
+      FilterOp::Replace { from, .. } if from.is_empty() => return None,
+      FilterOp::Uppercase
+      | FilterOp::Digits
+      | FilterOp::Alnum { .. }
+      | FilterOp::Alpha { .. }
+      | FilterOp::StripNewlines
+      | FilterOp::AllowChars { .. }
+      | FilterOp::DenyChars { .. }
+      | FilterOp::UrlEncode { .. }
+      | FilterOp::HexEncode { .. }
+      | FilterOp::JsonEscape
+      | FilterOp::JsEscape => Self::Map(op.clone()),
+      FilterOp::Trim => Self::Trim {
+        started: false,
+        held: String::new(),
+      },
+      FilterOp::NormalizeWhitespace => Self::NormalizeWhitespace {
+        started: false,
+        pending_space: false,
+      },
+      FilterOp::Lowercase => Self::Lowercase {
+        held: String::new(),
+      },
+      FilterOp::HtmlEntities => Self::HtmlEntities {
+        filter: XmlEntitiesFilter::new(),
+        held: String::new(),
+      },
+      FilterOp::Replace { from, to } => Self::Replace {
+        from: from.clone(),
+        to: to.clone(),
+        held: String::new(),
+      },
+      FilterOp::Truncate {
+        max_length,
+        unit: TruncateUnit::Chars,
+        suffix,
+        break_at_word: false,
+      } if suffix.is_empty() => Self::Truncate {
+        remaining: *max_length,
+      },
+      FilterOp::Base64Encode { url_safe, padding } => Self::Base64Encode {
+        url_safe: *url_safe,
+        padding: *padding,
+        held: Vec::new(),
+      },
+      other => Self::Buffered {
+        op: other.clone(),
+        buf: String::new(),
+      },
+    };
+    Some(stage)
+  }
+
+  /// Filters `input`, appending whatever output is already final to `out`.
+  fn push(&mut self, input: &str, out: &mut String) {
+    match self {
+      Self::Map(op) => out.push_str(&op.apply_ref(input)),
+      Self::Trim { started, held } => {
+        let mut text = input;
+        if !*started {
+          text = text.trim_start();
+          if text.is_empty() {
+            return;
+          }
+          *started = true;
+        }
+        let body_end = text.trim_end().len();
+        if body_end > 0 {
+          out.push_str(held);
+          held.clear();
+          out.push_str(&text[..body_end]);
+        }
+        held.push_str(&text[body_end..]);
+      }
+      Self::NormalizeWhitespace {
+        started,
+        pending_space,
+      } => {
+        for c in input.chars() {
+          if c.is_whitespace() {
+            *pending_space |= *started;
+          } else {
+            if std::mem::take(pending_space) {
+              out.push(' ');
+            }
+            out.push(c);
+            *started = true;
+          }
+        }
+      }
+      Self::Lowercase { held } => {
+        // Lowercasing is context-free across whitespace, so everything up to the
+        // last whitespace char is final.
+        let text = join_held(held, input);
+        let split = text
+          .char_indices()
+          .rfind(|(_, c)| c.is_whitespace())
+          .map_or(0, |(i, c)| i + c.len_utf8());
+        out.push_str(&text[..split].to_lowercase());
+        held.push_str(&text[split..]);
+      }
+      Self::HtmlEntities { filter, held } => {
+        let text = join_held(held, input);
+        let split = match text.rfind('&') {
+          Some(amp) if is_entity_prefix(&text.as_bytes()[amp..]) => amp,
+          _ => text.len(),
+        };
+        out.push_str(&filter.filter(Cow::Borrowed(&text[..split])));
+        held.push_str(&text[split..]);
+      }
+      Self::Replace { from, to, held } => {
+        let text = join_held(held, input);
+        // A match starting at or after `keep` could extend into the next chunk.
+        let mut keep = text.len().saturating_sub(from.len() - 1);
+        while !text.is_char_boundary(keep) {
+          keep -= 1;
+        }
+        let mut last = 0;
+        for (i, _) in text.match_indices(from.as_str()) {
+          if i >= keep {
+            break;
+          }
+          out.push_str(&text[last..i]);
+          out.push_str(to);
+          last = i + from.len();
+        }
+        let split = keep.max(last);
+        out.push_str(&text[last..split]);
+        held.push_str(&text[split..]);
+      }
+      Self::Truncate { remaining } => match input.char_indices().nth(*remaining) {
+        Some((end, _)) => {
+          out.push_str(&input[..end]);
+          *remaining = 0;
+        }
+        None => {
+          out.push_str(input);
+          *remaining -= input.chars().count();
+        }
+      },
+      Self::Base64Encode { url_safe, held, .. } => {
+        held.extend_from_slice(input.as_bytes());
+        // Whole 3-byte groups encode without padding and never depend on later bytes.
+        let whole = held.len() / 3 * 3;
+        out.push_str(&encoding::base64_encode(&held[..whole], *url_safe, false));
+        held.drain(..whole);
+      }
+      Self::Buffered { buf, .. } => buf.push_str(input),
+    }
+  }
+
+  /// Appends the output for any held-back text at the end of the stream.
+  fn finish(&mut self, out: &mut String) {
+    match self {
+      Self::Map(_) | Self::NormalizeWhitespace { .. } | Self::Truncate { .. } => {}
+      // Trailing whitespace is dropped.
+      Self::Trim { held, .. } => held.clear(),
+      Self::Lowercase { held } => out.push_str(&std::mem::take(held).to_lowercase()),
+      Self::HtmlEntities { filter, held } => {
+        out.push_str(&filter.filter(Cow::Borrowed(held.as_str())));
+        held.clear();
+      }
+      Self::Replace { from, to, held } => {
+        out.push_str(&held.replace(from.as_str(), to));
+        held.clear();
+      }
+      Self::Base64Encode {
+        url_safe,
+        padding,
+        held,
+      } => {
+        out.push_str(&encoding::base64_encode(&held, *url_safe, *padding));
+        held.clear();
+      }
+      Self::Buffered { op, buf } => {
+        out.push_str(&op.apply_ref(buf));
+        buf.clear();
+      }
+    }
+  }
+}
+
+/// Prepends any held-back text to `input`, leaving `held` empty.
+fn join_held<'a>(held: &mut String, input: &'a str) -> Cow<'a, str> {
+  if held.is_empty() {
+    Cow::Borrowed(input)
+  } else {
+    let mut text = std::mem::take(held);
+    text.push_str(input);
+    Cow::Owned(text)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::CaseStyle;
+  use std::sync::Arc;
+
+  const INPUTS: &[&str] = &[
+    "",
+    "   ",
+    "hello",
+    "  Hello,\t  World!\r\n  ",
+    "ΟΔΥΣΣΕΥΣ Σ ΑΣ",
+    "Tom &amp; Jerry & <friends> &#x1F600; &#128512; &bogus &#xZZ; &",
+    "café — naïve 日本語 🦀 text\u{00A0}with\u{2028}separators",
+    "aaaaabaaab ab a b abab",
+    "<p onclick=\"x()\">Hi <script>alert(1)</script></p>",
+  ];
+
+  fn ops() -> Vec<FilterOp<String>> {
+    vec![
+      FilterOp::Trim,
+      FilterOp::Lowercase,
+      FilterOp::Uppercase,
+      FilterOp::NormalizeWhitespace,
+      FilterOp::StripNewlines,
+      FilterOp::HtmlEntities,
+      FilterOp::Digits,
+      FilterOp::Alnum {
+        allow_whitespace: true,
+      },
+      FilterOp::Replace {
+        from: "aab".into(),
+        to: "X".into(),
+      },
+      FilterOp::Replace {
+        from: "é".into(),
+        to: "e".into(),
+      },
+      FilterOp::Truncate {
+        max_length: 7,
+        unit: TruncateUnit::Chars,
+        suffix: String::new(),
+        break_at_word: false,
+      },
+      FilterOp::UrlEncode {
+        encode_unreserved: false,
+      },
+      FilterOp::Base64Encode {
+        url_safe: false,
+        padding: true,
+      },
+      FilterOp::Base64Encode {
+        url_safe: true,
+        padding: false,
+      },
+      FilterOp::HexEncode { uppercase: false },
+      FilterOp::JsEscape,
+      FilterOp::StripTags,
+      FilterOp::Slug {
+        max_length: Some(12),
+        separator: '-',
+        stop_words: Vec::new(),
+      },
+      FilterOp::Case(CaseStyle::Snake),
+      FilterOp::Custom(Arc::new(|s: String| s.chars().rev().collect())),
+      FilterOp::Chain(vec![
+        FilterOp::StripTags,
+        FilterOp::NormalizeWhitespace,
+        FilterOp::HtmlEntities,
+        FilterOp::Trim,
+      ]),
+      FilterOp::Chain(vec![
+        FilterOp::Each(Box::new(FilterOp::Lowercase)),
+        FilterOp::Dedup,
+        FilterOp::Replace {
+          from: "σ".into(),
+          to: "s".into(),
+        },
+        FilterOp::Truncate {
+          max_length: 20,
+          unit: TruncateUnit::Chars,
+          suffix: String::new(),
+          break_at_word: false,
+        },
+      ]),
+      FilterOp::Truncate {
+        max_length: 9,
+        unit: TruncateUnit::Graphemes,
+        suffix: "…".into(),
+        break_at_word: true,
+      },
+    ]
+  }
+
+  /// Streams `input` through `op` in chunks of `chunk_size` bytes.
+  fn stream(op: &FilterOp<String>, input: &str, chunk_size: usize) -> String {
+    let mut writer = FilterWriter::new(op, Vec::new());
+    for chunk in input.as_bytes().chunks(chunk_size) {
+      writer.write_all(chunk).unwrap();
+    }
+    String::from_utf8(writer.finish().unwrap()).unwrap()
+  }
+
+  #[test]
+  fn test_matches_apply_ref_for_every_chunk_size() {
+    for op in ops() {
+      for input in INPUTS {
+        let expected = op.apply_ref(input);
+        for chunk_size in 1..=input.len().max(1) {
+          assert_eq!(
+            stream(&op, input, chunk_size),
+            expected,
+            "{op:?} on {input:?} in {chunk_size}-byte chunks"
+          );
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn test_apply_stream() {
+    let op = FilterOp::<String>::Chain(vec![FilterOp::Trim, FilterOp::NormalizeWhitespace]);
+    let input = format!("  {}  ", "word \n\t ".repeat(10_000));
+    let mut out = Vec::new();
+    let read = op.apply_stream(input.as_bytes(), &mut out).unwrap();
+    assert_eq!(read, input.len() as u64);
+    assert_eq!(String::from_utf8(out).unwrap(), op.apply_ref(&input));
+  }
+
+  #[test]
+  fn test_invalid_utf8_is_rejected_with_offset() {
+    let mut writer = FilterWriter::new(&FilterOp::Trim, Vec::new());
+    writer.write_all(b"ok ").unwrap();
+    let err = writer.write_all(b"bad \xFF").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    let filter_err = err
+      .get_ref()
+      .unwrap()
+      .downcast_ref::<FilterError>()
+      .unwrap();
+    assert_eq!(filter_err.offset(), Some(7));
+
+    let mut writer = FilterWriter::new(&FilterOp::Trim, Vec::new());
+    writer.write_all(b"caf\xC3").unwrap();
+    let err = writer.finish().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+  }
+
+  #[test]
+  fn test_held_text_is_emitted_on_drop() {
+    let mut out = Vec::new();
+    {
+      let mut writer = FilterWriter::new(&FilterOp::HtmlEntities, &mut out);
+      writer.write_all(b"a & b &am").unwrap();
+    }
+    assert_eq!(out, b"a &amp; b &amp;am");
+  }
+}
@@ -0,0 +1,298 @@
+//! Per-step change tracing backing [`FilterOp::apply_traced`] and
+//! [`TryFilterOp::try_apply_traced`].
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+use crate::{FilterError, FilterOp, TryFilterOp};
+
+/// How much of each value a [`FilterTrace`] records for steps that changed it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TraceValues {
+  /// Record the before and after values verbatim.
+  #[default]
+  Full,
+
+  /// Record the values with every character replaced by `*`, keeping only their
+  /// length — for inputs that may hold personal data.
+  Masked,
+
+  /// Record only which steps changed the value, never the values themselves.
+  Omitted,
+}
+
+impl TraceValues {
+  fn record(self, value: &str) -> Option<String> {
+    match self {
+      TraceValues::Full => Some(value.to_string()),
+      TraceValues::Masked => Some("*".repeat(value.chars().count())),
+      TraceValues::Omitted => None,
+    }
+  }
+}
+
+/// One filter step of a [`FilterTrace`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilterStep {
+  /// The op's variant name (see [`FilterOp::name`]).
+  pub op: String,
+
+  /// Whether the step altered the value.
+  pub changed: bool,
+
+  /// The value before the step; only set when it `changed` and values are recorded.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub before: Option<String>,
+
+  /// The value after the step; only set when it `changed` and values are recorded.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub after: Option<String>,
+}
+
+/// The record of every step a traced filter applied, in order.
+///
+/// `Chain`s are flattened, so each step is a single (non-chain) op.
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_filter::{FilterOp, TraceValues};
+///
+/// let op = FilterOp::<String>::Chain(vec![FilterOp::Trim, FilterOp::Lowercase]);
+/// let (value, trace) = op.apply_traced("hello  ".to_string(), TraceValues::Full);
+///
+/// assert_eq!(value, "hello");
+/// assert!(trace.is_changed());
+/// assert_eq!(trace.steps[0].before.as_deref(), Some("hello  "));
+/// assert!(!trace.steps[1].changed);
+/// assert_eq!(
+///     serde_json::to_string(&trace.steps).unwrap(),
+///     r#"[{"op":"Trim","changed":true,"before":"hello  ","after":"hello"},{"op":"Lowercase","changed":false}]"#
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilterTrace {
+  /// How much of each value is recorded.
+  #[serde(skip)]
+  pub values: TraceValues,
+
+  /// The applied steps, in order.
+  pub steps: Vec<FilterStep>,
+}
+
+impl FilterTrace {
+  /// An empty trace recording values per `values`.
+  pub fn new(values: TraceValues) -> Self {
+    Self {
+      values,
+      steps: Vec::new(),
+    }
+  }
+
+  /// Whether any step changed the value.
+  pub fn is_changed(&self) -> bool {
+    self.steps.iter().any(|step| step.changed)
+  }
+
+  /// The steps that changed the value.
+  pub fn changed_steps(&self) -> impl Iterator<Item = &FilterStep> {
+    self.steps.iter().filter(|step| step.changed)
+  }
+
+  /// Appends a step for `op`, turning `before` into `after`.
+  fn push(&mut self, op: &'static str, before: &str, after: Option<&str>) {
+    let changed = after.is_some_and(|after| after != before);
+    let (before, after) = match after {
+      Some(after) if changed => (self.values.record(before), self.values.record(after)),
+      _ => (None, None),
+    };
+    self.steps.push(FilterStep {
+      op: op.to_string(),
+      changed,
+      before,
+      after,
+    });
+  }
+}
+
+impl FilterOp<String> {
+  /// Apply the filter like [`apply`](Self::apply), also returning a [`FilterTrace`]
+  /// recording which step changed the value and how.
+  pub fn apply_traced(&self, value: String, values: TraceValues) -> (String, FilterTrace) {
+    let mut trace = FilterTrace::new(values);
+    let value = self.apply_traced_into(value, &mut trace);
+    (value, trace)
+  }
+
+  /// Applies `self`, appending its steps to `trace`.
+  fn apply_traced_into(&self, value: String, trace: &mut FilterTrace) -> String {
+    let ops = match self {
+      FilterOp::Chain(filters) => crate::filter_op::flatten_chain(filters),
+      op => vec![op],
+    };
+    ops
+      .into_iter()
+      .fold(value, |value, op| match op.apply_ref(&value) {
+        Cow::Borrowed(_) => {
+          trace.push(op.name(), &value, None);
+          value
+        }
+        Cow::Owned(after) => {
+          trace.push(op.name(), &value, Some(&after));
+          after
+        }
+      })
+  }
+}
+
+impl TryFilterOp<String> {
+  /// Apply the filter like [`try_apply`](Self::try_apply), also returning a
+  /// [`FilterTrace`] recording which step changed the value and how.
+  ///
+  /// `Infallible` ops are traced as the wrapped [`FilterOp`].
+  ///
+  /// # Errors
+  ///
+  /// Returns the first step's [`FilterError`]; the partial trace is discarded.
+  pub fn try_apply_traced(
+    &self,
+    value: String,
+    values: TraceValues,
+  ) -> Result<(String, FilterTrace), FilterError> {
+    let mut trace = FilterTrace::new(values);
+    let value = self.try_apply_traced_into(value, &mut trace)?;
+    Ok((value, trace))
+  }
+
+  /// Applies `self`, appending its steps to `trace`.
+  fn try_apply_traced_into(
+    &self,
+    value: String,
+    trace: &mut FilterTrace,
+  ) -> Result<String, FilterError> {
+    let ops = match self {
+      TryFilterOp::Chain(ops) => crate::try_filter_op::flatten_try_chain(ops),
+      op => vec![op],
+    };
+    ops
+      .into_iter()
+      .enumerate()
+      .try_fold(value, |value, (i, op)| {
+        if let TryFilterOp::Infallible(op) = op {
+          op.ensure_bound().map_err(|e| match self {
+            TryFilterOp::Chain(ops) => crate::try_filter_op::locate_chain_error(ops, i, e),
+            _ => e,
+          })?;
+          return Ok(op.apply_traced_into(value, trace));
+        }
+        let applied = op.try_apply_ref(&value).map_err(|e| match self {
+          TryFilterOp::Chain(ops) => crate::try_filter_op::locate_chain_error(ops, i, e),
+          _ => e,
+        })?;
+        Ok(match applied {
+          Cow::Borrowed(_) => {
+            trace.push(op.name(), &value, None);
+            value
+          }
+          Cow::Owned(after) => {
+            trace.push(op.name(), &value, Some(&after));
+            after
+          }
+        })
+      })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::Arc;
+
+  #[test]
+  fn test_nested_chains_are_flattened() {
+    let op = FilterOp::<String>::Chain(vec![
+      FilterOp::Trim,
+      FilterOp::Chain(vec![
+        FilterOp::Lowercase,
+        FilterOp::Slug {
+          max_length: None,
+          separator: '-',
+          stop_words: Vec::new(),
+        },
+      ]),
+    ]);
+    let (value, trace) = op.apply_traced(" Hello World ".to_string(), TraceValues::Full);
+    assert_eq!(value, "hello-world");
+    let ops: Vec<&str> = trace.steps.iter().map(|step| step.op.as_str()).collect();
+    assert_eq!(ops, ["Trim", "Lowercase", "Slug"]);
+    assert!(trace.steps.iter().all(|step| step.changed));
+    assert_eq!(trace.steps[2].before.as_deref(), Some("hello world"));
+    assert_eq!(trace.steps[2].after.as_deref(), Some("hello-world"));
+  }
+
+  #[test]
+  fn test_unchanged_steps_record_no_values() {
+    // `Custom` always allocates; an identical result is still not a change.
+    let op = FilterOp::<String>::Chain(vec![FilterOp::Trim, FilterOp::Custom(Arc::new(|s| s))]);
+    let (value, trace) = op.apply_traced("clean".to_string(), TraceValues::Full);
+    assert_eq!(value, "clean");
+    assert!(!trace.is_changed());
+    assert_eq!(trace.changed_steps().count(), 0);
+    assert!(
+      trace
+        .steps
+        .iter()
+        .all(|step| step.before.is_none() && step.after.is_none())
+    );
+  }
+
+  #[test]
+  fn test_masked_and_omitted_values() {
+    let op = FilterOp::<String>::Redact {
+      detectors: vec![crate::RedactDetector::Email],
+      mask_char: '#',
+    };
+    let (value, trace) = op.apply_traced("a@b.io".to_string(), TraceValues::Masked);
+    assert_eq!(value, "######");
+    let step = &trace.steps[0];
+    assert!(step.changed);
+    assert_eq!(step.before.as_deref(), Some("******"));
+    assert_eq!(step.after.as_deref(), Some("******"));
+
+    let (_, trace) = op.apply_traced("a@b.io".to_string(), TraceValues::Omitted);
+    assert!(trace.steps[0].changed);
+    assert_eq!(trace.steps[0].before, None);
+    assert_eq!(
+      serde_json::to_string(&trace).unwrap(),
+      r#"{"steps":[{"op":"Redact","changed":true}]}"#
+    );
+  }
+
+  #[test]
+  fn test_try_apply_traced() {
+    let op = TryFilterOp::<String>::Chain(vec![
+      TryFilterOp::Infallible(FilterOp::Chain(vec![FilterOp::Trim, FilterOp::Lowercase])),
+      TryFilterOp::ToBool,
+    ]);
+    let (value, trace) = op
+      .try_apply_traced(" YES ".to_string(), TraceValues::Full)
+      .unwrap();
+    assert_eq!(value, "true");
+    let ops: Vec<&str> = trace.steps.iter().map(|step| step.op.as_str()).collect();
+    assert_eq!(ops, ["Trim", "Lowercase", "ToBool"]);
+    assert_eq!(trace.steps[2].before.as_deref(), Some("yes"));
+
+    let err = op
+      .try_apply_traced("maybe".to_string(), TraceValues::Full)
+      .unwrap_err();
+    assert_eq!(err.filter_name(), Some("ToBool"));
+  }
+
+  #[test]
+  fn test_trace_round_trips() {
+    let (_, trace) = FilterOp::<String>::Uppercase.apply_traced("a".to_string(), TraceValues::Full);
+    let json = serde_json::to_string(&trace).unwrap();
+    let back: FilterTrace = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, trace);
+  }
+}
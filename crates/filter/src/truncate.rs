@@ -0,0 +1,223 @@
+//! Truncation backing [`FilterOp::Truncate`](crate::FilterOp::Truncate).
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// What [`FilterOp::Truncate`](crate::FilterOp::Truncate) counts toward `max_length`.
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_filter::{FilterOp, TruncateUnit};
+///
+/// let truncate = |unit| FilterOp::<String>::Truncate {
+///     max_length: 4,
+///     unit,
+///     suffix: String::new(),
+///     break_at_word: false,
+/// };
+///
+/// // "e" + combining acute accent is one grapheme but two chars.
+/// let value = "cafe\u{301}!";
+/// assert_eq!(truncate(TruncateUnit::Chars).apply_ref(value), "cafe");
+/// assert_eq!(truncate(TruncateUnit::Graphemes).apply_ref(value), "cafe\u{301}");
+///
+/// // Wide (East Asian) characters take two terminal columns.
+/// assert_eq!(truncate(TruncateUnit::DisplayWidth).apply_ref("日本語"), "日本");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TruncateUnit {
+  /// Unicode scalar values (`char`s).
+  #[default]
+  Chars,
+
+  /// Extended grapheme clusters, so emoji ZWJ sequences, flags, and combining marks
+  /// are never split.
+  Graphemes,
+
+  /// Display columns: East Asian wide and fullwidth characters (and most emoji)
+  /// count as two, zero-width characters as none. Grapheme clusters are never split.
+  DisplayWidth,
+}
+
+impl TruncateUnit {
+  /// Length of `value` in this unit.
+  fn measure(self, value: &str) -> usize {
+    match self {
+      TruncateUnit::Chars => value.chars().count(),
+      TruncateUnit::Graphemes => value.graphemes(true).count(),
+      TruncateUnit::DisplayWidth => value.graphemes(true).map(UnicodeWidthStr::width).sum(),
+    }
+  }
+
+  /// Byte index of the longest prefix of `value` measuring at most `max_length`.
+  fn cut(self, value: &str, max_length: usize) -> usize {
+    let mut used = 0;
+    let mut fits = |end: usize, cost: usize| {
+      used += cost;
+      (used <= max_length).then_some(end)
+    };
+    match self {
+      TruncateUnit::Chars => value
+        .char_indices()
+        .map_while(|(i, c)| fits(i + c.len_utf8(), 1))
+        .last(),
+      TruncateUnit::Graphemes => value
+        .grapheme_indices(true)
+        .map_while(|(i, g)| fits(i + g.len(), 1))
+        .last(),
+      TruncateUnit::DisplayWidth => value
+        .grapheme_indices(true)
+        .map_while(|(i, g)| fits(i + g.len(), g.width()))
+        .last(),
+    }
+    .unwrap_or(0)
+  }
+}
+
+/// Moves the byte index `cut` back to the start of the word it splits, if any.
+/// A word that begins the value is cut as-is rather than dropped entirely.
+fn word_boundary(value: &str, cut: usize) -> usize {
+  value
+    .split_word_bound_indices()
+    .find(|(start, word)| start + word.len() > cut)
+    .map_or(cut, |(start, _)| if start > 0 { start } else { cut })
+}
+
+/// Clips `value` to at most `max_length` units, reserving room for `suffix`, which
+/// is appended whenever the value is clipped.
+///
+/// Returns `Cow::Borrowed` when `value` already fits.
+pub(crate) fn truncate<'a>(
+  value: &'a str,
+  max_length: usize,
+  unit: TruncateUnit,
+  suffix: &str,
+  break_at_word: bool,
+) -> Cow<'a, str> {
+  if unit.measure(value) <= max_length {
+    return Cow::Borrowed(value);
+  }
+  let suffix_length = unit.measure(suffix);
+  if suffix_length > max_length {
+    return Cow::Owned(suffix[..unit.cut(suffix, max_length)].to_string());
+  }
+
+  let mut cut = unit.cut(value, max_length - suffix_length);
+  let mut kept = &value[..cut];
+  if break_at_word {
+    cut = word_boundary(value, cut);
+    kept = value[..cut].trim_end();
+  }
+  Cow::Owned(format!("{kept}{suffix}"))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_fitting_values_are_borrowed() {
+    for unit in [
+      TruncateUnit::Chars,
+      TruncateUnit::Graphemes,
+      TruncateUnit::DisplayWidth,
+    ] {
+      assert!(matches!(
+        truncate("hello", 5, unit, "…", true),
+        Cow::Borrowed("hello")
+      ));
+    }
+  }
+
+  #[test]
+  fn test_graphemes_are_not_split() {
+    let family = "👨\u{200D}👩\u{200D}👧";
+    let value = format!("{family}{family}x");
+    assert_eq!(family.chars().count(), 5);
+    assert_eq!(
+      truncate(&value, 1, TruncateUnit::Graphemes, "", false),
+      family
+    );
+    assert_eq!(
+      truncate("🇫🇷🇩🇪", 1, TruncateUnit::Graphemes, "", false),
+      "🇫🇷"
+    );
+    // Char counting splits the sequence.
+    assert_eq!(truncate(&value, 1, TruncateUnit::Chars, "", false), "👨");
+  }
+
+  #[test]
+  fn test_display_width() {
+    assert_eq!(UnicodeWidthStr::width("日本語"), 6);
+    assert_eq!(
+      truncate("日本語", 5, TruncateUnit::DisplayWidth, "", false),
+      "日本"
+    );
+    assert_eq!(
+      truncate("日本語", 5, TruncateUnit::DisplayWidth, "…", false),
+      "日本…"
+    );
+    assert_eq!(
+      truncate("ab日本", 3, TruncateUnit::DisplayWidth, "", false),
+      "ab"
+    );
+    // Combining marks take no columns and stay with their base character.
+    assert_eq!(
+      truncate(
+        "e\u{301}e\u{301}e\u{301}",
+        2,
+        TruncateUnit::DisplayWidth,
+        "",
+        false
+      ),
+      "e\u{301}e\u{301}"
+    );
+  }
+
+  #[test]
+  fn test_suffix_counts_toward_the_limit() {
+    assert_eq!(
+      truncate("Hello, world", 8, TruncateUnit::Chars, "…", false),
+      "Hello, …"
+    );
+    assert_eq!(
+      truncate("Hello, world", 8, TruncateUnit::Chars, "...", false),
+      "Hello..."
+    );
+    // A suffix longer than the limit is itself clipped.
+    assert_eq!(
+      truncate("Hello", 2, TruncateUnit::Chars, "...", false),
+      ".."
+    );
+  }
+
+  #[test]
+  fn test_break_at_word() {
+    let value = "The quick brown fox";
+    assert_eq!(
+      truncate(value, 12, TruncateUnit::Chars, "…", true),
+      "The quick…"
+    );
+    assert_eq!(
+      truncate(value, 15, TruncateUnit::Chars, "", true),
+      "The quick brown"
+    );
+    assert_eq!(
+      truncate(value, 14, TruncateUnit::Chars, "", true),
+      "The quick"
+    );
+    // A single long word is cut rather than dropped.
+    assert_eq!(
+      truncate("Supercalifragilistic", 6, TruncateUnit::Chars, "…", true),
+      "Super…"
+    );
+    // Ideographs are separate words.
+    assert_eq!(
+      truncate("日本語のテキスト", 7, TruncateUnit::DisplayWidth, "…", true),
+      "日本語…"
+    );
+  }
+}
@@ -5,13 +5,32 @@
 //! [`FilterOp<T>`](crate::FilterOp), allowing filters that can fail to
 //! participate in the same processing pipeline.
 
-use percent_encoding::percent_decode_str;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fmt::{self, Debug};
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::ops::Range;
+#[cfg(feature = "async")]
+use std::pin::Pin;
 use std::sync::Arc;
 
-use crate::{FilterError, FilterOp};
+#[cfg(any(feature = "chrono", feature = "jiff"))]
+use crate::DateAmbiguity;
+#[cfg(any(feature = "chrono", feature = "jiff"))]
+use crate::date::normalize_date;
+use crate::email::normalize_email;
+use crate::encoding;
+#[cfg(feature = "phone")]
+use crate::phone::normalize_phone;
+use crate::url_canonical::canonicalize_url;
+use crate::xml_entities::decode_entities;
+use crate::{
+  Binding, EmailNormalizeOptions, FilterError, FilterErrorCode, FilterOp, NamedTryFilterFn,
+  UrlCanonicalOptions,
+};
+#[cfg(any(feature = "chrono", feature = "jiff"))]
+use walrs_validation::DateFormat;
 
 /// Parse a permissive boolean literal (case-insensitive).
 ///
@@ -34,14 +53,40 @@ fn parse_bool_literal(s: &str) -> Result<bool, FilterError> {
   {
     return Ok(false);
   }
-  Err(FilterError::new(format!("cannot parse {s:?} as bool")).with_name("ToBool"))
+  Err(
+    FilterError::new(format!("cannot parse {s:?} as bool"))
+      .with_name("ToBool")
+      .with_code(FilterErrorCode::NotABoolean)
+      .with_span(trimmed_span(s)),
+  )
+}
+
+/// The byte range of `s` without its surrounding whitespace.
+fn trimmed_span(s: &str) -> Range<usize> {
+  let start = s.len() - s.trim_start().len();
+  start..start + s.trim().len()
+}
+
+/// Type alias for the async custom filter function used by `TryFilterOp::TryCustomAsync`.
+///
+/// The closure takes the value by ownership, so the returned future may outlive the call.
+#[cfg(feature = "async")]
+pub type TryCustomAsyncFn<T> =
+  Arc<dyn Fn(T) -> Pin<Box<dyn Future<Output = Result<T, FilterError>> + Send>> + Send + Sync>;
+
+/// The error a synchronous `try_apply*` returns for a `TryCustomAsync` step.
+#[cfg(feature = "async")]
+fn async_only_error() -> FilterError {
+  FilterError::new("async filter applied synchronously")
+    .with_name("TryCustomAsync")
+    .with_code(FilterErrorCode::AsyncOnly)
 }
 
 /// Iteratively flatten nested `Chain` variants into a list of non-chain operation references.
 ///
 /// Prevents stack overflow when deeply nested `TryFilterOp::Chain(vec![TryFilterOp::Chain(…)])`
 /// values are applied.
-fn flatten_try_chain<T>(ops: &[TryFilterOp<T>]) -> Vec<&TryFilterOp<T>> {
+pub(crate) fn flatten_try_chain<T>(ops: &[TryFilterOp<T>]) -> Vec<&TryFilterOp<T>> {
   let mut flat = Vec::new();
   let mut stack: Vec<&TryFilterOp<T>> = ops.iter().rev().collect();
   while let Some(op) = stack.pop() {
@@ -54,6 +99,38 @@ fn flatten_try_chain<T>(ops: &[TryFilterOp<T>]) -> Vec<&TryFilterOp<T>> {
   flat
 }
 
+/// Records in `err` where the `leaf`-th step of `flatten_try_chain(ops)` sits within the
+/// nested chains of `ops`, by prepending its index path.
+///
+/// Walks the chain only when a step has failed, so the success path keeps the flat list.
+pub(crate) fn locate_chain_error<T>(
+  ops: &[TryFilterOp<T>],
+  leaf: usize,
+  err: FilterError,
+) -> FilterError {
+  let mut seen = 0;
+  let mut stack: Vec<(Vec<usize>, &TryFilterOp<T>)> = ops
+    .iter()
+    .enumerate()
+    .rev()
+    .map(|(i, op)| (vec![i], op))
+    .collect();
+  while let Some((path, op)) = stack.pop() {
+    if let TryFilterOp::Chain(inner) = op {
+      stack.extend(inner.iter().enumerate().rev().map(|(i, op)| {
+        let mut path = path.clone();
+        path.push(i);
+        (path, op)
+      }));
+    } else if seen == leaf {
+      return path.into_iter().rev().fold(err, FilterError::with_step);
+    } else {
+      seen += 1;
+    }
+  }
+  err
+}
+
 /// A composable, fallible value transformer.
 ///
 /// `TryFilterOp` provides a way to define fallible filter operations that can
@@ -66,6 +143,7 @@ fn flatten_try_chain<T>(ops: &[TryFilterOp<T>]) -> Vec<&TryFilterOp<T>> {
 /// - [`Infallible`](Self::Infallible) — wraps an infallible `FilterOp`, lifting it into the fallible pipeline
 /// - [`Chain`](Self::Chain) — applies filters sequentially, short-circuiting on the first error
 /// - [`TryCustom`](Self::TryCustom) — custom fallible filter function
+/// - `TryCustomAsync` — async custom fallible filter function (requires the `async` feature)
 ///
 /// # Example
 ///
@@ -135,6 +213,124 @@ pub enum TryFilterOp<T> {
   /// Errors when the decoded bytes are not valid UTF-8.
   UrlDecode,
 
+  /// Decode XML/HTML entity references — the inverse of
+  /// [`FilterOp::HtmlEntities`] / [`XmlEntitiesFilter`](crate::XmlEntitiesFilter).
+  ///
+  /// Decodes decimal (`&#39;`) and hex (`&#x2F;`) character references, the five XML
+  /// entities, and common HTML entities (`&nbsp;`, `&copy;`, `&mdash;`, …). A bare `&`
+  /// that does not start an entity reference is left as-is.
+  ///
+  /// Errors, with the byte offset of the reference, on unknown named entities and on
+  /// numeric references that do not denote a Unicode scalar value.
+  HtmlEntityDecode,
+
+  /// Base64-decode the value and interpret the bytes as UTF-8. `=` padding is optional.
+  ///
+  /// Errors, with the byte offset of the offending character, on bytes outside the
+  /// alphabet, on invalid lengths, and when the decoded bytes are not valid UTF-8.
+  Base64Decode {
+    /// Use the URL- and filename-safe alphabet (`-`, `_`) instead of the standard one (`+`, `/`).
+    url_safe: bool,
+  },
+
+  /// Hex-decode the value (case-insensitive) and interpret the bytes as UTF-8.
+  ///
+  /// Errors, with a byte offset, on non-hex characters, an odd number of digits, and
+  /// when the decoded bytes are not valid UTF-8.
+  HexDecode,
+
+  /// Canonicalize an absolute URL so equivalent links compare equal.
+  ///
+  /// Lowercases the scheme and host, converts internationalized hosts to punycode,
+  /// removes default ports and `.`/`..` path segments, then applies
+  /// [`UrlCanonicalOptions`]: query sorting, tracking-parameter removal, fragment
+  /// removal, and optionally `http` → `https`.
+  ///
+  /// Errors when the value is not an absolute URL.
+  CanonicalizeUrl(UrlCanonicalOptions),
+
+  /// Normalize an email address for uniqueness checks.
+  ///
+  /// Trims surrounding whitespace, then applies [`EmailNormalizeOptions`]: domain
+  /// lowercasing and IDN → punycode conversion (on by default), local-part
+  /// lowercasing, and the provider-specific `+tag` stripping and Gmail dot removal.
+  ///
+  /// Errors when the value has no `@`, an empty local part or domain, or (with
+  /// `idn_to_ascii`) a domain that is not a valid hostname.
+  NormalizeEmail(EmailNormalizeOptions),
+
+  /// Normalize a phone number to E.164 (`+14155550132`) (requires the `phone` feature).
+  ///
+  /// Numbers without a leading `+` or international dialing prefix are read in
+  /// `default_region`. Extensions are dropped.
+  ///
+  /// Errors when the value cannot be parsed, is not a valid number for its region,
+  /// or `default_region` is not a known region code.
+  #[cfg(feature = "phone")]
+  NormalizePhone {
+    /// ISO 3166-1 alpha-2 region code (case-insensitive) for national-format numbers,
+    /// e.g. `"US"`.
+    default_region: Option<String>,
+  },
+
+  /// Parse a date in any of the `accept` formats and rewrite it in the `output` format
+  /// (requires the `chrono` or `jiff` feature).
+  ///
+  /// Formats are the [`DateFormat`] presets used by `Rule::Date`/`Rule::DateRange`, so a
+  /// date field can be canonicalized before its rules run. Surrounding whitespace is
+  /// ignored. Input that parses to different dates under several formats is resolved by
+  /// `ambiguity` (by default it is rejected). [`DateFormat::Rfc2822`] output uses
+  /// midnight UTC.
+  ///
+  /// Errors when no accepted format parses the value, when it is ambiguous under
+  /// [`DateAmbiguity::Reject`], and when `output` is a `Custom` format that cannot be
+  /// written for a date (e.g. it contains time fields).
+  ///
+  /// ```rust
+  /// use walrs_filter::{DateAmbiguity, TryFilterOp};
+  /// use walrs_validation::DateFormat;
+  ///
+  /// let op: TryFilterOp<String> = TryFilterOp::NormalizeDate {
+  ///     accept: vec![DateFormat::Iso8601, DateFormat::UsDate],
+  ///     output: DateFormat::Iso8601,
+  ///     ambiguity: DateAmbiguity::Reject,
+  /// };
+  ///
+  /// assert_eq!(op.try_apply("2/3/2026".to_string()).unwrap(), "2026-02-03");
+  /// assert!(op.try_apply("3 Feb 2026".to_string()).is_err());
+  /// ```
+  #[cfg(any(feature = "chrono", feature = "jiff"))]
+  NormalizeDate {
+    /// Formats tried, in order, when parsing the value.
+    accept: Vec<DateFormat>,
+    /// Format the date is written in.
+    output: DateFormat,
+    /// How to resolve input that denotes different dates in different accepted formats.
+    #[serde(default)]
+    ambiguity: DateAmbiguity,
+  },
+
+  /// A fallible function registered in a [`FilterRegistry`](crate::FilterRegistry),
+  /// referenced by name so the pipeline stays serializable.
+  ///
+  /// Bind a deserialized pipeline with
+  /// [`FilterRegistry::bind_try`](crate::FilterRegistry::bind_try), which resolves each
+  /// `Named` to its registered function and reports unknown names. A bound `Named` still
+  /// serializes as `{ name, args }`; build one in code with [`TryFilterOp::named`].
+  /// Applying an unbound `Named` fails with
+  /// [`UnknownFilter`](FilterErrorCode::UnknownFilter).
+  Named {
+    /// Name the function was registered under.
+    name: String,
+    /// Arguments passed to the function on every call (`null` when omitted).
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    args: serde_json::Value,
+    /// The registered function, set by `FilterRegistry::bind_try` (never serialized).
+    // An explicit `default` keeps serde from requiring `T: Default`.
+    #[serde(skip, default = "Binding::default")]
+    bound: Binding<NamedTryFilterFn<T>>,
+  },
+
   /// Custom fallible filter function (not serializable).
   ///
   /// # Serde limitation
@@ -144,10 +340,84 @@ pub enum TryFilterOp<T> {
   /// - A `Chain` that contains a `TryCustom` will also **fail to serialize**.
   /// - Deserialization will never produce a `TryCustom` variant.
   ///
-  /// If your filter pipeline must survive a serialization round-trip, avoid `TryCustom`
-  /// or add the custom logic as a post-deserialization step.
+  /// If your filter pipeline must survive a serialization round-trip, register the
+  /// function in a [`FilterRegistry`](crate::FilterRegistry) and reference it with
+  /// [`Named`](Self::Named) instead.
   #[serde(skip)]
   TryCustom(Arc<dyn Fn(T) -> Result<T, FilterError> + Send + Sync>),
+
+  /// Async custom fallible filter function (not serializable; requires the `async` feature),
+  /// for normalizations that need I/O — resolving a short link, looking up a canonical name.
+  ///
+  /// Only [`try_apply_async`](TryFilterOp::try_apply_async) runs it; the synchronous
+  /// `try_apply*` methods fail with [`FilterErrorCode::AsyncOnly`] rather than skip a
+  /// normalization step the caller relies on.
+  ///
+  /// ```rust
+  /// use std::sync::Arc;
+  /// use walrs_filter::{FilterOp, TryFilterOp};
+  ///
+  /// let expand = TryFilterOp::<String>::Chain(vec![
+  ///     TryFilterOp::Infallible(FilterOp::Trim),
+  ///     TryFilterOp::TryCustomAsync(Arc::new(|s: String| {
+  ///         Box::pin(async move {
+  ///             // e.g. an HTTP lookup
+  ///             Ok(if s == "t.co/rust" { "https://rust-lang.org".to_string() } else { s })
+  ///         })
+  ///     })),
+  /// ]);
+  ///
+  /// let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+  /// let expanded = rt.block_on(expand.try_apply_async(" t.co/rust ".into()));
+  /// assert_eq!(expanded.unwrap(), "https://rust-lang.org");
+  ///
+  /// // The sync path refuses to run the async step.
+  /// let err = expand.try_apply(" t.co/rust ".into()).unwrap_err();
+  /// assert_eq!(err.code(), walrs_filter::FilterErrorCode::AsyncOnly);
+  /// ```
+  #[cfg(feature = "async")]
+  #[serde(skip)]
+  TryCustomAsync(TryCustomAsyncFn<T>),
+}
+
+impl<T> TryFilterOp<T> {
+  /// The variant name, as used for the serialized `type` tag (`"ToInt"`, `"Chain"`, …).
+  ///
+  /// `Infallible` reports its own name; use [`FilterOp::name`] on the wrapped op for
+  /// the underlying filter.
+  pub fn name(&self) -> &'static str {
+    match self {
+      Self::Infallible(_) => "Infallible",
+      Self::Chain(_) => "Chain",
+      Self::ToBool => "ToBool",
+      Self::ToInt => "ToInt",
+      Self::ToFloat => "ToFloat",
+      Self::UrlDecode => "UrlDecode",
+      Self::HtmlEntityDecode => "HtmlEntityDecode",
+      Self::Base64Decode { .. } => "Base64Decode",
+      Self::HexDecode => "HexDecode",
+      Self::CanonicalizeUrl(_) => "CanonicalizeUrl",
+      Self::NormalizeEmail(_) => "NormalizeEmail",
+      #[cfg(feature = "phone")]
+      Self::NormalizePhone { .. } => "NormalizePhone",
+      #[cfg(any(feature = "chrono", feature = "jiff"))]
+      Self::NormalizeDate { .. } => "NormalizeDate",
+      Self::Named { .. } => "Named",
+      Self::TryCustom(_) => "TryCustom",
+      #[cfg(feature = "async")]
+      Self::TryCustomAsync(_) => "TryCustomAsync",
+    }
+  }
+
+  /// An unbound [`Named`](Self::Named) step; bind it with
+  /// [`FilterRegistry::bind_try`](crate::FilterRegistry::bind_try).
+  pub fn named(name: impl Into<String>, args: serde_json::Value) -> Self {
+    Self::Named {
+      name: name.into(),
+      args,
+      bound: Binding::default(),
+    }
+  }
 }
 
 impl<T: Debug> Debug for TryFilterOp<T> {
@@ -159,7 +429,39 @@ impl<T: Debug> Debug for TryFilterOp<T> {
       Self::ToInt => write!(f, "ToInt"),
       Self::ToFloat => write!(f, "ToFloat"),
       Self::UrlDecode => write!(f, "UrlDecode"),
+      Self::HtmlEntityDecode => write!(f, "HtmlEntityDecode"),
+      Self::Base64Decode { url_safe } => f
+        .debug_struct("Base64Decode")
+        .field("url_safe", url_safe)
+        .finish(),
+      Self::HexDecode => write!(f, "HexDecode"),
+      Self::CanonicalizeUrl(options) => f.debug_tuple("CanonicalizeUrl").field(options).finish(),
+      Self::NormalizeEmail(options) => f.debug_tuple("NormalizeEmail").field(options).finish(),
+      #[cfg(feature = "phone")]
+      Self::NormalizePhone { default_region } => f
+        .debug_struct("NormalizePhone")
+        .field("default_region", default_region)
+        .finish(),
+      #[cfg(any(feature = "chrono", feature = "jiff"))]
+      Self::NormalizeDate {
+        accept,
+        output,
+        ambiguity,
+      } => f
+        .debug_struct("NormalizeDate")
+        .field("accept", accept)
+        .field("output", output)
+        .field("ambiguity", ambiguity)
+        .finish(),
+      Self::Named { name, args, bound } => f
+        .debug_struct("Named")
+        .field("name", name)
+        .field("args", args)
+        .field("bound", &bound.is_bound())
+        .finish(),
       Self::TryCustom(_) => write!(f, "TryCustom(<fn>)"),
+      #[cfg(feature = "async")]
+      Self::TryCustomAsync(_) => write!(f, "TryCustomAsync(<async fn>)"),
     }
   }
 }
@@ -173,8 +475,44 @@ impl<T: PartialEq> PartialEq for TryFilterOp<T> {
       (Self::ToInt, Self::ToInt) => true,
       (Self::ToFloat, Self::ToFloat) => true,
       (Self::UrlDecode, Self::UrlDecode) => true,
+      (Self::HtmlEntityDecode, Self::HtmlEntityDecode) => true,
+      (Self::Base64Decode { url_safe: a }, Self::Base64Decode { url_safe: b }) => a == b,
+      (Self::HexDecode, Self::HexDecode) => true,
+      (Self::CanonicalizeUrl(a), Self::CanonicalizeUrl(b)) => a == b,
+      (Self::NormalizeEmail(a), Self::NormalizeEmail(b)) => a == b,
+      #[cfg(feature = "phone")]
+      (Self::NormalizePhone { default_region: a }, Self::NormalizePhone { default_region: b }) => {
+        a == b
+      }
+      #[cfg(any(feature = "chrono", feature = "jiff"))]
+      (
+        Self::NormalizeDate {
+          accept: aa,
+          output: ao,
+          ambiguity: am,
+        },
+        Self::NormalizeDate {
+          accept: ba,
+          output: bo,
+          ambiguity: bm,
+        },
+      ) => aa == ba && ao == bo && am == bm,
       // TryCustom filters are never equal
+      (
+        Self::Named {
+          name: na,
+          args: aa,
+          bound: ba,
+        },
+        Self::Named {
+          name: nb,
+          args: ab,
+          bound: bb,
+        },
+      ) => na == nb && aa == ab && ba.is_bound() == bb.is_bound(),
       (Self::TryCustom(_), Self::TryCustom(_)) => false,
+      #[cfg(feature = "async")]
+      (Self::TryCustomAsync(_), Self::TryCustomAsync(_)) => false,
       _ => false,
     }
   }
@@ -192,19 +530,23 @@ impl TryFilterOp<String> {
   /// Returns `Err(FilterError)` when the filter fails.
   pub fn try_apply_ref<'a>(&self, value: &'a str) -> Result<Cow<'a, str>, FilterError> {
     match self {
-      TryFilterOp::Infallible(op) => Ok(op.apply_ref(value)),
+      TryFilterOp::Infallible(op) => op.ensure_bound().map(|()| op.apply_ref(value)),
       TryFilterOp::Chain(ops) => {
         let flat = flatten_try_chain(ops);
         if flat.is_empty() {
           return Ok(Cow::Borrowed(value));
         }
-        let first_result = flat[0].try_apply_ref(value)?;
+        let first_result = flat[0]
+          .try_apply_ref(value)
+          .map_err(|e| locate_chain_error(ops, 0, e))?;
         if flat.len() == 1 {
           return Ok(first_result);
         }
         let mut result = first_result.into_owned();
-        for op in &flat[1..] {
-          result = op.try_apply(result)?;
+        for (i, op) in flat.iter().enumerate().skip(1) {
+          result = op
+            .try_apply(result)
+            .map_err(|e| locate_chain_error(ops, i, e))?;
         }
         Ok(Cow::Owned(result))
       }
@@ -219,7 +561,10 @@ impl TryFilterOp<String> {
       }
       TryFilterOp::ToInt => {
         let parsed: i64 = value.trim().parse().map_err(|e: std::num::ParseIntError| {
-          FilterError::new(format!("cannot parse {value:?} as i64: {e}")).with_name("ToInt")
+          FilterError::new(format!("cannot parse {value:?} as i64: {e}"))
+            .with_name("ToInt")
+            .with_code(FilterErrorCode::NotANumber)
+            .with_span(trimmed_span(value))
         })?;
         let canonical = parsed.to_string();
         if value == canonical {
@@ -233,7 +578,10 @@ impl TryFilterOp<String> {
           .trim()
           .parse()
           .map_err(|e: std::num::ParseFloatError| {
-            FilterError::new(format!("cannot parse {value:?} as f64: {e}")).with_name("ToFloat")
+            FilterError::new(format!("cannot parse {value:?} as f64: {e}"))
+              .with_name("ToFloat")
+              .with_code(FilterErrorCode::NotANumber)
+              .with_span(trimmed_span(value))
           })?;
         let canonical = format!("{}", parsed);
         if value == canonical {
@@ -242,16 +590,33 @@ impl TryFilterOp<String> {
           Ok(Cow::Owned(canonical))
         }
       }
-      TryFilterOp::UrlDecode => {
-        // `percent_decode_str(value).decode_utf8()` returns `Cow<'_, str>` borrowing from
-        // `value`, so the lifetimes line up and we can return it directly.
-        let decoded = percent_decode_str(value).decode_utf8().map_err(|e| {
-          FilterError::new(format!("invalid utf-8 after percent-decode: {e}"))
-            .with_name("UrlDecode")
-        })?;
-        Ok(decoded)
+      // Borrows from `value` when there is nothing to decode.
+      TryFilterOp::UrlDecode => encoding::url_decode(value),
+      TryFilterOp::HtmlEntityDecode => decode_entities(value),
+      TryFilterOp::Base64Decode { url_safe } => {
+        encoding::base64_decode(value, *url_safe).map(Cow::Owned)
       }
+      TryFilterOp::HexDecode => encoding::hex_decode(value).map(Cow::Owned),
+      TryFilterOp::CanonicalizeUrl(options) => canonicalize_url(value, options),
+      TryFilterOp::NormalizeEmail(options) => normalize_email(value, options),
+      #[cfg(feature = "phone")]
+      TryFilterOp::NormalizePhone { default_region } => {
+        normalize_phone(value, default_region.as_deref())
+      }
+      #[cfg(any(feature = "chrono", feature = "jiff"))]
+      TryFilterOp::NormalizeDate {
+        accept,
+        output,
+        ambiguity,
+      } => normalize_date(value, accept, output, *ambiguity),
+      TryFilterOp::Named { name, args, bound } => match bound.get() {
+        Some(f) => f(value.to_string(), args).map(Cow::Owned),
+        None => Err(crate::registry::unbound_error(name)),
+      },
       TryFilterOp::TryCustom(f) => f(value.to_string()).map(Cow::Owned),
+      // Only run by `try_apply_async`.
+      #[cfg(feature = "async")]
+      TryFilterOp::TryCustomAsync(_) => Err(async_only_error()),
     }
   }
 
@@ -261,44 +626,123 @@ impl TryFilterOp<String> {
   pub fn try_apply(&self, value: String) -> Result<String, FilterError> {
     self.try_apply_ref(&value).map(Cow::into_owned)
   }
+
+  /// Apply the fallible filter asynchronously (requires the `async` feature).
+  ///
+  /// Runs like [`try_apply`](Self::try_apply), but awaits
+  /// [`TryCustomAsync`](Self::TryCustomAsync) steps instead of rejecting them.
+  #[cfg(feature = "async")]
+  pub async fn try_apply_async(&self, value: String) -> Result<String, FilterError> {
+    let mut value = value;
+    for (i, op) in flatten_try_chain(std::slice::from_ref(self))
+      .into_iter()
+      .enumerate()
+    {
+      value = match op {
+        TryFilterOp::TryCustomAsync(f) => f(value).await,
+        op => op.try_apply(value),
+      }
+      .map_err(|e| match self {
+        TryFilterOp::Chain(ops) => locate_chain_error(ops, i, e),
+        _ => e,
+      })?;
+    }
+    Ok(value)
+  }
 }
 
 // ============================================================================
-// Numeric TryFilterOp Implementations
+// Non-String TryFilterOp Implementations
 // ============================================================================
 
-macro_rules! impl_numeric_try_filter_op {
-    ($($t:ty),*) => {
+/// Implements `try_apply` for a non-string `T` whose `FilterOp<T>` has an `apply`;
+/// `$kind` names the family in the panic raised for string-oriented variants.
+macro_rules! impl_non_string_try_filter_op {
+    ($kind:literal; $($t:ty),*) => {
         $(
             impl TryFilterOp<$t> {
-                /// Apply the fallible filter to a numeric value.
+                /// Apply the fallible filter to the value.
                 pub fn try_apply(&self, value: $t) -> Result<$t, FilterError> {
                     match self {
-                        TryFilterOp::Infallible(op) => Ok(op.apply(value)),
+                        TryFilterOp::Infallible(op) => op.ensure_bound().map(|()| op.apply(value)),
                         TryFilterOp::Chain(ops) => {
                             let flat = flatten_try_chain(ops);
-                            flat.iter().try_fold(value, |v, op| op.try_apply(v))
+                            flat.iter().enumerate().try_fold(value, |v, (i, op)| {
+                                op.try_apply(v).map_err(|e| locate_chain_error(ops, i, e))
+                            })
                         }
                         // String-oriented conversions (`ToBool`, `ToInt`, `ToFloat`,
-                        // `UrlDecode`) are only meaningful for `TryFilterOp<String>`.
-                        // Constructing one with a numeric `T` is a programming error —
+                        // and the decoders) are only meaningful for `TryFilterOp<String>`.
+                        // Constructing one with any other `T` is a programming error —
                         // panic loudly rather than silently pass the value through.
                         TryFilterOp::ToBool
                         | TryFilterOp::ToInt
                         | TryFilterOp::ToFloat
-                        | TryFilterOp::UrlDecode => unreachable!(
-                            "string-oriented TryFilterOp variant applied to numeric TryFilterOp<{}>; these variants are only valid for TryFilterOp<String>",
+                        | TryFilterOp::UrlDecode
+                        | TryFilterOp::HtmlEntityDecode
+                        | TryFilterOp::Base64Decode { .. }
+                        | TryFilterOp::HexDecode
+                        | TryFilterOp::CanonicalizeUrl(_)
+                        | TryFilterOp::NormalizeEmail(_) => unreachable!(
+                            "string-oriented TryFilterOp variant applied to {} TryFilterOp<{}>; these variants are only valid for TryFilterOp<String>",
+                            $kind,
+                            stringify!($t)
+                        ),
+                        #[cfg(feature = "phone")]
+                        TryFilterOp::NormalizePhone { .. } => unreachable!(
+                            "string-oriented TryFilterOp variant applied to {} TryFilterOp<{}>; these variants are only valid for TryFilterOp<String>",
+                            $kind,
                             stringify!($t)
                         ),
+                        #[cfg(any(feature = "chrono", feature = "jiff"))]
+                        TryFilterOp::NormalizeDate { .. } => unreachable!(
+                            "string-oriented TryFilterOp variant applied to {} TryFilterOp<{}>; these variants are only valid for TryFilterOp<String>",
+                            $kind,
+                            stringify!($t)
+                        ),
+                        TryFilterOp::Named { name, args, bound } => match bound.get() {
+                            Some(f) => f(value, args),
+                            None => Err(crate::registry::unbound_error(name)),
+                        },
                         TryFilterOp::TryCustom(f) => f(value),
+                        // Only run by `try_apply_async`.
+                        #[cfg(feature = "async")]
+                        TryFilterOp::TryCustomAsync(_) => Err(async_only_error()),
                     }
                 }
+
+                /// Apply the fallible filter asynchronously, awaiting `TryCustomAsync` steps.
+                #[cfg(feature = "async")]
+                pub async fn try_apply_async(&self, value: $t) -> Result<$t, FilterError> {
+                    let mut value = value;
+                    for (i, op) in flatten_try_chain(std::slice::from_ref(self)).into_iter().enumerate() {
+                        value = match op {
+                            TryFilterOp::TryCustomAsync(f) => f(value).await,
+                            op => op.try_apply(value),
+                        }
+                        .map_err(|e| match self {
+                            TryFilterOp::Chain(ops) => locate_chain_error(ops, i, e),
+                            _ => e,
+                        })?;
+                    }
+                    Ok(value)
+                }
             }
         )*
     };
 }
 
-impl_numeric_try_filter_op!(i32, i64, f32, f64, u32, u64, usize);
+impl_non_string_try_filter_op!(
+  "numeric"; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+impl_non_string_try_filter_op!("non-string"; bool, char, Vec<String>);
+
+#[cfg(feature = "chrono")]
+impl_non_string_try_filter_op!("non-string"; chrono::NaiveDate, chrono::NaiveDateTime);
+
+#[cfg(feature = "jiff")]
+impl_non_string_try_filter_op!("non-string"; jiff::civil::Date, jiff::civil::DateTime);
 
 // ============================================================================
 // From<FilterOp<T>> for TryFilterOp<T> — lift infallible to fallible
@@ -380,6 +824,51 @@ mod tests {
     assert_eq!(err.message(), "always fails");
   }
 
+  #[test]
+  fn test_chain_error_records_step_path() {
+    let op: TryFilterOp<String> = TryFilterOp::Chain(vec![
+      TryFilterOp::Infallible(FilterOp::Trim),
+      TryFilterOp::Chain(vec![
+        TryFilterOp::Infallible(FilterOp::Lowercase),
+        TryFilterOp::Chain(vec![]),
+        TryFilterOp::ToInt,
+      ]),
+      TryFilterOp::ToBool,
+    ]);
+    let err = op.try_apply(" 12a ".to_string()).unwrap_err();
+    assert_eq!(err.path(), &[1, 2]);
+    assert_eq!(err.code(), FilterErrorCode::NotANumber);
+    assert_eq!(err.span(), Some(0..3));
+
+    // The first step is applied by reference; its errors are located too.
+    let op: TryFilterOp<String> =
+      TryFilterOp::Chain(vec![TryFilterOp::Chain(vec![TryFilterOp::ToBool])]);
+    let err = op.try_apply_ref("maybe").unwrap_err();
+    assert_eq!(err.path(), &[0, 0]);
+    assert_eq!(err.code(), FilterErrorCode::NotABoolean);
+
+    // Errors outside a chain have an empty path.
+    let err = TryFilterOp::<String>::ToFloat
+      .try_apply("x".to_string())
+      .unwrap_err();
+    assert!(err.path().is_empty());
+  }
+
+  #[test]
+  fn test_numeric_chain_error_records_step_path() {
+    let op: TryFilterOp<i32> = TryFilterOp::Chain(vec![
+      TryFilterOp::Infallible(FilterOp::Clamp { min: 0, max: 10 }),
+      TryFilterOp::TryCustom(Arc::new(|v| {
+        if v == 10 {
+          Err(FilterError::new("clamped"))
+        } else {
+          Ok(v)
+        }
+      })),
+    ]);
+    assert_eq!(op.try_apply(50).unwrap_err().path(), &[1]);
+  }
+
   #[test]
   fn test_chain_empty() {
     let op: TryFilterOp<String> = TryFilterOp::Chain(vec![]);
@@ -462,6 +951,31 @@ mod tests {
     assert_eq!(op.try_apply(200).unwrap(), 200); // clamped to 100, then * 2
   }
 
+  #[test]
+  fn test_try_apply_non_string_types() {
+    let op: TryFilterOp<bool> = TryFilterOp::Chain(vec![
+      TryFilterOp::Infallible(FilterOp::Custom(Arc::new(|b: bool| !b))),
+      TryFilterOp::TryCustom(Arc::new(|b| {
+        if b {
+          Ok(b)
+        } else {
+          Err(FilterError::new("must be true"))
+        }
+      })),
+    ]);
+    assert!(op.try_apply(false).unwrap());
+    assert_eq!(op.try_apply(true).unwrap_err().path(), [1]);
+
+    let op: TryFilterOp<Vec<String>> = TryFilterOp::Infallible(FilterOp::Uppercase);
+    assert_eq!(op.try_apply(vec!["a".into()]).unwrap(), ["A"]);
+  }
+
+  #[test]
+  #[should_panic(expected = "string-oriented TryFilterOp variant applied to non-string")]
+  fn test_non_string_to_int_panics() {
+    let _ = TryFilterOp::<Vec<String>>::ToInt.try_apply(vec![]);
+  }
+
   // ---- apply_ref tests ----
 
   #[test]
@@ -820,4 +1334,265 @@ mod tests {
     let op = TryFilterOp::<u32>::UrlDecode;
     let _ = op.try_apply(1);
   }
+
+  // ---- Decoders ----
+
+  #[test]
+  fn test_html_entity_decode_string() {
+    let op = TryFilterOp::<String>::HtmlEntityDecode;
+    assert_eq!(
+      op.try_apply("&lt;b&gt;Tom &amp; Jerry&lt;/b&gt;".to_string())
+        .unwrap(),
+      "<b>Tom & Jerry</b>"
+    );
+    let result = op.try_apply_ref("nothing to decode").unwrap();
+    assert!(matches!(result, Cow::Borrowed(_)));
+  }
+
+  #[test]
+  fn test_html_entity_decode_roundtrips_html_entities() {
+    let encode = FilterOp::<String>::HtmlEntities;
+    let decode = TryFilterOp::<String>::HtmlEntityDecode;
+    let input = "<a href=\"x\">it's</a>";
+    assert_eq!(
+      decode.try_apply(encode.apply(input.to_string())).unwrap(),
+      input
+    );
+  }
+
+  #[test]
+  fn test_html_entity_decode_unknown_entity_errors() {
+    let op = TryFilterOp::<String>::HtmlEntityDecode;
+    let err = op.try_apply("a &nope; b".to_string()).unwrap_err();
+    assert_eq!(err.filter_name(), Some("HtmlEntityDecode"));
+    assert_eq!(err.offset(), Some(2));
+  }
+
+  #[test]
+  fn test_base64_decode_string() {
+    let op = TryFilterOp::<String>::Base64Decode { url_safe: false };
+    assert_eq!(op.try_apply("aGVsbG8=".to_string()).unwrap(), "hello");
+    assert_eq!(op.try_apply("aGVsbG8".to_string()).unwrap(), "hello");
+    let err = op.try_apply("aGV*bG8=".to_string()).unwrap_err();
+    assert_eq!(err.filter_name(), Some("Base64Decode"));
+    assert_eq!(err.offset(), Some(3));
+  }
+
+  #[test]
+  fn test_base64_url_safe_roundtrip() {
+    let encode = FilterOp::<String>::Base64Encode {
+      url_safe: true,
+      padding: false,
+    };
+    let decode = TryFilterOp::<String>::Base64Decode { url_safe: true };
+    let input = "subjects?_d=1&x=ÿ";
+    assert_eq!(
+      decode.try_apply(encode.apply(input.to_string())).unwrap(),
+      input
+    );
+  }
+
+  #[test]
+  fn test_hex_decode_string() {
+    let op = TryFilterOp::<String>::HexDecode;
+    assert_eq!(op.try_apply("68656C6c6f".to_string()).unwrap(), "hello");
+    let err = op.try_apply("6865g6".to_string()).unwrap_err();
+    assert_eq!(err.filter_name(), Some("HexDecode"));
+    assert_eq!(err.offset(), Some(4));
+  }
+
+  #[test]
+  fn test_chain_trim_then_base64_decode() {
+    let op: TryFilterOp<String> = TryFilterOp::Chain(vec![
+      TryFilterOp::Infallible(FilterOp::Trim),
+      TryFilterOp::Base64Decode { url_safe: false },
+    ]);
+    assert_eq!(op.try_apply("  aGk=\n".to_string()).unwrap(), "hi");
+  }
+
+  #[test]
+  fn test_serde_roundtrip_decoders() {
+    for op in [
+      TryFilterOp::<String>::HtmlEntityDecode,
+      TryFilterOp::Base64Decode { url_safe: true },
+      TryFilterOp::HexDecode,
+    ] {
+      let json = serde_json::to_string(&op).unwrap();
+      let deserialized: TryFilterOp<String> = serde_json::from_str(&json).unwrap();
+      assert_eq!(op, deserialized);
+    }
+  }
+
+  #[test]
+  fn test_canonicalize_url() {
+    let op = TryFilterOp::<String>::CanonicalizeUrl(UrlCanonicalOptions::default());
+    assert_eq!(
+      op.try_apply("HTTPS://Example.com:443/a/../b?z=1&utm_campaign=x&a=2#frag".to_string())
+        .unwrap(),
+      "https://example.com/b?a=2&z=1"
+    );
+    let err = op.try_apply("not a url".to_string()).unwrap_err();
+    assert_eq!(err.filter_name(), Some("CanonicalizeUrl"));
+  }
+
+  #[test]
+  fn test_serde_canonicalize_url() {
+    let op = TryFilterOp::<String>::CanonicalizeUrl(UrlCanonicalOptions {
+      force_https: true,
+      strip_params: vec!["ref".into()],
+      ..Default::default()
+    });
+    let json = serde_json::to_string(&op).unwrap();
+    let deserialized: TryFilterOp<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(op, deserialized);
+
+    // Omitted options fall back to their defaults.
+    let partial: TryFilterOp<String> =
+      serde_json::from_str(r#"{"type":"CanonicalizeUrl","config":{"force_https":true}}"#).unwrap();
+    assert_eq!(
+      partial,
+      TryFilterOp::CanonicalizeUrl(UrlCanonicalOptions {
+        force_https: true,
+        ..Default::default()
+      })
+    );
+  }
+
+  #[test]
+  fn test_normalize_email() {
+    let op = TryFilterOp::<String>::NormalizeEmail(EmailNormalizeOptions {
+      strip_plus_tag: true,
+      ..Default::default()
+    });
+    assert_eq!(
+      op.try_apply(" Jane+list@Example.COM ".to_string()).unwrap(),
+      "Jane@example.com"
+    );
+    let err = op.try_apply("jane.example.com".to_string()).unwrap_err();
+    assert_eq!(err.filter_name(), Some("NormalizeEmail"));
+
+    let json = serde_json::to_string(&op).unwrap();
+    let deserialized: TryFilterOp<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(op, deserialized);
+  }
+
+  #[cfg(feature = "phone")]
+  #[test]
+  fn test_normalize_phone() {
+    let op = TryFilterOp::<String>::NormalizePhone {
+      default_region: Some("US".into()),
+    };
+    assert_eq!(
+      op.try_apply("415.555.0132".to_string()).unwrap(),
+      "+14155550132"
+    );
+    assert!(op.try_apply("555".to_string()).is_err());
+
+    let json = serde_json::to_string(&op).unwrap();
+    assert_eq!(
+      json,
+      r#"{"type":"NormalizePhone","config":{"default_region":"US"}}"#
+    );
+    let deserialized: TryFilterOp<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(op, deserialized);
+  }
+
+  #[cfg(any(feature = "chrono", feature = "jiff"))]
+  #[test]
+  fn test_normalize_date() {
+    let op = TryFilterOp::<String>::NormalizeDate {
+      accept: vec![DateFormat::Iso8601, DateFormat::UsDate, DateFormat::EuDate],
+      output: DateFormat::Iso8601,
+      ambiguity: DateAmbiguity::DayFirst,
+    };
+    assert_eq!(op.try_apply("2/3/2026".to_string()).unwrap(), "2026-03-02");
+    let err = op.try_apply("yesterday".to_string()).unwrap_err();
+    assert_eq!(err.filter_name(), Some("NormalizeDate"));
+
+    let json = serde_json::to_string(&op).unwrap();
+    let deserialized: TryFilterOp<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(op, deserialized);
+
+    // An omitted ambiguity policy rejects ambiguous input.
+    let partial: TryFilterOp<String> = serde_json::from_str(
+      r#"{"type":"NormalizeDate","config":{"accept":[{"type":"us_date"},{"type":"eu_date"}],"output":{"type":"iso8601"}}}"#,
+    )
+    .unwrap();
+    assert!(partial.try_apply("2/3/2026".to_string()).is_err());
+    assert_eq!(
+      partial.try_apply("2/23/2026".to_string()).unwrap(),
+      "2026-02-23"
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "string-oriented TryFilterOp variant applied to numeric")]
+  fn test_numeric_hex_decode_panics() {
+    let op = TryFilterOp::<u8>::HexDecode;
+    let _ = op.try_apply(1);
+  }
+
+  #[cfg(feature = "async")]
+  fn double_async() -> TryFilterOp<i32> {
+    TryFilterOp::TryCustomAsync(Arc::new(|n: i32| {
+      Box::pin(async move {
+        n.checked_mul(2)
+          .ok_or_else(|| FilterError::new("overflow").with_name("Double"))
+      })
+    }))
+  }
+
+  #[cfg(feature = "async")]
+  #[tokio::test]
+  async fn test_try_apply_async_string_chain() {
+    let lookup = TryFilterOp::<String>::TryCustomAsync(Arc::new(|s: String| {
+      Box::pin(async move {
+        if s.is_empty() {
+          Err(FilterError::new("empty").with_name("Lookup"))
+        } else {
+          Ok(format!("<{s}>"))
+        }
+      })
+    }));
+    // Nested chains are flattened; errors short-circuit.
+    let op = TryFilterOp::Chain(vec![
+      TryFilterOp::Infallible(FilterOp::Trim),
+      TryFilterOp::Chain(vec![lookup]),
+    ]);
+    assert_eq!(op.try_apply_async(" a ".to_string()).await.unwrap(), "<a>");
+    let err = op.try_apply_async("  ".to_string()).await.unwrap_err();
+    assert_eq!(err.filter_name(), Some("Lookup"));
+    assert_eq!(err.path(), &[1, 0]);
+
+    let op = TryFilterOp::Chain(vec![op, TryFilterOp::ToInt]);
+    let err = op.try_apply_async("7".to_string()).await.unwrap_err();
+    assert_eq!(err.filter_name(), Some("ToInt"));
+    assert_eq!(err.path(), &[1]);
+  }
+
+  #[cfg(feature = "async")]
+  #[test]
+  fn test_try_custom_async_fails_synchronously() {
+    let op = TryFilterOp::<String>::TryCustomAsync(Arc::new(|_| {
+      Box::pin(async { Err(FilterError::new("never")) })
+    }));
+    let err = op.try_apply_ref("x").unwrap_err();
+    assert_eq!(err.code(), FilterErrorCode::AsyncOnly);
+    assert_eq!(err.filter_name(), Some("TryCustomAsync"));
+    let chain = TryFilterOp::Chain(vec![TryFilterOp::Infallible(FilterOp::Abs), double_async()]);
+    let err = chain.try_apply(21).unwrap_err();
+    assert_eq!(err.code(), FilterErrorCode::AsyncOnly);
+    assert_eq!(err.path(), &[1]);
+    assert_eq!(op.name(), "TryCustomAsync");
+    assert_eq!(format!("{op:?}"), "TryCustomAsync(<async fn>)");
+    assert_ne!(op, op.clone());
+  }
+
+  #[cfg(feature = "async")]
+  #[tokio::test]
+  async fn test_try_apply_async_numeric() {
+    let op = TryFilterOp::Chain(vec![TryFilterOp::Infallible(FilterOp::Abs), double_async()]);
+    assert_eq!(op.try_apply_async(-21).await.unwrap(), 42);
+    assert!(double_async().try_apply_async(i32::MAX).await.is_err());
+  }
 }
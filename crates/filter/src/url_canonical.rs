@@ -0,0 +1,246 @@
+//! URL canonicalization backing [`TryFilterOp::CanonicalizeUrl`](crate::TryFilterOp::CanonicalizeUrl).
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use url::Url;
+
+use crate::{FilterError, FilterErrorCode};
+
+/// Options for [`TryFilterOp::CanonicalizeUrl`](crate::TryFilterOp::CanonicalizeUrl).
+///
+/// Parsing with the [`url`] crate always lowercases the scheme and host, converts
+/// internationalized hosts to punycode, drops default ports, and resolves `.`/`..`
+/// path segments; these options control the remaining normalizations. Missing fields
+/// take their [`Default`] values when deserializing.
+///
+/// # Example
+///
+/// ```rust
+/// use walrs_filter::{TryFilterOp, UrlCanonicalOptions};
+///
+/// let op: TryFilterOp<String> = TryFilterOp::CanonicalizeUrl(UrlCanonicalOptions {
+///     force_https: true,
+///     ..Default::default()
+/// });
+///
+/// assert_eq!(
+///     op.try_apply("HTTP://Bücher.Example:80/a/./b/../c?z=1&utm_source=x&a=2#top".to_string())
+///         .unwrap(),
+///     "https://xn--bcher-kva.example/a/c?a=2&z=1"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrlCanonicalOptions {
+  /// Rewrite `http` URLs to `https`.
+  pub force_https: bool,
+
+  /// Sort query parameters by name (then by their raw `name=value` text). The
+  /// parameters' percent-encoding is left untouched.
+  pub sort_query: bool,
+
+  /// Remove the `#fragment`.
+  pub strip_fragment: bool,
+
+  /// Query parameter names to remove, compared ASCII case-insensitively. A trailing
+  /// `*` matches any name with that prefix (`utm_*`).
+  ///
+  /// Defaults to `utm_*`, `fbclid`, and `gclid`.
+  pub strip_params: Vec<String>,
+}
+
+impl Default for UrlCanonicalOptions {
+  fn default() -> Self {
+    Self {
+      force_https: false,
+      sort_query: true,
+      strip_fragment: true,
+      strip_params: vec![
+        "utm_*".to_string(),
+        "fbclid".to_string(),
+        "gclid".to_string(),
+      ],
+    }
+  }
+}
+
+impl UrlCanonicalOptions {
+  /// Returns `true` when the (still percent-encoded) query parameter `name` is listed
+  /// in [`strip_params`](Self::strip_params).
+  fn strips_param(&self, name: &str) -> bool {
+    let name = percent_encoding::percent_decode_str(name).decode_utf8_lossy();
+    self
+      .strip_params
+      .iter()
+      .any(|pattern| match pattern.strip_suffix('*') {
+        Some(prefix) => name
+          .get(..prefix.len())
+          .is_some_and(|head| head.eq_ignore_ascii_case(prefix)),
+        None => name.eq_ignore_ascii_case(pattern),
+      })
+  }
+}
+
+/// Canonicalizes the absolute URL `value` according to `options`.
+///
+/// Returns `Cow::Borrowed` when `value` is already canonical.
+pub(crate) fn canonicalize_url<'a>(
+  value: &'a str,
+  options: &UrlCanonicalOptions,
+) -> Result<Cow<'a, str>, FilterError> {
+  let mut url = Url::parse(value).map_err(|e| {
+    FilterError::new(format!("cannot parse {value:?} as a URL: {e}"))
+      .with_name("CanonicalizeUrl")
+      .with_code(FilterErrorCode::InvalidUrl)
+  })?;
+
+  if options.force_https && url.scheme() == "http" {
+    // `http` → `https` is always permitted; an explicit `:443` becomes the default
+    // port and is dropped.
+    let _ = url.set_scheme("https");
+  }
+
+  if let Some(query) = url.query() {
+    let mut params: Vec<&str> = query
+      .split('&')
+      .filter(|param| !param.is_empty())
+      .filter(|param| !options.strips_param(param_name(param)))
+      .collect();
+    if options.sort_query {
+      params.sort_by(|a, b| param_name(a).cmp(param_name(b)).then_with(|| a.cmp(b)));
+    }
+    let query = params.join("&");
+    url.set_query((!query.is_empty()).then_some(query.as_str()));
+  }
+
+  if options.strip_fragment {
+    url.set_fragment(None);
+  }
+
+  if url.as_str() == value {
+    Ok(Cow::Borrowed(value))
+  } else {
+    Ok(Cow::Owned(url.into()))
+  }
+}
+
+/// The (percent-encoded) name part of a `name=value` query parameter.
+fn param_name(param: &str) -> &str {
+  param.split_once('=').map_or(param, |(name, _)| name)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn canonical(value: &str) -> String {
+    canonicalize_url(value, &UrlCanonicalOptions::default())
+      .unwrap()
+      .into_owned()
+  }
+
+  #[test]
+  fn test_scheme_host_and_port() {
+    assert_eq!(
+      canonical("HTTP://WWW.Example.COM:80"),
+      "http://www.example.com/"
+    );
+    assert_eq!(
+      canonical("https://example.com:443/x"),
+      "https://example.com/x"
+    );
+    assert_eq!(
+      canonical("https://example.com:8443/x"),
+      "https://example.com:8443/x"
+    );
+  }
+
+  #[test]
+  fn test_idn_host_is_punycoded() {
+    assert_eq!(
+      canonical("https://münchen.de/"),
+      "https://xn--mnchen-3ya.de/"
+    );
+  }
+
+  #[test]
+  fn test_dot_segments_are_removed() {
+    assert_eq!(
+      canonical("https://a.com/a/b/../c/./d"),
+      "https://a.com/a/c/d"
+    );
+    assert_eq!(canonical("https://a.com/../../x"), "https://a.com/x");
+  }
+
+  #[test]
+  fn test_query_is_sorted_and_tracking_params_stripped() {
+    assert_eq!(
+      canonical("https://a.com/?b=2&UTM_Source=x&a=1&fbclid=y&a=0&&gclid=z"),
+      "https://a.com/?a=0&a=1&b=2"
+    );
+    assert_eq!(
+      canonical("https://a.com/?utm_medium=email"),
+      "https://a.com/"
+    );
+    // Percent-encoding is preserved; names are compared decoded.
+    assert_eq!(
+      canonical("https://a.com/?q=a%20b&%75tm_x=1"),
+      "https://a.com/?q=a%20b"
+    );
+  }
+
+  #[test]
+  fn test_fragment() {
+    assert_eq!(canonical("https://a.com/p#section"), "https://a.com/p");
+    let keep = UrlCanonicalOptions {
+      strip_fragment: false,
+      ..Default::default()
+    };
+    assert_eq!(
+      canonicalize_url("https://a.com/p#section", &keep).unwrap(),
+      "https://a.com/p#section"
+    );
+  }
+
+  #[test]
+  fn test_force_https() {
+    let options = UrlCanonicalOptions {
+      force_https: true,
+      ..Default::default()
+    };
+    let canonical = |value| canonicalize_url(value, &options).unwrap().into_owned();
+    assert_eq!(canonical("http://a.com/"), "https://a.com/");
+    assert_eq!(canonical("http://a.com:443/"), "https://a.com/");
+    assert_eq!(canonical("ftp://a.com/"), "ftp://a.com/");
+  }
+
+  #[test]
+  fn test_options_disable_normalizations() {
+    let options = UrlCanonicalOptions {
+      sort_query: false,
+      strip_params: Vec::new(),
+      ..Default::default()
+    };
+    assert_eq!(
+      canonicalize_url("https://a.com/?b=1&utm_id=2&a=3", &options).unwrap(),
+      "https://a.com/?b=1&utm_id=2&a=3"
+    );
+  }
+
+  #[test]
+  fn test_canonical_input_is_borrowed() {
+    let value = "https://example.com/path?a=1&b=2";
+    assert!(matches!(
+      canonicalize_url(value, &UrlCanonicalOptions::default()).unwrap(),
+      Cow::Borrowed(_)
+    ));
+  }
+
+  #[test]
+  fn test_invalid_urls_are_rejected() {
+    for value in ["", "example.com/path", "/relative", "https://exa mple.com/"] {
+      let err = canonicalize_url(value, &UrlCanonicalOptions::default()).unwrap_err();
+      assert_eq!(err.filter_name(), Some("CanonicalizeUrl"), "{value}");
+    }
+  }
+}
@@ -0,0 +1,127 @@
+//! Word segmentation shared by the case-conversion ([`CaseFilter`](crate::CaseFilter))
+//! and slug ([`SlugFilter`](crate::SlugFilter)) filters.
+//!
+//! Segmentation happens in two layers:
+//!
+//! 1. [`split_runs`] splits on characters that are not "word" characters (the caller
+//!    decides what a word character is). Slugs stop here.
+//! 2. [`split_words`] takes alphanumeric runs from [`split_runs`], then splits them at
+//!    stray apostrophes, case humps (`fooBar` → `foo`, `Bar`), and acronym ends
+//!    (`HTTPServer` → `HTTP`, `Server`).
+//!
+//! Character classes come from the Unicode-aware `char` predicates
+//! (`is_alphanumeric`, `is_uppercase`, `is_lowercase`), so non-ASCII letters are
+//! treated the same way as ASCII ones.
+
+/// Returns `true` for the apostrophes that may appear inside a word (`don't`, `l’homme`).
+fn is_apostrophe(c: char) -> bool {
+  c == '\'' || c == '\u{2019}'
+}
+
+/// Splits `value` into the maximal, non-empty runs of characters matching `is_word_char`.
+pub(crate) fn split_runs<F>(value: &str, is_word_char: F) -> impl Iterator<Item = &str>
+where
+  F: Fn(char) -> bool,
+{
+  value
+    .split(move |c| !is_word_char(c))
+    .filter(|s| !s.is_empty())
+}
+
+/// Splits `value` into words.
+///
+/// Words are runs of alphanumeric characters (plus apostrophes between two
+/// alphanumerics), further split:
+///
+/// - before an uppercase letter that follows a lowercase letter or digit (`fooBar`, `v2Beta`);
+/// - before the last uppercase letter of an acronym that is followed by a
+///   lowercase letter (`XMLHttp` → `XML`, `Http`).
+pub(crate) fn split_words(value: &str) -> Vec<&str> {
+  let mut words = Vec::new();
+  for run in split_runs(value, |c| c.is_alphanumeric() || is_apostrophe(c)) {
+    let chars: Vec<(usize, char)> = run.char_indices().collect();
+    let mut start: Option<usize> = None;
+
+    for (i, &(idx, c)) in chars.iter().enumerate() {
+      let prev = i.checked_sub(1).map(|p| chars[p].1);
+      let next = chars.get(i + 1).map(|&(_, n)| n);
+
+      // An apostrophe only stays inside a word between two alphanumerics.
+      if is_apostrophe(c) && !(start.is_some() && next.is_some_and(char::is_alphanumeric)) {
+        if let Some(s) = start.take() {
+          words.push(&run[s..idx]);
+        }
+        continue;
+      }
+
+      match (start, prev) {
+        (Some(s), Some(prev)) => {
+          let hump = c.is_uppercase() && (prev.is_lowercase() || prev.is_numeric());
+          let acronym_end =
+            c.is_uppercase() && prev.is_uppercase() && next.is_some_and(char::is_lowercase);
+          if hump || acronym_end {
+            words.push(&run[s..idx]);
+            start = Some(idx);
+          }
+        }
+        _ => start = Some(idx),
+      }
+    }
+
+    if let Some(s) = start {
+      words.push(&run[s..]);
+    }
+  }
+  words
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_split_runs() {
+    let runs: Vec<&str> = split_runs("$Hello'\"@$World_1$", |c| {
+      c.is_ascii_alphanumeric() || c == '_'
+    })
+    .collect();
+    assert_eq!(runs, ["Hello", "World_1"]);
+    assert_eq!(split_runs("", char::is_alphanumeric).count(), 0);
+  }
+
+  #[test]
+  fn test_split_words_separators() {
+    assert_eq!(split_words("hello world"), ["hello", "world"]);
+    assert_eq!(split_words("  user_name--id  "), ["user", "name", "id"]);
+    assert_eq!(split_words(""), Vec::<&str>::new());
+  }
+
+  #[test]
+  fn test_split_words_case_humps() {
+    assert_eq!(split_words("fooBarBaz"), ["foo", "Bar", "Baz"]);
+    assert_eq!(split_words("FooBar"), ["Foo", "Bar"]);
+    assert_eq!(split_words("version2Beta"), ["version2", "Beta"]);
+  }
+
+  #[test]
+  fn test_split_words_acronyms() {
+    assert_eq!(split_words("HTTPServer"), ["HTTP", "Server"]);
+    assert_eq!(split_words("XMLHttpRequest"), ["XML", "Http", "Request"]);
+    assert_eq!(split_words("userID"), ["user", "ID"]);
+    assert_eq!(split_words("NASA"), ["NASA"]);
+  }
+
+  #[test]
+  fn test_split_words_unicode() {
+    assert_eq!(split_words("ÉtéÀParis"), ["Été", "À", "Paris"]);
+    assert_eq!(split_words("straße café"), ["straße", "café"]);
+  }
+
+  #[test]
+  fn test_split_words_apostrophes() {
+    assert_eq!(split_words("don't stop"), ["don't", "stop"]);
+    assert_eq!(split_words("l’homme"), ["l’homme"]);
+    assert_eq!(split_words("'quoted'"), ["quoted"]);
+    assert_eq!(split_words("a''b it'sX"), ["a", "b", "it's", "X"]);
+  }
+}
@@ -1,4 +1,4 @@
-use crate::Filter;
+use crate::{Filter, FilterError, FilterErrorCode};
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::OnceLock;
@@ -74,6 +74,127 @@ fn scan_entity(bytes: &[u8], start: usize) -> Option<usize> {
   }
 }
 
+/// Returns `true` when `bytes` (starting at a `&`) is an unterminated prefix of an
+/// entity reference that [`scan_entity`] would accept once more input arrives.
+///
+/// Used by streaming filters to hold back a possible entity split across chunks.
+pub(crate) fn is_entity_prefix(bytes: &[u8]) -> bool {
+  let Some((&b'&', rest)) = bytes.split_first() else {
+    return false;
+  };
+  match rest.split_first() {
+    None => true,
+    Some((&b'#', digits)) => match digits.split_first() {
+      Some((&(b'x' | b'X'), hex)) => {
+        hex.len() <= MAX_HEX_ENTITY_DIGITS && hex.iter().all(u8::is_ascii_hexdigit)
+      }
+      _ => digits.len() <= MAX_DECIMAL_ENTITY_DIGITS && digits.iter().all(u8::is_ascii_digit),
+    },
+    Some((first, _)) if first.is_ascii_alphabetic() => {
+      rest.len() <= MAX_NAMED_ENTITY_LEN && rest.iter().all(u8::is_ascii_alphanumeric)
+    }
+    Some(_) => false,
+  }
+}
+
+/// Named entities recognized by [`decode_entities`]: the five XML entities plus
+/// common HTML typographic entities.
+const NAMED_ENTITIES: &[(&str, char)] = &[
+  ("amp", '&'),
+  ("lt", '<'),
+  ("gt", '>'),
+  ("quot", '"'),
+  ("apos", '\''),
+  ("nbsp", '\u{A0}'),
+  ("iexcl", '¡'),
+  ("cent", '¢'),
+  ("pound", '£'),
+  ("yen", '¥'),
+  ("euro", '€'),
+  ("sect", '§'),
+  ("copy", '©'),
+  ("laquo", '«'),
+  ("raquo", '»'),
+  ("reg", '®'),
+  ("deg", '°'),
+  ("plusmn", '±'),
+  ("para", '¶'),
+  ("middot", '·'),
+  ("iquest", '¿'),
+  ("times", '×'),
+  ("divide", '÷'),
+  ("ndash", '–'),
+  ("mdash", '—'),
+  ("lsquo", '‘'),
+  ("rsquo", '’'),
+  ("ldquo", '“'),
+  ("rdquo", '”'),
+  ("bull", '•'),
+  ("hellip", '…'),
+  ("trade", '™'),
+];
+
+/// Decodes XML/HTML entity references — the inverse of [`XmlEntitiesFilter`].
+///
+/// Decodes decimal (`&#39;`) and hexadecimal (`&#x2F;`) character references and the
+/// named entities in [`NAMED_ENTITIES`]. Text that is not a syntactically valid entity
+/// reference (e.g. a bare `&` in `AT&T`) is left as-is.
+///
+/// Errors, with the byte span of the offending reference, on unknown named entities and on
+/// numeric references that do not denote a Unicode scalar value (`&#0;`, surrogates,
+/// values above U+10FFFF).
+pub(crate) fn decode_entities(input: &str) -> Result<Cow<'_, str>, FilterError> {
+  const NAME: &str = "HtmlEntityDecode";
+  let bytes = input.as_bytes();
+  let Some(first) = (0..bytes.len()).find(|&i| scan_entity(bytes, i).is_some()) else {
+    return Ok(Cow::Borrowed(input));
+  };
+
+  let mut output = String::with_capacity(input.len());
+  output.push_str(&input[..first]);
+  let mut i = first;
+  while i < bytes.len() {
+    let Some(end) = (bytes[i] == b'&').then(|| scan_entity(bytes, i)).flatten() else {
+      // Copy through to the next `&` (always a char boundary).
+      let next = input[i + 1..].find('&').map_or(bytes.len(), |p| i + 1 + p);
+      output.push_str(&input[i..next]);
+      i = next;
+      continue;
+    };
+    let body = &input[i + 1..end - 1];
+    let decoded = if let Some(numeric) = body.strip_prefix('#') {
+      let code = match numeric.strip_prefix(['x', 'X']) {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => numeric.parse::<u32>(),
+      };
+      code
+        .ok()
+        .filter(|&c| c != 0)
+        .and_then(char::from_u32)
+        .ok_or_else(|| {
+          FilterError::new(format!("invalid numeric character reference \"&{body};\""))
+            .with_name(NAME)
+            .with_code(FilterErrorCode::InvalidEntity)
+            .with_span(i..end)
+        })?
+    } else {
+      NAMED_ENTITIES
+        .iter()
+        .find(|(name, _)| *name == body)
+        .map(|&(_, c)| c)
+        .ok_or_else(|| {
+          FilterError::new(format!("unknown named entity \"&{body};\""))
+            .with_name(NAME)
+            .with_code(FilterErrorCode::InvalidEntity)
+            .with_span(i..end)
+        })?
+    };
+    output.push(decoded);
+    i = end;
+  }
+  Ok(Cow::Owned(output))
+}
+
 /// Encodes `<`, `>`, `&`, `'`, and `"` as XML/HTML entities.
 ///
 /// Existing entity references in the input are preserved verbatim so the filter never
@@ -496,6 +617,65 @@ mod test {
     assert_eq!(scan_entity(ok.as_bytes(), 0), Some(ok.len()));
   }
 
+  // ---- Entity decoding ----
+
+  #[test]
+  fn test_is_entity_prefix() {
+    use super::is_entity_prefix;
+    for prefix in ["&", "&a", "&amp", "&#", "&#12", "&#x", "&#x1F"] {
+      assert!(is_entity_prefix(prefix.as_bytes()), "{prefix}");
+    }
+    for not_prefix in ["", "a", "& ", "&a-", "&#x1G", "&#1a", "&amp;"] {
+      assert!(!is_entity_prefix(not_prefix.as_bytes()), "{not_prefix}");
+    }
+  }
+
+  #[test]
+  fn test_decode_entities_inverts_filter() {
+    let filter = super::XmlEntitiesFilter::new();
+    for input in [
+      "<script>alert('hello');</script>",
+      "\"Tom\" & Jerry",
+      "héllo <b>wörld</b>",
+    ] {
+      let encoded = filter.filter(input.into());
+      assert_eq!(super::decode_entities(&encoded).unwrap(), input);
+    }
+  }
+
+  #[test]
+  fn test_decode_entities_numeric_and_named() {
+    use super::decode_entities;
+    assert_eq!(decode_entities("&#39;hi&#x27;").unwrap(), "'hi'");
+    assert_eq!(decode_entities("&#X1F600;").unwrap(), "😀");
+    assert_eq!(
+      decode_entities("&copy; 2026&nbsp;&mdash;").unwrap(),
+      "© 2026\u{A0}—"
+    );
+  }
+
+  #[test]
+  fn test_decode_entities_leaves_non_entities() {
+    use super::decode_entities;
+    let result = decode_entities("AT&T & co &amp").unwrap();
+    assert!(matches!(result, std::borrow::Cow::Borrowed(_)));
+    assert_eq!(
+      decode_entities("AT&T &amp; café &").unwrap(),
+      "AT&T & café &"
+    );
+  }
+
+  #[test]
+  fn test_decode_entities_errors_report_offset() {
+    use super::decode_entities;
+    let err = decode_entities("ok &bogus; x").unwrap_err();
+    assert_eq!(err.filter_name(), Some("HtmlEntityDecode"));
+    assert_eq!(err.offset(), Some(3));
+    assert_eq!(decode_entities("é&#xD800;").unwrap_err().offset(), Some(2));
+    assert!(decode_entities("&#0;").is_err());
+    assert!(decode_entities("&#x110000;").is_err());
+  }
+
   #[cfg(feature = "fn_traits")]
   #[test]
   fn test_fn_traits() {
@@ -0,0 +1,35 @@
+//! File name restrictions shared by [`Rule::Filename`](crate::Rule::Filename) and
+//! `walrs_filter`'s `SanitizeFilename`, so the filter produces exactly the names the
+//! rule accepts.
+
+/// Characters Windows forbids in file names, in addition to control characters.
+pub const FILENAME_FORBIDDEN_CHARS: &[char] = &['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
+
+/// Device names Windows reserves regardless of extension (`CON`, `aux.txt`).
+pub const WINDOWS_RESERVED_NAMES: &[&str] = &[
+  "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+  "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Returns `true` for characters not allowed in a file name: control characters and
+/// [`FILENAME_FORBIDDEN_CHARS`].
+pub fn is_forbidden_filename_char(c: char) -> bool {
+  c.is_control() || FILENAME_FORBIDDEN_CHARS.contains(&c)
+}
+
+/// Returns `true` when the part of `name` before its first `.` is one of the
+/// [`WINDOWS_RESERVED_NAMES`] (ASCII case-insensitive).
+///
+/// ```rust
+/// use walrs_validation::is_windows_reserved_name;
+///
+/// assert!(is_windows_reserved_name("aux.txt"));
+/// assert!(is_windows_reserved_name("Com1"));
+/// assert!(!is_windows_reserved_name("console.log"));
+/// ```
+pub fn is_windows_reserved_name(name: &str) -> bool {
+  let stem = name.split('.').next().unwrap_or(name);
+  WINDOWS_RESERVED_NAMES
+    .iter()
+    .any(|reserved| stem.eq_ignore_ascii_case(reserved))
+}
@@ -21,6 +21,7 @@
 //! - `Rule::Uri` - Configurable URI validation (scheme, relative/absolute)
 //! - `Rule::Ip` - Configurable IP address validation (IPv4/IPv6/IPvFuture)
 //! - `Rule::Hostname` - Configurable hostname validation (DNS/IP/local/public IPv4)
+//! - `Rule::Filename` - Safe file name validation (byte limit, path separators, reserved names)
 //! - `Rule::Date` - Configurable date format validation (ISO 8601, US, EU, custom)
 //! - `Rule::DateRange` - Date range validation with min/max bounds
 //! - `Rule::Step` - Step/multiple validation
@@ -88,6 +89,7 @@ pub use indexmap;
 
 pub mod attributes;
 pub mod fieldset_violations;
+pub mod filename;
 pub mod message;
 pub mod options;
 pub mod rule;
@@ -97,6 +99,7 @@ pub mod violation;
 
 pub use attributes::*;
 pub use fieldset_violations::*;
+pub use filename::*;
 pub use message::*;
 pub use options::*;
 pub use rule::{CompiledPattern, Condition, Rule, RuleResult};
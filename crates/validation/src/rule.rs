@@ -128,8 +128,9 @@ pub type RuleResult = Result<(), Violation>;
 
 /// Type alias for the async custom validation function pointer used by `Rule::CustomAsync`.
 #[cfg(feature = "async")]
-pub type CustomAsyncFn<T> =
-  Arc<dyn Fn(&T) -> Pin<Box<dyn std::future::Future<Output = RuleResult> + Send + '_>> + Send + Sync>;
+pub type CustomAsyncFn<T> = Arc<
+  dyn Fn(&T) -> Pin<Box<dyn std::future::Future<Output = RuleResult> + Send + '_>> + Send + Sync,
+>;
 
 // ============================================================================
 // Condition Enum
@@ -138,9 +139,11 @@ pub type CustomAsyncFn<T> =
 /// Conditions for `When` rules.
 ///
 /// Conditions determine whether the `then_rule` or `else_rule` of a `When` rule
-/// should be applied. Most variants are serializable for config-driven validation.
+/// should be applied. Most variants are serializable for config-driven validation,
+/// using adjacent tagging: `{"type":"isempty"}`, `{"type":"equals","value":42}`,
+/// `{"type":"matches","value":"^[A-Z]"}`.
 #[derive(Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "lowercase")]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
 pub enum Condition<T> {
   /// Value is empty (for strings: empty or whitespace-only)
   IsEmpty,
@@ -293,6 +296,17 @@ pub enum Rule<T> {
   /// Hostname validation with configurable options.
   Hostname(HostnameOptions),
 
+  /// Filename validation for user-supplied file names (e.g. uploads used as storage keys).
+  ///
+  /// Rejects empty names, names longer than `max_bytes` UTF-8 bytes, path separators and the
+  /// other characters Windows forbids (`< > : " / \ | ? *`), control characters (including
+  /// NUL), trailing dots or spaces (which also rules out `.` and `..`), and Windows device
+  /// names with or without an extension (`CON`, `aux.txt`, `LPT1.log`).
+  Filename {
+    /// Maximum length in UTF-8 bytes (255 on most filesystems).
+    max_bytes: usize,
+  },
+
   // ---- Date Rules ----
   /// Date format validation (validates that a string is a parseable date).
   Date(DateOptions),
@@ -391,6 +405,10 @@ impl<T: Debug> Debug for Rule<T> {
       Self::Uri(opts) => f.debug_tuple("Uri").field(opts).finish(),
       Self::Ip(opts) => f.debug_tuple("Ip").field(opts).finish(),
       Self::Hostname(opts) => f.debug_tuple("Hostname").field(opts).finish(),
+      Self::Filename { max_bytes } => f
+        .debug_struct("Filename")
+        .field("max_bytes", max_bytes)
+        .finish(),
       Self::Date(opts) => f.debug_tuple("Date").field(opts).finish(),
       Self::DateRange(opts) => f.debug_tuple("DateRange").field(opts).finish(),
       Self::Min(v) => f.debug_tuple("Min").field(v).finish(),
@@ -447,6 +465,7 @@ impl<T: PartialEq> PartialEq for Rule<T> {
       (Self::Uri(a), Self::Uri(b)) => a == b,
       (Self::Ip(a), Self::Ip(b)) => a == b,
       (Self::Hostname(a), Self::Hostname(b)) => a == b,
+      (Self::Filename { max_bytes: a }, Self::Filename { max_bytes: b }) => a == b,
       (Self::Date(a), Self::Date(b)) => a == b,
       (Self::DateRange(a), Self::DateRange(b)) => a == b,
       (Self::Min(a), Self::Min(b)) => a == b,
@@ -829,6 +848,11 @@ impl<T> Rule<T> {
     Rule::Hostname(options)
   }
 
+  /// Creates a `Filename` rule allowing names of at most `max_bytes` UTF-8 bytes.
+  pub fn filename(max_bytes: usize) -> Rule<T> {
+    Rule::Filename { max_bytes }
+  }
+
   /// Creates a `Date` rule with the given options.
   pub fn date(options: DateOptions) -> Rule<T> {
     Rule::Date(options)
@@ -1030,6 +1054,28 @@ mod tests {
     assert_eq!(rule, deserialized);
   }
 
+  #[test]
+  #[cfg(feature = "serde_json_bridge")]
+  fn test_condition_serialization() {
+    assert_eq!(
+      serde_json::to_string(&Condition::<String>::IsEmpty).unwrap(),
+      r#"{"type":"isempty"}"#
+    );
+    let cases = [
+      Condition::<String>::IsNotEmpty,
+      Condition::Equals("x".into()),
+      Condition::Matches(CompiledPattern::try_from("^[A-Z]").unwrap()),
+    ];
+    for condition in cases {
+      let json = serde_json::to_string(&condition).unwrap();
+      let deserialized: Condition<String> = serde_json::from_str(&json).unwrap();
+      assert_eq!(condition, deserialized);
+    }
+    let parsed: Condition<i32> =
+      serde_json::from_str(r#"{"type":"greaterthan","value":5}"#).unwrap();
+    assert_eq!(parsed, Condition::GreaterThan(5));
+  }
+
   #[test]
   fn test_convenience_constructors() {
     assert_eq!(Rule::<String>::required(), Rule::Required);
@@ -135,6 +135,7 @@ impl<T: Serialize> ToAttributesList for Rule<T> {
       Rule::Uri(_) => None,
       Rule::Ip(_) => None,
       Rule::Hostname(_) => None,
+      Rule::Filename { .. } => None,
       Rule::Date(_) => None,
       Rule::DateRange(_) => None,
 
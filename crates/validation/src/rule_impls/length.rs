@@ -1,10 +1,9 @@
 use crate::Violation;
 use crate::rule::{Rule, RuleResult};
-use crate::traits::WithLength;
+use crate::traits::{Validate, ValidateRef, WithLength};
 
 impl<T: WithLength> Rule<T> {
   /// Validates a collection's length against this rule.
-  #[allow(dead_code)] // Reserved for a future public API
   pub(crate) fn validate_len(&self, value: &T) -> RuleResult {
     self.validate_len_inner(value, None)
   }
@@ -79,11 +78,7 @@ impl<T: WithLength> Rule<T> {
         }
         Ok(())
       }
-      Rule::Custom(_) => {
-        // Custom rules are not supported for generic WithLength validation
-        // as they require the specific type T
-        Ok(())
-      }
+      Rule::Custom(f) => f(value),
       #[cfg(feature = "async")]
       Rule::CustomAsync(_) => Ok(()),
       Rule::Ref(name) => Err(Violation::unresolved_ref(name)),
@@ -105,6 +100,7 @@ impl<T: WithLength> Rule<T> {
       | Rule::Uri(_)
       | Rule::Ip(_)
       | Rule::Hostname(_)
+      | Rule::Filename { .. }
       | Rule::Date(_)
       | Rule::DateRange(_)
       | Rule::Min(_)
@@ -129,7 +125,6 @@ impl<T: WithLength> Rule<T> {
   }
 
   /// Validates an optional collection's length.
-  #[allow(dead_code)] // Reserved for a future `validate_option` public API
   pub(crate) fn validate_option_len(&self, value: Option<&T>) -> RuleResult {
     match value {
       Some(v) => self.validate_len(v),
@@ -138,6 +133,85 @@ impl<T: WithLength> Rule<T> {
     }
   }
 
+  /// Validates a collection's length asynchronously, awaiting `CustomAsync` rules.
+  #[cfg(feature = "async")]
+  pub(crate) async fn validate_len_async(&self, value: &T) -> RuleResult
+  where
+    T: Sync,
+  {
+    self.validate_len_async_inner(value, None).await
+  }
+
+  /// Internal async validation with inherited locale.
+  #[cfg(feature = "async")]
+  fn validate_len_async_inner<'a>(
+    &'a self,
+    value: &'a T,
+    inherited_locale: Option<&'a str>,
+  ) -> std::pin::Pin<Box<dyn std::future::Future<Output = RuleResult> + Send + 'a>>
+  where
+    T: Sync,
+  {
+    Box::pin(async move {
+      match self {
+        Rule::CustomAsync(f) => f(value).await,
+
+        Rule::All(rules) => {
+          for rule in rules {
+            rule
+              .validate_len_async_inner(value, inherited_locale)
+              .await?;
+          }
+          Ok(())
+        }
+        Rule::Any(rules) => {
+          if rules.is_empty() {
+            return Ok(());
+          }
+          let mut last_err = None;
+          for rule in rules {
+            match rule.validate_len_async_inner(value, inherited_locale).await {
+              Ok(()) => return Ok(()),
+              Err(e) => last_err = Some(e),
+            }
+          }
+          Err(last_err.unwrap())
+        }
+        Rule::Not(inner) => match inner
+          .validate_len_async_inner(value, inherited_locale)
+          .await
+        {
+          Ok(()) => Err(Violation::negation_failed()),
+          Err(_) => Ok(()),
+        },
+        Rule::When { then_rule, .. } => {
+          if value.length() > 0 {
+            then_rule
+              .validate_len_async_inner(value, inherited_locale)
+              .await?;
+          }
+          Ok(())
+        }
+        Rule::WithMessage {
+          rule,
+          message,
+          locale,
+        } => {
+          let eff = locale.as_deref().or(inherited_locale);
+          match message {
+            Some(msg) => {
+              msg.wrap_result(rule.validate_len_async_inner(value, eff).await, value, eff)
+            }
+            None => rule.validate_len_async_inner(value, eff).await,
+          }
+        }
+
+        // All sync rules — delegate to sync validation
+        other => other.validate_len_inner(value, inherited_locale),
+      }
+    })
+  }
+
   /// Validates an optional collection's length and collects all violations.
   #[allow(dead_code)] // Reserved for a future `validate_option_all` public API
   pub(crate) fn validate_option_len_all(&self, value: Option<&T>) -> Result<(), crate::Violations> {
@@ -213,6 +287,74 @@ impl<T: WithLength> Rule<T> {
   }
 }
 
+// ============================================================================
+// Vec Validation
+// ============================================================================
+//
+// Collection rules check the number of elements (`Required`, `MinLength`,
+// `MaxLength`, `ExactLength`) or run `Custom` against the whole vector; value
+// rules (`Pattern`, `Min`, …) pass.
+
+impl<T> Validate<Vec<T>> for Rule<Vec<T>> {
+  fn validate(&self, value: Vec<T>) -> crate::ValidatorResult {
+    self.validate_len(&value)
+  }
+}
+
+impl<T> ValidateRef<Vec<T>> for Rule<Vec<T>> {
+  fn validate_ref(&self, value: &Vec<T>) -> crate::ValidatorResult {
+    self.validate_len(value)
+  }
+}
+
+impl<T> Validate<Option<Vec<T>>> for Rule<Vec<T>> {
+  fn validate(&self, value: Option<Vec<T>>) -> crate::ValidatorResult {
+    self.validate_option_len(value.as_ref())
+  }
+}
+
+impl<T> ValidateRef<Option<Vec<T>>> for Rule<Vec<T>> {
+  fn validate_ref(&self, value: &Option<Vec<T>>) -> crate::ValidatorResult {
+    self.validate_option_len(value.as_ref())
+  }
+}
+
+#[cfg(feature = "async")]
+impl<T: Send + Sync> crate::ValidateAsync<Vec<T>> for Rule<Vec<T>> {
+  async fn validate_async(&self, value: Vec<T>) -> crate::ValidatorResult {
+    self.validate_len_async(&value).await
+  }
+}
+
+#[cfg(feature = "async")]
+impl<T: Send + Sync> crate::ValidateRefAsync<Vec<T>> for Rule<Vec<T>> {
+  async fn validate_ref_async(&self, value: &Vec<T>) -> crate::ValidatorResult {
+    self.validate_len_async(value).await
+  }
+}
+
+#[cfg(feature = "async")]
+impl<T: Send + Sync> crate::ValidateAsync<Option<Vec<T>>> for Rule<Vec<T>> {
+  async fn validate_async(&self, value: Option<Vec<T>>) -> crate::ValidatorResult {
+    match value {
+      None if self.requires_value() => Err(Violation::value_missing()),
+      None => Ok(()),
+      Some(ref v) => self.validate_len_async(v).await,
+    }
+  }
+}
+
+#[cfg(feature = "async")]
+impl<T: Send + Sync> crate::ValidateRefAsync<Option<Vec<T>>> for Rule<Vec<T>> {
+  async fn validate_ref_async(&self, value: &Option<Vec<T>>) -> crate::ValidatorResult {
+    match value {
+      None if self.requires_value() => Err(Violation::value_missing()),
+      None => Ok(()),
+      Some(v) => self.validate_len_async(v).await,
+    }
+  }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -220,6 +362,8 @@ impl<T: WithLength> Rule<T> {
 #[cfg(test)]
 mod tests {
   use crate::rule::Rule;
+  use crate::traits::{Validate, ValidateRef};
+  use std::sync::Arc;
 
   // ========================================================================
   // Collection Length Validation Tests
@@ -589,4 +733,73 @@ mod tests {
     let rule = Rule::<Vec<i32>>::Not(Box::new(Rule::Ref("len_ref".into())));
     assert!(rule.validate_len(&vec![1, 2]).is_ok());
   }
+
+  #[test]
+  fn test_validate_vec_trait_impls() {
+    let rule = Rule::<Vec<String>>::Required.and(Rule::MaxLength(2));
+    assert!(rule.validate_ref(&vec!["a".to_string()]).is_ok());
+    assert!(rule.validate(vec![]).is_err());
+    assert!(rule.validate(Some(vec!["a".to_string(); 3])).is_err());
+    assert!(rule.validate_ref(&None::<Vec<String>>).is_err());
+    assert!(Rule::<Vec<String>>::MinLength(1).validate(None).is_ok());
+  }
+
+  #[test]
+  fn test_validate_len_custom() {
+    let rule = Rule::<Vec<i32>>::Custom(Arc::new(|v: &Vec<i32>| {
+      if v.iter().all(|n| *n > 0) {
+        Ok(())
+      } else {
+        Err(crate::Violation::range_underflow(&1))
+      }
+    }));
+    assert!(rule.validate_ref(&vec![1, 2]).is_ok());
+    assert!(rule.validate_ref(&vec![1, 0]).is_err());
+  }
+
+  #[cfg(feature = "async")]
+  mod async_len_tests {
+    use crate::rule::Rule;
+    use crate::{ValidateAsync, ValidateRefAsync, Violation};
+    use std::sync::Arc;
+
+    fn no_blank_tags() -> Rule<Vec<String>> {
+      Rule::CustomAsync(Arc::new(|tags: &Vec<String>| {
+        let blank = tags.iter().any(|t| t.trim().is_empty());
+        Box::pin(async move {
+          if blank {
+            Err(Violation::value_missing())
+          } else {
+            Ok(())
+          }
+        })
+      }))
+    }
+
+    #[tokio::test]
+    async fn test_async_validate_vec_awaits_custom_async() {
+      let rule = Rule::<Vec<String>>::MinLength(1).and(no_blank_tags());
+      assert!(rule.validate_async(vec!["a".into()]).await.is_ok());
+      assert!(rule.validate_ref_async(&vec![" ".into()]).await.is_err());
+      assert!(rule.validate_ref_async(&vec![]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_async_option_vec() {
+      let rule = Rule::<Vec<String>>::Required;
+      assert!(rule.validate_async(None::<Vec<String>>).await.is_err());
+      assert!(
+        no_blank_tags()
+          .validate_ref_async(&None::<Vec<String>>)
+          .await
+          .is_ok()
+      );
+      assert!(
+        no_blank_tags()
+          .validate_ref_async(&Some(vec!["".to_string()]))
+          .await
+          .is_err()
+      );
+    }
+  }
 }
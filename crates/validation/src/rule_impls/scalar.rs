@@ -141,6 +141,7 @@ impl<T: ScalarValue + IsEmpty> Rule<T> {
       | Rule::Uri(_)
       | Rule::Ip(_)
       | Rule::Hostname(_)
+      | Rule::Filename { .. }
       | Rule::Date(_)
       | Rule::DateRange(_) => Ok(()),
     }
@@ -132,6 +132,7 @@ impl<T: SteppableValue + IsEmpty> Rule<T> {
       | Rule::Uri(_)
       | Rule::Ip(_)
       | Rule::Hostname(_)
+      | Rule::Filename { .. }
       | Rule::Date(_)
       | Rule::DateRange(_) => Ok(()),
     }
@@ -1,4 +1,5 @@
 use crate::Violation;
+use crate::filename::{is_forbidden_filename_char, is_windows_reserved_name};
 use crate::options::{
   DateOptions, DateRangeOptions, EmailOptions, HostnameOptions, IpOptions, UriOptions, UrlOptions,
 };
@@ -253,6 +254,25 @@ fn validate_hostname(value: &str, opts: &HostnameOptions) -> RuleResult {
   Ok(())
 }
 
+// ============================================================================
+// Filename Validation Helper
+// ============================================================================
+
+/// Validates a user-supplied file name (`Rule::Filename`).
+fn validate_filename(value: &str, max_bytes: usize) -> RuleResult {
+  if value.len() > max_bytes {
+    return Err(Violation::too_long(max_bytes, value.len()));
+  }
+  if value.is_empty()
+    || value.ends_with(['.', ' '])
+    || value.chars().any(is_forbidden_filename_char)
+    || is_windows_reserved_name(value)
+  {
+    return Err(Violation::invalid_filename());
+  }
+  Ok(())
+}
+
 // ============================================================================
 // Email Validation Helper
 // ============================================================================
@@ -436,6 +456,7 @@ impl Rule<String> {
       Rule::Uri(opts) => validate_uri(value, opts),
       Rule::Ip(opts) => validate_ip(value, opts),
       Rule::Hostname(opts) => validate_hostname(value, opts),
+      Rule::Filename { max_bytes } => validate_filename(value, *max_bytes),
       Rule::Date(opts) => validate_date_str_dispatch(value, opts),
       Rule::DateRange(opts) => validate_date_range_str_dispatch(value, opts),
       Rule::Equals(expected) => {
@@ -1655,6 +1676,70 @@ mod tests {
     assert_eq!(rule, Rule::Hostname(opts));
   }
 
+  // ========================================================================
+  // Filename Validation Tests
+  // ========================================================================
+
+  #[test]
+  fn test_validate_filename_accepts_safe_names() {
+    let rule = Rule::<String>::filename(255);
+    for name in [
+      "report.pdf",
+      "photo 2024.jpeg",
+      ".gitignore",
+      "naïve café.txt",
+      "console.log",
+    ] {
+      assert!(rule.validate_str(name).is_ok(), "{name:?}");
+    }
+  }
+
+  #[test]
+  fn test_validate_filename_rejects_unsafe_names() {
+    let rule = Rule::<String>::filename(255);
+    for name in [
+      "",
+      ".",
+      "..",
+      "../etc/passwd",
+      "dir\\file.txt",
+      "a:b",
+      "what?.txt",
+      "nul\0byte",
+      "tab\there",
+      "trailing.",
+      "trailing ",
+      "CON",
+      "aux.txt",
+      "Lpt1.tar.gz",
+    ] {
+      let err = rule.validate_str(name).unwrap_err();
+      assert_eq!(
+        err.violation_type(),
+        crate::ViolationType::TypeMismatch,
+        "{name:?}"
+      );
+    }
+  }
+
+  #[test]
+  fn test_validate_filename_max_bytes() {
+    let rule = Rule::<String>::filename(8);
+    assert!(rule.validate_str("éééé.md").is_err()); // 11 bytes
+    assert!(rule.validate_str("éé.md").is_ok()); // 7 bytes
+    let err = rule.validate_str("123456789").unwrap_err();
+    assert_eq!(err.violation_type(), crate::ViolationType::TooLong);
+  }
+
+  #[test]
+  fn test_filename_serialization() {
+    let rule = Rule::<String>::filename(255);
+    let json = serde_json::to_string(&rule).unwrap();
+    assert_eq!(json, r#"{"type":"filename","config":{"max_bytes":255}}"#);
+    let deserialized: Rule<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(rule, deserialized);
+  }
+
   // ========================================================================
   // Rule::Ref tests (#143)
   // ========================================================================
@@ -1,6 +1,8 @@
+use std::borrow::Cow;
 use std::error::Error;
 use std::fmt;
 use std::fmt::{Debug, Display};
+use std::ops::Range;
 
 /// A validation violation message.
 pub type ViolationMessage = String;
@@ -20,14 +22,68 @@ pub enum ViolationType {
   ValueMissing,
 }
 
+/// A validation violation: its type, a message, and optional machine-readable details
+/// (a [`code`](Self::code), step [`path`](Self::path), and input [`span`](Self::span)).
+///
+/// Construct with [`Violation::new`] or one of the named constructors.
 #[must_use]
-#[derive(Clone, PartialEq, Debug)]
-pub struct Violation(pub ViolationType, pub ViolationMessage);
+#[derive(Clone, PartialEq)]
+pub struct Violation(
+  pub ViolationType,
+  pub ViolationMessage,
+  Option<Box<ViolationDetails>>,
+);
+
+/// Details kept out of line so that plain violations stay small.
+#[derive(Clone, PartialEq, Debug, Default)]
+struct ViolationDetails {
+  code: Option<Cow<'static, str>>,
+  path: Vec<usize>,
+  span: Option<Range<usize>>,
+}
 
 impl Violation {
   /// Creates a new `Violation` with the given type and message.
   pub fn new(violation_type: ViolationType, message: impl Into<String>) -> Self {
-    Self(violation_type, message.into())
+    Self(violation_type, message.into(), None)
+  }
+
+  /// Sets a stable, machine-readable code, e.g. `walrs_filter`'s `"not_a_number"`.
+  pub fn with_code(mut self, code: impl Into<Cow<'static, str>>) -> Self {
+    self.details_mut().code = Some(code.into());
+    self
+  }
+
+  /// Sets the index path of the step that raised the violation (e.g. within nested
+  /// filter chains).
+  pub fn with_path(mut self, path: impl Into<Vec<usize>>) -> Self {
+    self.details_mut().path = path.into();
+    self
+  }
+
+  /// Sets the byte range of the input the violation refers to.
+  pub fn with_span(mut self, span: Range<usize>) -> Self {
+    self.details_mut().span = Some(span);
+    self
+  }
+
+  /// The code set with [`with_code`](Self::with_code), if any.
+  pub fn code(&self) -> Option<&str> {
+    self.2.as_ref()?.code.as_deref()
+  }
+
+  /// The step path set with [`with_path`](Self::with_path); empty when unset.
+  pub fn path(&self) -> &[usize] {
+    self.2.as_ref().map_or(&[], |details| &details.path)
+  }
+
+  /// The byte span set with [`with_span`](Self::with_span), if any.
+  pub fn span(&self) -> Option<Range<usize>> {
+    self.2.as_ref()?.span.clone()
+  }
+
+  fn details_mut(&mut self) -> &mut ViolationDetails {
+    self.2.get_or_insert_with(Default::default)
   }
 
   /// Returns the violation type.
@@ -120,6 +176,11 @@ impl Violation {
     Self::new(ViolationType::TypeMismatch, "Invalid hostname.")
   }
 
+  /// Value is not a safe filename.
+  pub fn invalid_filename() -> Self {
+    Self::new(ViolationType::TypeMismatch, "Invalid filename.")
+  }
+
   /// Value is not a valid date.
   pub fn invalid_date() -> Self {
     Self::new(ViolationType::TypeMismatch, "Invalid date.")
@@ -203,7 +264,7 @@ impl Violation {
 /// ```rust
 /// use walrs_validation::{ViolationType::ValueMissing, Violation};
 ///
-/// let violation = Violation(ValueMissing, "Value missing.".to_string());
+/// let violation = Violation::new(ValueMissing, "Value missing.");
 /// let displayed = format!("{}", violation);
 ///
 /// assert_eq!(&displayed, "Value missing.");
@@ -217,6 +278,18 @@ impl Display for Violation {
   }
 }
 
+/// Prints the type and message, plus the details when any are set.
+impl Debug for Violation {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut tuple = f.debug_tuple("Violation");
+    tuple.field(&self.0).field(&self.1);
+    if let Some(details) = &self.2 {
+      tuple.field(details);
+    }
+    tuple.finish()
+  }
+}
+
 impl std::ops::Deref for Violation {
   type Target = ViolationMessage;
 
@@ -382,40 +455,54 @@ mod test {
 
   #[test]
   fn test_violation_to_string() {
-    let v = Violation(ValueMissing, "value is missing.".to_string());
+    let v = Violation::new(ValueMissing, "value is missing.");
     assert_eq!(&v.to_string(), "value is missing.");
   }
 
   #[test]
   fn test_violation_debug() {
-    let v = Violation(ValueMissing, "value is missing.".to_string());
+    let v = Violation::new(ValueMissing, "value is missing.");
     assert_eq!(
       format!("{:?}", v),
       "Violation(ValueMissing, \"value is missing.\")"
     );
   }
 
+  #[test]
+  fn test_violation_details() {
+    let v = Violation::new(TypeMismatch, "not a number.");
+    assert_eq!((v.code(), v.path(), v.span()), (None, &[][..], None));
+
+    let v = v
+      .with_code("not_a_number")
+      .with_path([1, 0])
+      .with_span(2..4);
+    assert_eq!(v.code(), Some("not_a_number"));
+    assert_eq!(v.path(), &[1, 0]);
+    assert_eq!(v.span(), Some(2..4));
+    assert_eq!(v.message(), "not a number.");
+    assert_ne!(v, Violation::new(TypeMismatch, "not a number."));
+    assert!(format!("{v:?}").contains("not_a_number"));
+  }
+
   #[test]
   fn test_violation_display() {
-    let v = Violation(ValueMissing, "value is missing.".to_string());
+    let v = Violation::new(ValueMissing, "value is missing.");
     assert_eq!(format!("{:}", v), "value is missing.");
   }
 
   #[test]
   fn test_violations_display() {
     let vs = Violations(vec![
-      Violation(ValueMissing, "value is missing.".to_string()),
-      Violation(TypeMismatch, "type mismatch.".to_string()),
+      Violation::new(ValueMissing, "value is missing."),
+      Violation::new(TypeMismatch, "type mismatch."),
     ]);
     assert_eq!(format!("{}", vs), "value is missing.; type mismatch.");
   }
 
   #[test]
   fn test_violations_error() {
-    let vs = Violations(vec![Violation(
-      ValueMissing,
-      "value is missing.".to_string(),
-    )]);
+    let vs = Violations(vec![Violation::new(ValueMissing, "value is missing.")]);
 
     // Test that Violations implements Error
     let err: &dyn Error = &vs;